@@ -0,0 +1,48 @@
+//! Keeps track of mounted widgets which change over time, so that the event
+//! loop in [`run`](crate::run) only redraws periodically while there are
+//! any.
+
+use std::{
+  sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  },
+  time::Duration,
+};
+
+/// How often the screen is redrawn while any animated widget is mounted.
+pub(crate) const FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
+static ANIMATED_WIDGETS: AtomicUsize = AtomicUsize::new(0);
+
+/// Keeps the screen redrawing every frame for as long as it is alive.
+///
+/// Animated widgets, such as [`Spinner`](crate::Spinner), should hold one
+/// from the moment they are turned into a view.
+#[derive(Debug)]
+pub struct AnimationGuard(());
+
+impl AnimationGuard {
+  pub fn new() -> Self {
+    ANIMATED_WIDGETS.fetch_add(1, Ordering::SeqCst);
+
+    Self(())
+  }
+}
+
+impl Default for AnimationGuard {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Drop for AnimationGuard {
+  fn drop(&mut self) {
+    ANIMATED_WIDGETS.fetch_sub(1, Ordering::SeqCst);
+  }
+}
+
+/// Returns `true` if any [`AnimationGuard`] is alive.
+pub(crate) fn is_animating() -> bool {
+  ANIMATED_WIDGETS.load(Ordering::SeqCst) > 0
+}
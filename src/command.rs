@@ -0,0 +1,208 @@
+use crate::{
+  Shortcut,
+  ShortcutId,
+  Shortcuts,
+};
+use leptos_reactive::{
+  use_context,
+  Scope,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// An action which can be run from the
+/// [`CommandPalette`](crate::CommandPalette), registered with
+/// [`Commands::register`].
+#[derive(Clone)]
+pub struct Command {
+  pub name: Cow<'static, str>,
+  pub description: Option<Cow<'static, str>>,
+  pub shortcut: Option<Shortcut>,
+  run: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl fmt::Debug for Command {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Command")
+      .field("name", &self.name)
+      .field("description", &self.description)
+      .field("shortcut", &self.shortcut)
+      .field("run", &"Fn()")
+      .finish()
+  }
+}
+
+impl Command {
+  pub fn new(
+    name: impl Into<Cow<'static, str>>,
+    run: impl Fn() + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      name: name.into(),
+      description: None,
+      shortcut: None,
+      run: Arc::new(run),
+    }
+  }
+
+  pub fn description(
+    mut self,
+    description: impl Into<Cow<'static, str>>,
+  ) -> Self {
+    self.description = Some(description.into());
+
+    self
+  }
+
+  /// Sets a shortcut which runs the command from anywhere while it is
+  /// registered, and which is shown next to it in the palette.
+  pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+    self.shortcut = Some(shortcut);
+
+    self
+  }
+
+  pub fn run(&self) {
+    (self.run)()
+  }
+}
+
+/// Identifies a command registered with [`Commands::register`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommandId(usize);
+
+/// The app-wide registry of commands listed by the
+/// [`CommandPalette`](crate::CommandPalette).
+///
+/// The registry is provided through context by [`run`](crate::run), and
+/// can be retrieved with [`use_commands`] from anywhere in the component
+/// tree.
+#[derive(Clone, Debug)]
+pub struct Commands {
+  inner: Arc<Mutex<CommandsInner>>,
+  shortcuts: Shortcuts,
+}
+
+#[derive(Debug, Default)]
+struct CommandsInner {
+  next_id: usize,
+  commands: Vec<(CommandId, Command, Option<ShortcutId>)>,
+}
+
+impl Commands {
+  pub(crate) fn new(shortcuts: Shortcuts) -> Self {
+    Self {
+      inner: Default::default(),
+      shortcuts,
+    }
+  }
+
+  /// Adds a command, registering its shortcut if it has one.
+  pub fn register(&self, command: Command) -> CommandId {
+    let shortcut_id = command.shortcut.map(|shortcut| {
+      let command = command.clone();
+
+      self.shortcuts.register(shortcut, move || command.run())
+    });
+
+    let mut inner = self.inner.lock().unwrap();
+
+    let id = CommandId(inner.next_id);
+
+    inner.next_id += 1;
+
+    inner.commands.push((id, command, shortcut_id));
+
+    id
+  }
+
+  /// Removes a command and its shortcut. Does nothing if it was already
+  /// removed.
+  pub fn unregister(&self, id: CommandId) {
+    let mut inner = self.inner.lock().unwrap();
+
+    if let Some(i) = inner
+      .commands
+      .iter()
+      .position(|(command_id, ..)| *command_id == id)
+    {
+      let (_, _, shortcut_id) = inner.commands.remove(i);
+
+      if let Some(shortcut_id) = shortcut_id {
+        self.shortcuts.unregister(shortcut_id);
+      }
+    }
+  }
+
+  /// Returns every registered command, in the order they were registered.
+  pub fn list(&self) -> Vec<Command> {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .commands
+      .iter()
+      .map(|(_, command, _)| command.clone())
+      .collect()
+  }
+}
+
+/// Gets the [`Commands`] registry provided by [`run`](crate::run).
+///
+/// # Panics
+///
+/// Panics if called outside of [`run`](crate::run).
+pub fn use_commands(cx: Scope) -> Commands {
+  use_context(cx).expect("`Commands` to be provided through context")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Event;
+  use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  #[test]
+  fn command_shortcuts_live_as_long_as_the_command() {
+    let shortcuts = Shortcuts::default();
+    let commands = Commands::new(shortcuts.clone());
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let id = commands.register(
+      Command::new("Save", {
+        let runs = runs.clone();
+
+        move || {
+          runs.fetch_add(1, Ordering::SeqCst);
+        }
+      })
+      .shortcut(Shortcut::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+    );
+
+    let ctrl_s =
+      Event::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+    shortcuts.on(&ctrl_s);
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+    commands.unregister(id);
+
+    assert!(shortcuts.on(&ctrl_s).is_none());
+    assert!(commands.list().is_empty());
+  }
+}
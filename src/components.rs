@@ -1,36 +1,93 @@
 #[cfg(test)]
 #[macro_use]
 mod text_macros;
+mod bar_chart;
+mod braille;
 mod button;
+mod canvas;
 mod center;
+mod checkbox;
+mod command_palette;
 mod dyn_child;
+mod error_boundary;
+mod for_loop;
+mod form;
+mod gauge;
+mod line_chart;
+mod list;
+mod menu;
+mod modal;
+mod outlet;
+mod portal;
+mod progress_bar;
+mod radio_group;
+mod scroll_view;
+mod select;
+pub mod show;
+mod sparkline;
+mod spinner;
+mod split;
+mod status_bar;
+mod suspense;
+mod switch;
+mod table;
+mod tabs;
 mod text;
+mod tree_view;
 mod unit;
 
 use crate::{
-  ArcView,
-  ArcWidget,
+  Event,
+  EventHandlerResult,
   IntoView,
   Limits,
   Size,
   View,
   Widget,
 };
+pub use bar_chart::*;
 pub use button::*;
+pub use canvas::*;
 pub use center::*;
+pub use checkbox::*;
+pub use command_palette::*;
 use core::fmt;
 pub use dyn_child::*;
-use std::sync::{
-  Arc,
-  Mutex,
+pub use error_boundary::*;
+pub use for_loop::*;
+pub use form::*;
+pub use gauge::*;
+pub use line_chart::*;
+pub use list::*;
+pub use menu::*;
+pub use modal::*;
+pub use outlet::*;
+pub use portal::*;
+pub use progress_bar::*;
+pub use radio_group::*;
+pub use scroll_view::*;
+pub use select::*;
+pub use show::{
+  Match,
+  Show,
 };
+pub use sparkline::*;
+pub use spinner::*;
+pub use split::*;
+pub use status_bar::*;
+pub use suspense::*;
+pub use switch::*;
+pub use table::*;
+pub use tabs::*;
 pub use text::*;
+pub use tree_view::*;
 pub use unit::*;
 
 #[derive(Debug)]
 pub enum CoreComponent {
   Unit(Unit),
   DynChild(DynChild),
+  For(For),
   Text(Text),
 }
 
@@ -43,8 +100,9 @@ impl Default for CoreComponent {
 impl fmt::Display for CoreComponent {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
-      Self::Unit(arg0) => writeln!(f, "<() />"),
+      Self::Unit(_) => writeln!(f, "<() />"),
       Self::DynChild(dyn_child) => dyn_child.fmt(f),
+      Self::For(for_) => for_.fmt(f),
       Self::Text(text) => writeln!(f, "{}", text),
     }
   }
@@ -61,6 +119,7 @@ impl Widget for CoreComponent {
     match self {
       CoreComponent::Unit(unit) => unit.name(),
       CoreComponent::DynChild(dyn_child) => dyn_child.name(),
+      CoreComponent::For(for_) => for_.name(),
       CoreComponent::Text(text) => text.name(),
     }
   }
@@ -69,6 +128,7 @@ impl Widget for CoreComponent {
     match self {
       Self::Unit(unit) => unit.layout(limits),
       Self::DynChild(dyn_child) => dyn_child.layout(limits),
+      Self::For(for_) => for_.layout(limits),
       Self::Text(text) => text.layout(limits),
     }
   }
@@ -77,9 +137,50 @@ impl Widget for CoreComponent {
     match self {
       Self::Unit(unit) => unit.draw(surface),
       Self::DynChild(dyn_child) => dyn_child.draw(surface),
+      Self::For(for_) => for_.draw(surface),
       Self::Text(text) => text.draw(surface),
     }
   }
+
+  fn needs_focus(&self) -> Option<bool> {
+    match self {
+      Self::DynChild(dyn_child) => dyn_child.needs_focus(),
+      Self::For(for_) => for_.needs_focus(),
+      Self::Unit(_) | Self::Text(_) => None,
+    }
+  }
+
+  fn has_focus(&self) -> bool {
+    match self {
+      Self::DynChild(dyn_child) => dyn_child.has_focus(),
+      Self::For(for_) => for_.has_focus(),
+      Self::Unit(_) | Self::Text(_) => false,
+    }
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match self {
+      Self::DynChild(dyn_child) => dyn_child.on(event),
+      Self::For(for_) => for_.on(event),
+      Self::Unit(_) | Self::Text(_) => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    match self {
+      Self::DynChild(dyn_child) => dyn_child.focus(),
+      Self::For(for_) => for_.focus(),
+      Self::Unit(_) | Self::Text(_) => {}
+    }
+  }
+
+  fn blur(&mut self) {
+    match self {
+      Self::DynChild(dyn_child) => dyn_child.blur(),
+      Self::For(for_) => for_.blur(),
+      Self::Unit(_) | Self::Text(_) => {}
+    }
+  }
 }
 
 #[track_caller]
@@ -98,15 +199,19 @@ pub fn debug_assert_size_within_limits(
   );
 }
 
+/// Truncates `text` to at most `width` characters.
+pub(crate) fn clip_to_width(text: &str, width: u16) -> String {
+  text.chars().take(width as usize).collect()
+}
+
 #[cfg(test)]
+#[allow(dead_code)]
 fn get_view<V: IntoView>(
   f: impl FnOnce(leptos_reactive::Scope) -> V + 'static,
 ) -> View {
   let rt = leptos_reactive::create_runtime();
 
-  let view = leptos_reactive::run_scope(rt, move |cx| f(cx).into_view(cx));
-
-  view
+  leptos_reactive::run_scope(rt, move |cx| f(cx).into_view(cx))
 }
 
 #[cfg(test)]
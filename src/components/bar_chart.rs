@@ -0,0 +1,310 @@
+use super::{
+  progress_bar::block_bar,
+  sparkline::{
+    column_cell,
+    scale_to_eighths,
+  },
+};
+use crate::{
+  Alignment,
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  Style,
+  Theme,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::style::Color;
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A single bar of a [`BarChart`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bar {
+  pub label: Cow<'static, str>,
+  pub value: f64,
+  pub color: Option<Color>,
+}
+
+impl Bar {
+  pub fn new(label: impl Into<Cow<'static, str>>, value: f64) -> Self {
+    Self {
+      label: label.into(),
+      value,
+      color: None,
+    }
+  }
+
+  /// Sets the color of the bar, instead of the theme's primary color.
+  pub fn color(mut self, color: Color) -> Self {
+    self.color = Some(color);
+
+    self
+  }
+
+  fn style(&self, theme: &Theme) -> Style {
+    self
+      .color
+      .map_or(theme.primary, |color| theme.primary.foreground(color))
+  }
+}
+
+/// A chart comparing labelled values, drawn as bars which are filled in
+/// eighths of a cell.
+///
+/// Bars rise from the bottom with their labels below them, or grow from
+/// the left with their labels before them if the chart is horizontal.
+/// The chart takes up all of the space it is given.
+pub struct BarChart {
+  bars_fn: Box<dyn Fn() -> Vec<Bar> + Send + Sync>,
+  horizontal: bool,
+  bar_width: u16,
+  max: Option<f64>,
+  bars: Vec<Bar>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for BarChart {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("BarChart")
+      .field("bars_fn", &"Fn() -> Vec<Bar>")
+      .field("horizontal", &self.horizontal)
+      .field("bar_width", &self.bar_width)
+      .field("max", &self.max)
+      .field("bars", &self.bars)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for BarChart {
+  fn name(&self) -> Cow<'static, str> {
+    "BarChart".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.bars = (self.bars_fn)();
+    self.size = limits.max_size();
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let max = self.max.unwrap_or_else(|| {
+      self.bars.iter().map(|bar| bar.value).fold(0.0, f64::max)
+    });
+
+    if self.horizontal {
+      self.draw_horizontal(surface, max);
+    } else {
+      self.draw_vertical(surface, max);
+    }
+  }
+}
+
+impl IntoView for BarChart {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl BarChart {
+  /// Creates a chart of the bars returned by `bars`.
+  pub fn new(bars: impl Fn() -> Vec<Bar> + Send + Sync + 'static) -> Self {
+    Self {
+      bars_fn: Box::new(bars),
+      horizontal: false,
+      bar_width: 3,
+      max: None,
+      bars: vec![],
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Sets whether bars grow from the left instead of rising from the
+  /// bottom.
+  pub fn horizontal(mut self, horizontal: bool) -> Self {
+    self.horizontal = horizontal;
+
+    self
+  }
+
+  /// Sets how many cells wide the bars of a vertical chart are, which is
+  /// 3 by default.
+  pub fn bar_width(mut self, bar_width: u16) -> Self {
+    self.bar_width = bar_width.max(1);
+
+    self
+  }
+
+  /// Sets the value which fills a bar, instead of the largest value.
+  pub fn max(mut self, max: f64) -> Self {
+    self.max = Some(max);
+
+    self
+  }
+
+  fn draw_vertical(&self, surface: &mut dyn DrawSurface, max: f64) {
+    let Size { width, height } = self.size;
+
+    if height == 0 {
+      return;
+    }
+
+    let bars_height = height - 1;
+    let theme = self.theme.get(&self.name());
+
+    for (i, bar) in self.bars.iter().enumerate() {
+      let x = i as u16 * (self.bar_width + 1);
+
+      if x >= width {
+        break;
+      }
+
+      let eighths = scale_to_eighths(bar.value, max, bars_height);
+
+      for y in 0..bars_height {
+        let cell = column_cell(eighths, bars_height - 1 - y);
+
+        surface.write_with_style(
+          XY { x, y },
+          &cell.to_string().repeat(self.bar_width as usize),
+          bar.style(&theme),
+        );
+      }
+
+      surface.write_with_style(
+        XY { x, y: bars_height },
+        &Alignment::Center.align(&bar.label, self.bar_width),
+        theme.text,
+      );
+    }
+  }
+
+  fn draw_horizontal(&self, surface: &mut dyn DrawSurface, max: f64) {
+    let label_width = self
+      .bars
+      .iter()
+      .map(|bar| bar.label.chars().count() as u16)
+      .max()
+      .unwrap_or_default()
+      .min(self.size.width / 3);
+
+    let bar_width = self.size.width.saturating_sub(label_width + 1);
+    let theme = self.theme.get(&self.name());
+
+    for (y, bar) in self.bars.iter().enumerate() {
+      let y = y as u16;
+
+      if y >= self.size.height {
+        break;
+      }
+
+      surface.write_with_style(
+        XY { x: 0, y },
+        &Alignment::Start.align(&bar.label, label_width),
+        theme.text,
+      );
+
+      let ratio = if max > 0.0 {
+        (bar.value / max).clamp(0.0, 1.0)
+      } else {
+        0.0
+      };
+
+      surface.write_with_style(
+        XY {
+          x: label_width + 1,
+          y,
+        },
+        block_bar(ratio, bar_width).trim_end(),
+        bar.style(&theme),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Print,
+      ResetColor,
+      SetForegroundColor,
+    },
+  };
+
+  fn bars() -> Vec<Bar> {
+    vec![Bar::new("a", 2.0), Bar::new("bc", 1.0).color(Color::Green)]
+  }
+
+  #[test]
+  fn draws_vertical_bars_with_labels_below() {
+    let chart = BarChart::new(bars).bar_width(2);
+
+    let (size, output) = get_widget_output(chart, (6, 2), (0, 0));
+
+    assert_eq!(size, (6, 2).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        SetForegroundColor(Color::Blue),
+        Print("██"),
+        ResetColor,
+        MoveTo(0, 1),
+        Print("a "),
+        MoveTo(3, 0),
+        SetForegroundColor(Color::Green),
+        Print("▄▄"),
+        ResetColor,
+        MoveTo(3, 1),
+        Print("bc"),
+      ]
+    );
+  }
+
+  #[test]
+  fn draws_horizontal_bars_with_labels_before() {
+    let chart = BarChart::new(bars).horizontal(true);
+
+    let (_, output) = get_widget_output(chart, (9, 2), (0, 0));
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print("a "),
+        MoveTo(3, 0),
+        SetForegroundColor(Color::Blue),
+        Print("██████"),
+        ResetColor,
+        MoveTo(0, 1),
+        Print("bc"),
+        MoveTo(3, 1),
+        SetForegroundColor(Color::Green),
+        Print("███"),
+        ResetColor,
+      ]
+    );
+  }
+}
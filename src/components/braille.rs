@@ -0,0 +1,190 @@
+use crate::{
+  DrawSurface,
+  Size,
+  XY,
+};
+use crossterm::style::Color;
+
+/// First character of the braille patterns block, with no dots raised.
+const BLANK: u32 = 0x2800;
+
+/// Bit of each dot within a braille character, indexed by `[y][x]`.
+const DOTS: [[u8; 2]; 4] =
+  [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A grid of dots drawn with braille characters, each of which is 2 dots
+/// wide and 4 dots tall, so that plots have 8 times the resolution of the
+/// cells they are drawn in.
+///
+/// Every cell takes the color of the last dot set within it.
+#[derive(Clone, Debug)]
+pub(crate) struct BrailleGrid {
+  size: Size,
+  cells: Vec<u8>,
+  colors: Vec<Option<Color>>,
+}
+
+impl BrailleGrid {
+  /// Creates an empty grid covering `size` cells.
+  pub fn new(size: Size) -> Self {
+    let len = size.width as usize * size.height as usize;
+
+    Self {
+      size,
+      cells: vec![0; len],
+      colors: vec![None; len],
+    }
+  }
+}
+
+/// A grid of cells, each of which is divided into dots which can be
+/// raised individually, used to draw shapes at a higher resolution than
+/// the terminal's cells.
+pub(crate) trait DotGrid {
+  /// Width of the grid, in dots.
+  fn width(&self) -> usize;
+
+  /// Height of the grid, in dots.
+  fn height(&self) -> usize;
+
+  /// Raises the dot at `(x, y)`, counted from the top-left corner. Dots
+  /// outside of the grid are ignored.
+  fn set(&mut self, x: isize, y: isize, color: Option<Color>);
+
+  /// Draws the grid with its top-left corner at `at`, skipping empty
+  /// cells so that whatever was drawn below them stays visible.
+  fn draw(&self, surface: &mut dyn DrawSurface, at: XY);
+
+  /// Raises every dot on the line from `from` to `to`.
+  fn line(
+    &mut self,
+    from: (isize, isize),
+    to: (isize, isize),
+    color: Option<Color>,
+  ) {
+    let (mut x, mut y) = from;
+    let dx = (to.0 - x).abs();
+    let dy = -(to.1 - y).abs();
+    let step_x = if x < to.0 { 1 } else { -1 };
+    let step_y = if y < to.1 { 1 } else { -1 };
+    let mut error = dx + dy;
+
+    loop {
+      self.set(x, y, color);
+
+      if (x, y) == to {
+        break;
+      }
+
+      let error2 = 2 * error;
+
+      if error2 >= dy {
+        error += dy;
+        x += step_x;
+      }
+
+      if error2 <= dx {
+        error += dx;
+        y += step_y;
+      }
+    }
+  }
+}
+
+impl DotGrid for BrailleGrid {
+  fn width(&self) -> usize {
+    self.size.width as usize * 2
+  }
+
+  fn height(&self) -> usize {
+    self.size.height as usize * 4
+  }
+
+  fn set(&mut self, x: isize, y: isize, color: Option<Color>) {
+    if x < 0
+      || y < 0
+      || x as usize >= self.width()
+      || y as usize >= self.height()
+    {
+      return;
+    }
+
+    let (x, y) = (x as usize, y as usize);
+    let i = (y / 4) * self.size.width as usize + x / 2;
+
+    self.cells[i] |= DOTS[y % 4][x % 2];
+    self.colors[i] = color;
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface, at: XY) {
+    let width = self.size.width as usize;
+
+    for y in 0..self.size.height {
+      let row = y as usize * width;
+      let mut x = 0;
+
+      while x < width {
+        if self.cells[row + x] == 0 {
+          x += 1;
+
+          continue;
+        }
+
+        // Runs of cells with the same color are written at once
+        let color = self.colors[row + x];
+        let start = x;
+        let mut run = String::new();
+
+        while x < width
+          && self.cells[row + x] != 0
+          && self.colors[row + x] == color
+        {
+          run.push(braille(self.cells[row + x]));
+
+          x += 1;
+        }
+
+        surface.write_styled(
+          XY {
+            x: at.x + start as u16,
+            y: at.y + y,
+          },
+          &run,
+          color,
+          None,
+          None,
+        );
+      }
+    }
+  }
+}
+
+fn braille(dots: u8) -> char {
+  char::from_u32(BLANK + dots as u32).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sets_dots_within_cells() {
+    let mut grid = BrailleGrid::new((2, 1).into());
+
+    grid.set(0, 0, None);
+    grid.set(3, 3, None);
+    grid.set(4, 0, None);
+
+    assert_eq!(braille(grid.cells[0]), '⠁');
+    assert_eq!(braille(grid.cells[1]), '⢀');
+  }
+
+  #[test]
+  fn draws_diagonal_lines() {
+    let mut grid = BrailleGrid::new((1, 1).into());
+
+    grid.line((0, 0), (1, 3), None);
+
+    assert_eq!(braille(grid.cells[0]), '⢣');
+  }
+}
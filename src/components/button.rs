@@ -1,14 +1,11 @@
 use crate::{
   IntoView,
   Size,
+  ThemeHandle,
   View,
   Widget,
   XY,
 };
-use crossterm::style::{
-  Attribute,
-  Color,
-};
 use std::{
   borrow::Cow,
   sync::{
@@ -22,6 +19,7 @@ pub struct Button {
   text: Cow<'static, str>,
   formatted_text: String,
   focused: bool,
+  theme: ThemeHandle,
 }
 
 impl Widget for Button {
@@ -63,20 +61,22 @@ impl Widget for Button {
   }
 
   fn draw(&self, surface: &mut dyn crate::DrawSurface) {
-    let focused = self.focused;
-
-    surface.write_styled(
-      XY::default(),
-      &self.formatted_text,
-      None,
-      if focused { Some(Color::Red) } else { None },
-      if focused { Some(Attribute::Bold) } else { None },
-    );
+    let theme = self.theme.get(&self.name());
+
+    let style = if self.focused {
+      theme.focus_fill
+    } else {
+      theme.text
+    };
+
+    surface.write_with_style(XY::default(), &self.formatted_text, style);
   }
 }
 
 impl IntoView for Button {
-  fn into_view(self, _: leptos_reactive::Scope) -> crate::View {
+  fn into_view(mut self, cx: leptos_reactive::Scope) -> crate::View {
+    self.theme = ThemeHandle::new(cx);
+
     View::Widget(Arc::new(Mutex::new(self)))
   }
 }
@@ -87,6 +87,7 @@ impl Button {
       text: text.into(),
       formatted_text: String::new(),
       focused: false,
+      theme: ThemeHandle::default(),
     }
   }
 }
@@ -94,14 +95,23 @@ impl Button {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::components::get_widget_output;
+  use crate::{
+    components::get_widget_output,
+    provide_component_styles,
+    ComponentStyles,
+    Style,
+    Theme,
+  };
   use crossterm::{
     cursor::MoveTo,
     style::{
+      Attribute,
+      Color,
       Print,
       ResetColor,
       SetAttribute,
       SetBackgroundColor,
+      SetForegroundColor,
     },
   };
 
@@ -203,4 +213,43 @@ mod tests {
 
     assert_eq!(output, commands![MoveTo(0, 0), Print("<hello>")]);
   }
+
+  #[test]
+  fn component_styles_are_drawn_as_written() {
+    leptos_reactive::run_scope(leptos_reactive::create_runtime(), |cx| {
+      let mut styles = ComponentStyles::new();
+
+      styles.set(
+        "Button",
+        Theme {
+          focus_fill: Style::new()
+            .foreground(Color::Black)
+            .background(Color::Yellow),
+          ..Theme::unstyled()
+        },
+      );
+
+      provide_component_styles(cx, styles);
+
+      let mut btn = Button::new("hello");
+
+      btn.theme = ThemeHandle::new(cx);
+      btn.focused = true;
+
+      let (_, output) = get_widget_output(btn, (7, 1), (0, 0));
+
+      assert_eq!(
+        output,
+        commands![
+          MoveTo(0, 0),
+          SetAttribute(Attribute::Bold),
+          SetBackgroundColor(Color::Yellow),
+          SetForegroundColor(Color::Black),
+          Print("<hello>"),
+          SetAttribute(Attribute::Reset),
+          ResetColor,
+        ]
+      );
+    });
+  }
 }
@@ -0,0 +1,413 @@
+use super::braille::{
+  BrailleGrid,
+  DotGrid,
+};
+use crate::{
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::style::Color;
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  f64::consts::TAU,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// How the shapes drawn on a [`Canvas`] are rasterized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Marker {
+  /// Braille dots, 2 by 4 per cell. Every cell has a single color.
+  #[default]
+  Braille,
+  /// Half blocks, 1 by 2 per cell. Both halves of a cell can have
+  /// different colors.
+  HalfBlock,
+}
+
+/// A drawing area with its own floating-point coordinate space, in which
+/// shapes are painted by a closure every time the canvas is drawn.
+///
+/// Coordinates grow rightwards and upwards, from the bottom-left corner at
+/// the minimum of both bounds, which are `0.0..=1.0` by default. The canvas
+/// takes up all of the space it is given.
+pub struct Canvas {
+  paint: Box<dyn Fn(&mut Painter) + Send + Sync>,
+  x_bounds: (f64, f64),
+  y_bounds: (f64, f64),
+  marker: Marker,
+  size: Size,
+}
+
+impl fmt::Debug for Canvas {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Canvas")
+      .field("paint", &"Fn(&mut Painter)")
+      .field("x_bounds", &self.x_bounds)
+      .field("y_bounds", &self.y_bounds)
+      .field("marker", &self.marker)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for Canvas {
+  fn name(&self) -> Cow<'static, str> {
+    "Canvas".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.size = limits.max_size();
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if self.size.width == 0 || self.size.height == 0 {
+      return;
+    }
+
+    let mut grid: Box<dyn DotGrid> = match self.marker {
+      Marker::Braille => Box::new(BrailleGrid::new(self.size)),
+      Marker::HalfBlock => Box::new(HalfBlockGrid::new(self.size)),
+    };
+
+    let mut painter = Painter {
+      grid: &mut *grid,
+      size: self.size,
+      x_bounds: self.x_bounds,
+      y_bounds: self.y_bounds,
+      labels: vec![],
+    };
+
+    (self.paint)(&mut painter);
+
+    let labels = std::mem::take(&mut painter.labels);
+
+    grid.draw(surface, XY::default());
+
+    for (at, text, color) in labels {
+      surface.write_styled(at, &text, color, None, None);
+    }
+  }
+}
+
+impl IntoView for Canvas {
+  fn into_view(self, _: Scope) -> View {
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Canvas {
+  /// Creates a canvas painted by `paint`.
+  pub fn new(paint: impl Fn(&mut Painter) + Send + Sync + 'static) -> Self {
+    Self {
+      paint: Box::new(paint),
+      x_bounds: (0.0, 1.0),
+      y_bounds: (0.0, 1.0),
+      marker: Marker::default(),
+      size: Size::default(),
+    }
+  }
+
+  /// Sets the range of x coordinates spanning the width of the canvas.
+  pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+    self.x_bounds = (min, max);
+
+    self
+  }
+
+  /// Sets the range of y coordinates spanning the height of the canvas.
+  pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+    self.y_bounds = (min, max);
+
+    self
+  }
+
+  /// Sets how shapes are rasterized, which is with braille dots by
+  /// default.
+  pub fn marker(mut self, marker: Marker) -> Self {
+    self.marker = marker;
+
+    self
+  }
+}
+
+/// The drawing context given to the closure painting a [`Canvas`].
+pub struct Painter<'a> {
+  grid: &'a mut dyn DotGrid,
+  size: Size,
+  x_bounds: (f64, f64),
+  y_bounds: (f64, f64),
+  labels: Vec<(XY, String, Option<Color>)>,
+}
+
+impl Painter<'_> {
+  /// Returns the size of the canvas, in cells.
+  pub fn size(&self) -> Size {
+    self.size
+  }
+
+  pub fn point(&mut self, x: f64, y: f64, color: Color) {
+    let (x, y) = self.to_dots(x, y);
+
+    self.grid.set(x, y, Some(color));
+  }
+
+  pub fn line(&mut self, from: (f64, f64), to: (f64, f64), color: Color) {
+    let from = self.to_dots(from.0, from.1);
+    let to = self.to_dots(to.0, to.1);
+
+    self.grid.line(from, to, Some(color));
+  }
+
+  /// Draws the outline of the rectangle with its bottom-left corner at
+  /// `(x, y)`.
+  pub fn rect(
+    &mut self,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    color: Color,
+  ) {
+    self.polygon(
+      &[
+        (x, y),
+        (x + width, y),
+        (x + width, y + height),
+        (x, y + height),
+      ],
+      color,
+    );
+  }
+
+  /// Draws the outline of the circle centered at `(x, y)`.
+  pub fn circle(&mut self, x: f64, y: f64, radius: f64, color: Color) {
+    // Enough segments for neighbouring vertices to be about a dot apart
+    let (dot_width, dot_height) = self.dot_size();
+    let dots = (radius / dot_width.min(dot_height)).abs();
+    let segments = ((TAU * dots) as usize).clamp(8, 1024);
+
+    let vertices = (0..segments)
+      .map(|i| {
+        let angle = TAU * i as f64 / segments as f64;
+
+        (x + radius * angle.cos(), y + radius * angle.sin())
+      })
+      .collect::<Vec<_>>();
+
+    self.polygon(&vertices, color);
+  }
+
+  /// Draws the outline of the polygon with the given vertices, connecting
+  /// the last one back to the first.
+  pub fn polygon(&mut self, vertices: &[(f64, f64)], color: Color) {
+    for (i, from) in vertices.iter().enumerate() {
+      let to = vertices[(i + 1) % vertices.len()];
+
+      self.line(*from, to, color);
+    }
+  }
+
+  /// Writes `text` starting at the cell containing `(x, y)`, on top of
+  /// every shape.
+  pub fn label(
+    &mut self,
+    x: f64,
+    y: f64,
+    text: impl Into<String>,
+    color: Option<Color>,
+  ) {
+    let (dot_x, dot_y) = self.to_dots(x, y);
+
+    if dot_x < 0 || dot_y < 0 {
+      return;
+    }
+
+    let cell_width = self.grid.width() / self.size.width as usize;
+    let cell_height = self.grid.height() / self.size.height as usize;
+
+    self.labels.push((
+      XY {
+        x: (dot_x as usize / cell_width) as u16,
+        y: (dot_y as usize / cell_height) as u16,
+      },
+      text.into(),
+      color,
+    ));
+  }
+
+  /// Converts canvas coordinates into the nearest dot, so that both
+  /// bounds of each axis map to the dots on the edges of the canvas.
+  fn to_dots(&self, x: f64, y: f64) -> (isize, isize) {
+    let (x_min, x_max) = self.x_bounds;
+    let (y_min, y_max) = self.y_bounds;
+
+    let dot_x = (x - x_min) / (x_max - x_min) * (self.grid.width() - 1) as f64;
+    let dot_y = (y_max - y) / (y_max - y_min) * (self.grid.height() - 1) as f64;
+
+    (dot_x.round() as isize, dot_y.round() as isize)
+  }
+
+  /// Returns how much of the canvas' coordinate space a dot covers.
+  fn dot_size(&self) -> (f64, f64) {
+    (
+      (self.x_bounds.1 - self.x_bounds.0) / self.grid.width() as f64,
+      (self.y_bounds.1 - self.y_bounds.0) / self.grid.height() as f64,
+    )
+  }
+}
+
+/// A grid drawn with half blocks, splitting every cell into a top and a
+/// bottom dot, each with its own color.
+struct HalfBlockGrid {
+  size: Size,
+  /// Colors of the top and bottom halves of every cell, if they are set.
+  cells: Vec<[Option<Color>; 2]>,
+}
+
+impl HalfBlockGrid {
+  fn new(size: Size) -> Self {
+    Self {
+      size,
+      cells: vec![[None; 2]; size.width as usize * size.height as usize],
+    }
+  }
+}
+
+impl DotGrid for HalfBlockGrid {
+  fn width(&self) -> usize {
+    self.size.width as usize
+  }
+
+  fn height(&self) -> usize {
+    self.size.height as usize * 2
+  }
+
+  fn set(&mut self, x: isize, y: isize, color: Option<Color>) {
+    if x < 0
+      || y < 0
+      || x as usize >= self.width()
+      || y as usize >= self.height()
+    {
+      return;
+    }
+
+    let (x, y) = (x as usize, y as usize);
+
+    self.cells[(y / 2) * self.size.width as usize + x][y % 2] =
+      Some(color.unwrap_or(Color::Reset));
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface, at: XY) {
+    for (i, [top, bottom]) in self.cells.iter().enumerate() {
+      let (block, foreground, background) = match (top, bottom) {
+        (None, None) => continue,
+        (Some(top), None) => ("▀", *top, None),
+        (None, Some(bottom)) => ("▄", *bottom, None),
+        (Some(top), Some(bottom)) if top == bottom => ("█", *top, None),
+        (Some(top), Some(bottom)) => ("▀", *top, Some(*bottom)),
+      };
+
+      let x = (i % self.size.width as usize) as u16;
+      let y = (i / self.size.width as usize) as u16;
+
+      surface.write_styled(
+        XY {
+          x: at.x + x,
+          y: at.y + y,
+        },
+        block,
+        Some(foreground),
+        background,
+        None,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Print,
+      ResetColor,
+      SetBackgroundColor,
+      SetForegroundColor,
+    },
+  };
+
+  #[test]
+  fn maps_coordinates_to_braille_dots() {
+    let canvas = Canvas::new(|painter| {
+      painter.line((0.0, 0.0), (4.0, 0.0), Color::Green);
+      painter.label(0.0, 4.0, "hi", None);
+    })
+    .x_bounds(0.0, 4.0)
+    .y_bounds(0.0, 4.0);
+
+    let (size, output) = get_widget_output(canvas, (2, 1), (0, 0));
+
+    assert_eq!(size, (2, 1).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print(format!(
+          "{}⣀⣀{}",
+          SetForegroundColor(Color::Green),
+          ResetColor
+        )),
+        MoveTo(0, 0),
+        Print("hi"),
+      ]
+    );
+  }
+
+  #[test]
+  fn half_blocks_keep_both_colors() {
+    let canvas = Canvas::new(|painter| {
+      painter.point(0.0, 1.5, Color::Red);
+      painter.point(0.0, 0.5, Color::Blue);
+      painter.point(1.0, 0.5, Color::Blue);
+    })
+    .x_bounds(0.0, 2.0)
+    .y_bounds(0.0, 2.0)
+    .marker(Marker::HalfBlock);
+
+    let (_, output) = get_widget_output(canvas, (2, 1), (0, 0));
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print(format!(
+          "{}{}▀{}",
+          SetBackgroundColor(Color::Blue),
+          SetForegroundColor(Color::Red),
+          ResetColor
+        )),
+        MoveTo(1, 0),
+        Print(format!(
+          "{}▄{}",
+          SetForegroundColor(Color::Blue),
+          ResetColor
+        )),
+      ]
+    );
+  }
+}
@@ -1,13 +1,14 @@
-use super::debug_assert_size_within_limits;
 use crate::{
   ArcView,
-  ArcWidget,
   DrawSurface,
+  Event,
+  EventHandlerResult,
   IntoView,
   Limits,
   Size,
   View,
   Widget,
+  XY,
 };
 use leptos_reactive::Scope;
 use std::{
@@ -24,7 +25,7 @@ pub struct Center<State> {
   child_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
   child: Option<ArcView>,
   child_size: Size,
-  #[cfg(debug_assertions)]
+  child_offset: XY,
   created_at: &'static std::panic::Location<'static>,
 }
 
@@ -34,6 +35,7 @@ impl fmt::Debug for Center<WithChild> {
       .field("state", &self.state)
       .field("child", &self.child)
       .field("child_size", &self.child_size)
+      .field("child_offset", &self.child_offset)
       .field("created_at", &self.created_at)
       .finish()
   }
@@ -47,6 +49,7 @@ impl Default for Center<MissingChild> {
       child_fn: None,
       child: None,
       child_size: Default::default(),
+      child_offset: Default::default(),
       created_at: std::panic::Location::caller(),
     }
   }
@@ -83,13 +86,15 @@ impl Widget for Center<WithChild> {
       .layout(child_limits);
 
     self.child_size = child_size;
+    self.child_offset = XY {
+      x: (limits.max_width - child_size.width) / 2,
+      y: (limits.max_height - child_size.height) / 2,
+    };
 
     limits.max_size()
   }
 
   fn draw(&self, surface: &mut dyn DrawSurface) {
-    let size = surface.size();
-
     let child_size = self.child_size;
 
     surface.shrink_centered(
@@ -99,6 +104,29 @@ impl Widget for Center<WithChild> {
       }),
     );
   }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.child.as_ref().unwrap().lock().unwrap().needs_focus()
+  }
+
+  fn has_focus(&self) -> bool {
+    self.child.as_ref().unwrap().lock().unwrap().has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event.relative_to(self.child_offset, self.child_size) {
+      Some(event) => self.child.as_ref().unwrap().lock().unwrap().on(event),
+      None => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.child.as_ref().unwrap().lock().unwrap().focus()
+  }
+
+  fn blur(&mut self) {
+    self.child.as_ref().unwrap().lock().unwrap().blur()
+  }
 }
 
 impl Center<MissingChild> {
@@ -118,6 +146,7 @@ impl Center<MissingChild> {
       child_fn: Some(Box::new(|cx| child.into_view(cx))),
       child: None,
       child_size: Size::default(),
+      child_offset: XY::default(),
       created_at: self.created_at,
     }
   }
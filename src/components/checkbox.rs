@@ -0,0 +1,239 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  SignalGetUntracked,
+  SignalUpdate,
+};
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A focusable `[x] label` input bound to a `bool` signal.
+#[derive(Debug)]
+pub struct Checkbox {
+  label: Cow<'static, str>,
+  checked: RwSignal<bool>,
+  formatted_text: String,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl Widget for Checkbox {
+  fn name(&self) -> Cow<'static, str> {
+    "Checkbox".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let mark = if self.checked.get_untracked() {
+      'x'
+    } else {
+      ' '
+    };
+
+    self.formatted_text =
+      clip_to_width(&format!("[{mark}] {}", self.label), limits.max_width);
+
+    let width =
+      (self.formatted_text.chars().count() as u16).max(limits.min_width);
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let theme = self.theme.get(&self.name());
+
+    surface.write_with_style(
+      XY::default(),
+      &self.formatted_text,
+      if self.focused {
+        theme.focus
+      } else {
+        theme.text
+      },
+    );
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent {
+        code: KeyCode::Char(' ') | KeyCode::Enter,
+        ..
+      }) if self.focused => {
+        self.toggle();
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        ..
+      }) if column < self.size.width && row < self.size.height => {
+        self.focus();
+        self.toggle();
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for Checkbox {
+  fn into_view(mut self, cx: leptos_reactive::Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Checkbox {
+  pub fn new(
+    label: impl Into<Cow<'static, str>>,
+    checked: RwSignal<bool>,
+  ) -> Self {
+    Self {
+      label: label.into(),
+      checked,
+      formatted_text: String::new(),
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  fn toggle(&self) {
+    self.checked.update(|checked| *checked = !*checked);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::Print,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  #[test]
+  fn renders_unchecked() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, false);
+
+      let (size, output) =
+        get_widget_output(Checkbox::new("hello", checked), (20, 1), (0, 0));
+
+      assert_eq!(size, (9, 1).into());
+
+      assert_eq!(output, commands![MoveTo(0, 0), Print("[ ] hello")]);
+    });
+  }
+
+  #[test]
+  fn renders_checked_and_clips() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, true);
+
+      let (size, output) =
+        get_widget_output(Checkbox::new("hello", checked), (6, 1), (0, 0));
+
+      assert_eq!(size, (6, 1).into());
+
+      assert_eq!(output, commands![MoveTo(0, 0), Print("[x] he")]);
+    });
+  }
+
+  #[test]
+  fn space_toggles_only_when_focused() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, false);
+      let mut checkbox = Checkbox::new("hello", checked);
+      let space =
+        Event::Key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+
+      checkbox.on(space.clone());
+
+      assert!(!checked.get_untracked());
+
+      checkbox.on(Event::NextFocus);
+      checkbox.on(space);
+
+      assert!(checked.get_untracked());
+    });
+  }
+
+  #[test]
+  fn click_focuses_and_toggles() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, true);
+      let mut checkbox = Checkbox::new("hello", checked);
+
+      checkbox.layout(Limits::strict(9, 1));
+      checkbox.on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 2,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert!(checkbox.has_focus());
+      assert!(!checked.get_untracked());
+    });
+  }
+}
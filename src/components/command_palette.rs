@@ -0,0 +1,542 @@
+use super::clip_to_width;
+use crate::{
+  use_commands,
+  use_overlays,
+  use_shortcuts,
+  Command,
+  Commands,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  OverlayId,
+  Overlays,
+  Shortcut,
+  Size,
+  Style,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  KeyModifiers,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  on_cleanup,
+  Scope,
+};
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// The maximum number of commands listed at once.
+const MAX_RESULTS: usize = 10;
+
+/// How wide the palette is, if there's enough room.
+const PALETTE_WIDTH: u16 = 60;
+
+/// A searchable list of the commands registered with
+/// [`Commands`](crate::Commands), opened as a modal overlay by a shortcut,
+/// which is Ctrl+P by default.
+///
+/// Typing filters the commands by fuzzy matching their names, best
+/// matches first, with the matched characters highlighted. Up and Down
+/// move through the results, Enter or a click runs the highlighted
+/// command, and Esc closes the palette.
+///
+/// The palette takes up no space where it is placed in the view tree.
+#[derive(Debug)]
+pub struct CommandPalette {
+  shortcut: Shortcut,
+}
+
+impl IntoView for CommandPalette {
+  fn into_view(self, cx: Scope) -> View {
+    let overlays = use_overlays(cx);
+    let commands = use_commands(cx);
+    let shortcuts = use_shortcuts(cx);
+    let theme = ThemeHandle::new(cx);
+
+    let open = Arc::new(Mutex::new(None::<OverlayId>));
+
+    let id = shortcuts.register(self.shortcut, {
+      let overlays = overlays.clone();
+      let open = open.clone();
+
+      move || {
+        let mut open_id = open.lock().unwrap();
+
+        if open_id.is_some() {
+          return;
+        }
+
+        let mut popup = PalettePopup {
+          commands: commands.clone(),
+          overlays: overlays.clone(),
+          open: open.clone(),
+          query: String::new(),
+          results: vec![],
+          highlighted: 0,
+          size: Size::default(),
+          theme,
+        };
+
+        popup.search();
+
+        *open_id = Some(overlays.push_modal(Arc::new(Mutex::new(popup))));
+      }
+    });
+
+    on_cleanup(cx, move || {
+      shortcuts.unregister(id);
+
+      if let Some(id) = open.lock().unwrap().take() {
+        overlays.remove(id);
+      }
+    });
+
+    ().into_view(cx)
+  }
+}
+
+impl CommandPalette {
+  pub fn new() -> Self {
+    Self {
+      shortcut: Shortcut::new(KeyCode::Char('p'), KeyModifiers::CONTROL),
+    }
+  }
+
+  /// Sets the shortcut opening the palette.
+  pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+    self.shortcut = shortcut;
+
+    self
+  }
+}
+
+impl Default for CommandPalette {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A command matching the palette's query, with the indices of the
+/// characters of its name which matched.
+#[derive(Debug)]
+struct SearchResult {
+  command: Command,
+  matches: Vec<usize>,
+}
+
+/// The open palette, pushed onto the overlay layer.
+#[derive(Debug)]
+struct PalettePopup {
+  commands: Commands,
+  overlays: Overlays,
+  open: Arc<Mutex<Option<OverlayId>>>,
+  query: String,
+  results: Vec<SearchResult>,
+  highlighted: usize,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl Widget for PalettePopup {
+  fn name(&self) -> Cow<'static, str> {
+    "CommandPalette".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let rows = self.results.len().clamp(1, MAX_RESULTS) as u16;
+
+    self.size = Size {
+      width: PALETTE_WIDTH.clamp(limits.min_width, limits.max_width),
+      // The query, a separator, and the results, within borders
+      height: (rows + 4).clamp(limits.min_height, limits.max_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Size { width, height } = self.size;
+
+    if width < 4 || height < 4 {
+      return;
+    }
+
+    let inner_width = width - 2;
+    let line = |left, right| {
+      format!("{left}{}{right}", "─".repeat(inner_width as usize))
+    };
+
+    let theme = self.theme.get(&self.name());
+    let border = theme.surface.patch(theme.border);
+
+    surface.write_with_style(XY { x: 0, y: 0 }, &line("┌", "┐"), border);
+    surface.write_with_style(XY { x: 0, y: 2 }, &line("├", "┤"), border);
+    surface.write_with_style(
+      XY {
+        x: 0,
+        y: height - 1,
+      },
+      &line("└", "┘"),
+      border,
+    );
+
+    for y in 1..height - 1 {
+      if y != 2 {
+        surface.write_with_style(XY { x: 0, y }, "│", border);
+        surface.write_with_style(XY { x: width - 1, y }, "│", border);
+      }
+    }
+
+    surface.write_with_style(
+      XY { x: 1, y: 1 },
+      &clip_to_width(&format!(" > {}▏", self.query), inner_width),
+      theme.surface.patch(theme.text),
+    );
+
+    if self.results.is_empty() {
+      surface.write_with_style(
+        XY { x: 2, y: 3 },
+        &clip_to_width("no matching commands", inner_width - 2),
+        theme.surface.patch(theme.muted),
+      );
+
+      return;
+    }
+
+    let scroll = self.scroll();
+
+    for (row, result) in self
+      .results
+      .iter()
+      .enumerate()
+      .skip(scroll)
+      .take((height - 4) as usize)
+    {
+      let y = (row - scroll) as u16 + 3;
+      let is_highlighted = row == self.highlighted;
+
+      self.draw_result(surface, y, result, inner_width, is_highlighted);
+    }
+  }
+
+  fn has_focus(&self) -> bool {
+    true
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::Key(KeyEvent { code, .. }) => match code {
+        KeyCode::Esc => self.close(),
+        KeyCode::Enter => self.run(self.highlighted),
+        KeyCode::Up => self.highlighted = self.highlighted.saturating_sub(1),
+        KeyCode::Down => {
+          self.highlighted =
+            (self.highlighted + 1).min(self.results.len().saturating_sub(1));
+        }
+        KeyCode::Backspace => {
+          self.query.pop();
+          self.search();
+        }
+        KeyCode::Char(c) => {
+          self.query.push(c);
+          self.search();
+        }
+        _ => {}
+      },
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        row,
+        ..
+      }) if row >= 3 => {
+        let i = self.scroll() + (row - 3) as usize;
+
+        if i < self.results.len() {
+          self.run(i);
+        }
+      }
+      Event::Mouse(MouseEvent { kind, .. }) => match kind {
+        MouseEventKind::ScrollUp => {
+          self.highlighted = self.highlighted.saturating_sub(1);
+        }
+        MouseEventKind::ScrollDown => {
+          self.highlighted =
+            (self.highlighted + 1).min(self.results.len().saturating_sub(1));
+        }
+        _ => {}
+      },
+      _ => return EventHandlerResult::Bubble,
+    }
+
+    EventHandlerResult::Captured
+  }
+}
+
+impl PalettePopup {
+  /// Filters and sorts the commands by how well they match the query.
+  fn search(&mut self) {
+    let mut results = self
+      .commands
+      .list()
+      .into_iter()
+      .filter_map(|command| {
+        let (score, matches) = fuzzy_match(&self.query, &command.name)?;
+
+        Some((score, SearchResult { command, matches }))
+      })
+      .collect::<Vec<_>>();
+
+    // The sort is stable, so commands which match equally well stay in the
+    // order they were registered in
+    results.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    self.results = results.into_iter().map(|(_, result)| result).collect();
+    self.highlighted = 0;
+  }
+
+  /// Returns the index of the first visible result, keeping the
+  /// highlighted one visible.
+  fn scroll(&self) -> usize {
+    let rows = self.size.height.saturating_sub(4).max(1) as usize;
+
+    (self.highlighted + 1).saturating_sub(rows)
+  }
+
+  fn close(&self) {
+    if let Some(id) = self.open.lock().unwrap().take() {
+      self.overlays.remove(id);
+    }
+  }
+
+  /// Closes the palette and runs a command.
+  fn run(&self, i: usize) {
+    let Some(result) = self.results.get(i) else {
+      return;
+    };
+
+    self.close();
+
+    result.command.run();
+  }
+
+  fn draw_result(
+    &self,
+    surface: &mut dyn DrawSurface,
+    y: u16,
+    result: &SearchResult,
+    width: u16,
+    is_highlighted: bool,
+  ) {
+    let theme = self.theme.get(&self.name());
+    let style = theme.surface.patch(if is_highlighted {
+      theme.selected
+    } else {
+      theme.text
+    });
+    // Keeps the row's attribute, such as the highlighted row's
+    let muted = Style {
+      attribute: style.attribute,
+      ..style.patch(theme.muted)
+    };
+
+    surface.write_with_style(
+      XY { x: 1, y },
+      &" ".repeat(width as usize),
+      style,
+    );
+
+    let shortcut = result
+      .command
+      .shortcut
+      .map(|shortcut| shortcut.to_string())
+      .unwrap_or_default();
+
+    let shortcut_width = shortcut.chars().count() as u16;
+    let text_width = width.saturating_sub(shortcut_width + 3);
+
+    // The name, with its matched characters highlighted
+    let mut x = 2;
+
+    for (i, c) in result.command.name.chars().enumerate() {
+      if x - 2 >= text_width {
+        break;
+      }
+
+      let is_match = result.matches.contains(&i);
+
+      let style = match (is_match, is_highlighted) {
+        (true, true) => Style {
+          attribute: style.attribute,
+          ..style.patch(theme.highlight)
+        },
+        (true, false) => style.patch(theme.highlight),
+        (false, _) => style,
+      };
+
+      surface.write_with_style(XY { x, y }, &c.to_string(), style);
+
+      x += 1;
+    }
+
+    if let Some(description) = &result.command.description {
+      let room = text_width.saturating_sub(x - 2 + 2);
+
+      if room > 0 {
+        surface.write_with_style(
+          XY { x: x + 2, y },
+          &clip_to_width(description, room),
+          muted,
+        );
+      }
+    }
+
+    if shortcut_width > 0 && shortcut_width + 3 <= width {
+      surface.write_with_style(
+        XY {
+          x: width - shortcut_width,
+          y,
+        },
+        &shortcut,
+        muted,
+      );
+    }
+  }
+}
+
+/// Matches `pattern` against `text` as a case-insensitive subsequence,
+/// returning a score, higher for better matches, and the indices of the
+/// characters of `text` which matched.
+///
+/// Consecutive matches, and matches at the start of words, score higher,
+/// while characters skipped between matches lower the score. An empty
+/// pattern matches everything equally.
+pub(crate) fn fuzzy_match(
+  pattern: &str,
+  text: &str,
+) -> Option<(i32, Vec<usize>)> {
+  let text = text.chars().collect::<Vec<_>>();
+  let mut matches = vec![];
+  let mut score = 0;
+  let mut next = 0;
+
+  for p in pattern.chars().filter(|c| !c.is_whitespace()) {
+    let p = p.to_lowercase().next()?;
+
+    let i =
+      (next..text.len()).find(|&i| text[i].to_lowercase().next() == Some(p))?;
+
+    let is_consecutive = matches.last().is_some_and(|&last| last + 1 == i);
+    let is_word_start = i == 0
+      || !text[i - 1].is_alphanumeric()
+      || (text[i - 1].is_lowercase() && text[i].is_uppercase());
+
+    score += 1;
+
+    if is_consecutive {
+      score += 5;
+    }
+
+    if is_word_start {
+      score += 3;
+    }
+
+    score -= (i - next).min(5) as i32;
+
+    matches.push(i);
+    next = i + 1;
+  }
+
+  Some((score, matches))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Shortcuts;
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  #[test]
+  fn scores_consecutive_and_word_start_matches_higher() {
+    assert_eq!(fuzzy_match("of", "Open File").unwrap().1, vec![0, 5]);
+    assert!(fuzzy_match("xyz", "Open File").is_none());
+
+    let (word_starts, _) = fuzzy_match("of", "Open File").unwrap();
+    let (scattered, _) = fuzzy_match("of", "Go to definition").unwrap();
+
+    assert!(word_starts > scattered);
+
+    let (consecutive, _) = fuzzy_match("sav", "Save").unwrap();
+    let (gaps, _) = fuzzy_match("sav", "Show all views").unwrap();
+
+    assert!(consecutive > gaps);
+  }
+
+  #[test]
+  fn filters_and_runs_commands() {
+    let overlays = Overlays::default();
+    let commands = Commands::new(Shortcuts::default());
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    commands.register(Command::new("Close window", || {}));
+    commands.register(Command::new("Open file", {
+      let runs = runs.clone();
+
+      move || {
+        runs.fetch_add(1, Ordering::SeqCst);
+      }
+    }));
+
+    let open = Arc::new(Mutex::new(None));
+
+    let mut popup = PalettePopup {
+      commands,
+      overlays: overlays.clone(),
+      open: open.clone(),
+      query: String::new(),
+      results: vec![],
+      highlighted: 0,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    };
+
+    popup.search();
+
+    assert_eq!(popup.results.len(), 2);
+
+    popup.on(key(KeyCode::Char('o')));
+    popup.on(key(KeyCode::Char('f')));
+
+    assert_eq!(popup.results[0].command.name, "Open file");
+
+    *open.lock().unwrap() = Some(
+      overlays
+        .push_modal(Arc::new(Mutex::new(crate::Text::new("placeholder")))),
+    );
+
+    popup.on(key(KeyCode::Enter));
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert!(overlays.is_empty());
+  }
+}
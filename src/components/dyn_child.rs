@@ -1,11 +1,11 @@
 use super::{
   debug_assert_size_within_limits,
   CoreComponent,
-  Unit,
 };
 use crate::{
   ArcView,
-  ArcWidget,
+  Event,
+  EventHandlerResult,
   IntoView,
   View,
   Widget,
@@ -20,7 +20,6 @@ use std::sync::Arc;
 pub struct DynChild {
   child_fn: Arc<dyn Fn(Scope) -> View + Send + Sync>,
   child: ArcView,
-  #[cfg(debug_assertions)]
   created_at: &'static std::panic::Location<'static>,
 }
 
@@ -73,6 +72,26 @@ impl Widget for DynChild {
   fn draw(&self, surface: &mut dyn crate::DrawSurface) {
     self.child.lock().unwrap().draw(surface)
   }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.child.lock().unwrap().needs_focus()
+  }
+
+  fn has_focus(&self) -> bool {
+    self.child.lock().unwrap().has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    self.child.lock().unwrap().on(event)
+  }
+
+  fn focus(&mut self) {
+    self.child.lock().unwrap().focus()
+  }
+
+  fn blur(&mut self) {
+    self.child.lock().unwrap().blur()
+  }
 }
 
 impl DynChild {
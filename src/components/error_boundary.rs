@@ -0,0 +1,730 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  executor::request_render,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  create_rw_signal,
+  provide_context,
+  use_context,
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalUpdate,
+  SignalWith,
+  SignalWithUntracked,
+};
+use std::{
+  any::Any,
+  borrow::Cow,
+  cell::Cell,
+  collections::BTreeMap,
+  fmt,
+  panic::{
+    self,
+    AssertUnwindSafe,
+  },
+  sync::{
+    atomic::{
+      AtomicBool,
+      AtomicUsize,
+      Ordering,
+    },
+    Arc,
+    Mutex,
+  },
+};
+
+type ViewFn = Arc<dyn Fn(Scope) -> View + Send + Sync>;
+type FallbackFn = Arc<dyn Fn(Scope, Errors) -> View + Send + Sync>;
+
+thread_local! {
+  /// How many error boundaries are catching panics on this thread right now.
+  static CATCHING_PANICS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Unique across all boundaries, so that an error can be removed once the
+/// view which reported it is dropped.
+static NEXT_ERROR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns `true` if a panic on this thread would be caught by an
+/// [`ErrorBoundary`], in which case the panic hook set by
+/// [`run`](crate::run) leaves the terminal alone.
+pub(crate) fn is_catching_panics() -> bool {
+  CATCHING_PANICS.with(|catching| catching.get() > 0)
+}
+
+/// Runs `f`, returning the message it panicked with, if it did.
+fn catch_panics<T>(f: impl FnOnce() -> T) -> Result<T, Cow<'static, str>> {
+  CATCHING_PANICS.with(|catching| catching.set(catching.get() + 1));
+
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+  CATCHING_PANICS.with(|catching| catching.set(catching.get() - 1));
+
+  result.map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Cow<'static, str> {
+  if let Some(message) = payload.downcast_ref::<&'static str>() {
+    Cow::Borrowed(message)
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    Cow::Owned(message.clone())
+  } else {
+    "panicked".into()
+  }
+}
+
+/// The errors caught by an [`ErrorBoundary`], which its fallback is given.
+#[derive(Clone, Copy, Debug)]
+pub struct Errors {
+  errors: RwSignal<BTreeMap<usize, Cow<'static, str>>>,
+  /// Incremented on every reset, which creates the child again.
+  resets: RwSignal<usize>,
+}
+
+impl Errors {
+  fn new(cx: Scope) -> Self {
+    Self {
+      errors: create_rw_signal(cx, BTreeMap::new()),
+      resets: create_rw_signal(cx, 0),
+    }
+  }
+
+  /// Returns the message of every error, oldest first.
+  pub fn messages(&self) -> Vec<Cow<'static, str>> {
+    self
+      .errors
+      .with(|errors| errors.values().cloned().collect())
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.errors.with(BTreeMap::is_empty)
+  }
+
+  /// Clears every error, and creates the child of the boundary again.
+  pub fn reset(&self) {
+    self.errors.update(BTreeMap::clear);
+    self.resets.update(|resets| *resets += 1);
+  }
+
+  fn insert(&self, message: Cow<'static, str>) -> usize {
+    let id = NEXT_ERROR_ID.fetch_add(1, Ordering::Relaxed);
+
+    self.errors.update(|errors| {
+      errors.insert(id, message);
+    });
+
+    id
+  }
+
+  fn remove(&self, id: usize) {
+    // The boundary may have been disposed of first
+    self.errors.try_update(|errors| errors.remove(&id));
+  }
+}
+
+/// Shows a fallback instead of its child while the child has errors.
+///
+/// Errors are [`Err`] values rendered as views anywhere below the boundary,
+/// which are reported for as long as their view exists, and panics
+/// unwinding from the child while it is laid out, drawn or handles an
+/// event. A child which panicked is dropped, and only created again once
+/// the boundary is [reset](Errors::reset).
+///
+/// Without a boundary, an [`Err`] is rendered as its message.
+pub struct ErrorBoundary {
+  child_fn: ViewFn,
+  fallback_fn: FallbackFn,
+}
+
+impl fmt::Debug for ErrorBoundary {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ErrorBoundary")
+      .field("child_fn", &"Fn(Scope) -> View")
+      .field("fallback_fn", &"Fn(Scope, Errors) -> View")
+      .finish()
+  }
+}
+
+impl IntoView for ErrorBoundary {
+  fn into_view(self, cx: Scope) -> View {
+    let mut boundary = Boundary {
+      cx,
+      child_fn: self.child_fn,
+      fallback_fn: self.fallback_fn,
+      errors: Errors::new(cx),
+      resets: 0,
+      panicked: AtomicBool::new(false),
+      child: None,
+      fallback: None,
+    };
+
+    boundary.update_shown();
+
+    View::Widget(Arc::new(Mutex::new(boundary)))
+  }
+}
+
+impl ErrorBoundary {
+  /// Creates a boundary around the view returned by `child`, which is
+  /// called again whenever the boundary is reset.
+  pub fn new<V: IntoView>(
+    child: impl Fn(Scope) -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      child_fn: Arc::new(move |cx| child(cx).into_view(cx)),
+      fallback_fn: Arc::new(|cx, errors| ErrorList::new(errors).into_view(cx)),
+    }
+  }
+
+  /// Sets what to show while the child has errors. Defaults to their
+  /// messages, followed by a button which resets the boundary.
+  pub fn fallback<V: IntoView>(
+    mut self,
+    fallback: impl Fn(Errors) -> V + Send + Sync + 'static,
+  ) -> Self {
+    self.fallback_fn =
+      Arc::new(move |cx, errors| fallback(errors).into_view(cx));
+
+    self
+  }
+}
+
+struct Boundary {
+  cx: Scope,
+  child_fn: ViewFn,
+  fallback_fn: FallbackFn,
+  errors: Errors,
+  /// The number of resets the child was created after.
+  resets: usize,
+  /// Set when the child panicked while being drawn, after which it must
+  /// not be used anymore, since it may have been left half-updated.
+  panicked: AtomicBool,
+  child: Option<(View, Scope)>,
+  fallback: Option<(View, Scope)>,
+}
+
+impl fmt::Debug for Boundary {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ErrorBoundary")
+      .field("errors", &self.errors)
+      .field("child", &self.child.as_ref().map(|(view, _)| view))
+      .field("fallback", &self.fallback.as_ref().map(|(view, _)| view))
+      .finish()
+  }
+}
+
+impl Widget for Boundary {
+  fn name(&self) -> Cow<'static, str> {
+    "ErrorBoundary".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.update_shown();
+
+    if let (None, Some((child, _))) = (&self.fallback, &mut self.child) {
+      match catch_panics(|| child.layout(limits)) {
+        Ok(size) => return size,
+        Err(message) => {
+          self.panicked(message);
+          self.update_shown();
+        }
+      }
+    }
+
+    self
+      .shown_mut()
+      .map_or(limits.min_size(), |view| view.layout(limits))
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if let Some((fallback, _)) = &self.fallback {
+      fallback.draw(surface);
+    } else if let Some(child) = self.shown() {
+      if let Err(message) = catch_panics(|| child.draw(surface)) {
+        self.panicked(message);
+
+        // Another render shows the fallback
+        request_render();
+      }
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.shown().and_then(|view| view.needs_focus())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.shown().is_some_and(|view| view.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    self.update_shown();
+
+    if let (None, Some((child, _))) = (&self.fallback, &mut self.child) {
+      return match catch_panics(|| child.on(event)) {
+        Ok(result) => result,
+        Err(message) => {
+          self.panicked(message);
+          self.update_shown();
+
+          EventHandlerResult::Captured
+        }
+      };
+    }
+
+    match self.shown_mut() {
+      Some(view) => view.on(event),
+      None => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(view) = self.shown_mut() {
+      view.focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    if let Some(view) = self.shown_mut() {
+      view.blur();
+    }
+  }
+}
+
+impl Boundary {
+  fn shown(&self) -> Option<&View> {
+    match &self.fallback {
+      Some((fallback, _)) => Some(fallback),
+      None if self.panicked.load(Ordering::SeqCst) => None,
+      None => self.child.as_ref().map(|(child, _)| child),
+    }
+  }
+
+  fn shown_mut(&mut self) -> Option<&mut View> {
+    match &mut self.fallback {
+      Some((fallback, _)) => Some(fallback),
+      None if self.panicked.load(Ordering::SeqCst) => None,
+      None => self.child.as_mut().map(|(child, _)| child),
+    }
+  }
+
+  fn panicked(&self, message: Cow<'static, str>) {
+    self.panicked.store(true, Ordering::SeqCst);
+    self.errors.insert(message);
+  }
+
+  /// Drops the child if it panicked or the boundary was reset, creates it
+  /// again once there are no errors, and shows or hides the fallback
+  /// depending on whether there are any.
+  fn update_shown(&mut self) {
+    let had_focus = self.has_focus();
+    let showed_fallback = self.fallback.is_some();
+    let resets = self.errors.resets.get_untracked();
+
+    if self.panicked.swap(false, Ordering::SeqCst) || resets != self.resets {
+      self.resets = resets;
+
+      if let Some((_, cx)) = self.child.take() {
+        cx.dispose();
+      }
+    }
+
+    let created_child = self.child.is_none()
+      && self.errors.errors.with_untracked(BTreeMap::is_empty);
+
+    if created_child {
+      self.create_child();
+    }
+
+    let has_errors = !self.errors.errors.with_untracked(BTreeMap::is_empty);
+
+    if has_errors && self.fallback.is_none() {
+      let errors = self.errors;
+      let fallback_fn = self.fallback_fn.clone();
+
+      let ((view, cx), _) = self
+        .cx
+        .run_child_scope(|cx| (cx.untrack(|| fallback_fn(cx, errors)), cx));
+
+      self.fallback = Some((view, cx));
+    } else if !has_errors {
+      if let Some((_, cx)) = self.fallback.take() {
+        cx.dispose();
+      }
+    }
+
+    let changed = showed_fallback != self.fallback.is_some()
+      || (created_child && self.fallback.is_none());
+
+    if had_focus && changed {
+      if let Some(view) = self.shown_mut() {
+        view.on(Event::NextFocus);
+      }
+    }
+  }
+
+  fn create_child(&mut self) {
+    let errors = self.errors;
+    let child_fn = self.child_fn.clone();
+
+    let ((child, cx), _) = self.cx.run_child_scope(|cx| {
+      provide_context(cx, errors);
+
+      (catch_panics(|| cx.untrack(|| child_fn(cx))), cx)
+    });
+
+    match child {
+      Ok(child) => self.child = Some((child, cx)),
+      Err(message) => {
+        cx.dispose();
+        errors.insert(message);
+      }
+    }
+  }
+}
+
+impl<T: IntoView, E: fmt::Display> IntoView for Result<T, E> {
+  fn into_view(self, cx: Scope) -> View {
+    match self {
+      Ok(view) => view.into_view(cx),
+      Err(error) => ErrorView::new(cx, error.to_string().into()).into_view(cx),
+    }
+  }
+}
+
+/// What an [`Err`] is rendered as. Under an [`ErrorBoundary`], the error is
+/// reported to it for as long as this view exists, and nothing is drawn.
+struct ErrorView {
+  message: Cow<'static, str>,
+  reported: Option<(Errors, usize)>,
+  text: String,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for ErrorView {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ErrorView")
+      .field("message", &self.message)
+      .field("reported", &self.reported.is_some())
+      .finish()
+  }
+}
+
+impl Drop for ErrorView {
+  fn drop(&mut self) {
+    if let Some((errors, id)) = self.reported {
+      errors.remove(id);
+    }
+  }
+}
+
+impl Widget for ErrorView {
+  fn name(&self) -> Cow<'static, str> {
+    "ErrorView".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    if self.reported.is_some() {
+      return limits.min_size();
+    }
+
+    self.text = clip_to_width(&format!("✖ {}", self.message), limits.max_width);
+
+    Size {
+      width: (self.text.chars().count() as u16).max(limits.min_width),
+      height: limits.max_height.min(1).max(limits.min_height),
+    }
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if self.reported.is_none() {
+      surface.write_with_style(
+        XY::default(),
+        &self.text,
+        self.theme.get(&self.name()).error,
+      );
+    }
+  }
+}
+
+impl IntoView for ErrorView {
+  fn into_view(self, _: Scope) -> View {
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl ErrorView {
+  fn new(cx: Scope, message: Cow<'static, str>) -> Self {
+    let reported = use_context::<Errors>(cx)
+      .map(|errors| (errors, errors.insert(message.clone())));
+
+    Self {
+      message,
+      reported,
+      text: String::new(),
+      theme: ThemeHandle::new(cx),
+    }
+  }
+}
+
+/// The default fallback of an [`ErrorBoundary`], listing its errors above
+/// a button which resets it.
+struct ErrorList {
+  errors: Errors,
+  lines: Vec<String>,
+  button: String,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for ErrorList {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ErrorList")
+      .field("lines", &self.lines)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl Widget for ErrorList {
+  fn name(&self) -> Cow<'static, str> {
+    "ErrorList".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    // Leaves room for the button
+    let max_lines = limits.max_height.saturating_sub(1) as usize;
+
+    self.lines = self.errors.errors.with_untracked(|errors| {
+      errors
+        .values()
+        .take(max_lines)
+        .map(|message| clip_to_width(&format!("✖ {message}"), limits.max_width))
+        .collect()
+    });
+    self.button = clip_to_width("<Retry>", limits.max_width);
+
+    let width = self
+      .lines
+      .iter()
+      .chain([&self.button])
+      .map(|line| line.chars().count() as u16)
+      .max()
+      .unwrap_or_default();
+
+    self.size = Size {
+      width: width.max(limits.min_width),
+      height: (self.lines.len() as u16 + 1)
+        .min(limits.max_height)
+        .max(limits.min_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let theme = self.theme.get(&self.name());
+
+    for (y, line) in self.lines.iter().enumerate() {
+      surface.write_with_style(XY { x: 0, y: y as u16 }, line, theme.error);
+    }
+
+    surface.write_with_style(
+      XY {
+        x: 0,
+        y: self.lines.len() as u16,
+      },
+      &self.button,
+      if self.focused {
+        theme.focus
+      } else {
+        theme.text
+      },
+    );
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent {
+        code: KeyCode::Enter | KeyCode::Char(' '),
+        ..
+      }) if self.focused => {
+        self.errors.reset();
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        row,
+        ..
+      }) if row as usize == self.lines.len() => {
+        self.focused = true;
+        self.errors.reset();
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for ErrorList {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl ErrorList {
+  fn new(errors: Errors) -> Self {
+    Self {
+      errors,
+      lines: vec![],
+      button: String::new(),
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::event::KeyModifiers;
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+    SignalGet,
+    SignalSet,
+  };
+
+  fn render(view: &mut View) -> String {
+    let mut buf = vec![];
+    let mut surface = crate::BufDrawSurface::new(&mut buf, (20u16, 2u16));
+
+    view.layout(Limits {
+      min_width: 0,
+      max_width: 20,
+      min_height: 0,
+      max_height: 2,
+    });
+    view.draw(&mut surface);
+
+    drop(surface);
+
+    String::from_utf8(buf).unwrap()
+  }
+
+  #[test]
+  fn rendered_errors_show_the_fallback_while_they_exist() {
+    run_scope(create_runtime(), |cx| {
+      let value = create_rw_signal(cx, Ok::<_, String>(1));
+
+      let mut view = ErrorBoundary::new(move |_| {
+        move || value.get().map(|value| format!("value {value}"))
+      })
+      .fallback(|errors: Errors| move || errors.messages().join(", "))
+      .into_view(cx);
+
+      assert!(render(&mut view).contains("value 1"));
+
+      value.set(Err("not a number".into()));
+
+      assert!(render(&mut view).contains("not a number"));
+
+      value.set(Ok(2));
+
+      assert!(render(&mut view).contains("value 2"));
+    });
+  }
+
+  #[derive(Debug)]
+  struct Bomb(Arc<AtomicBool>);
+
+  impl Widget for Bomb {
+    fn layout(&mut self, limits: Limits) -> Size {
+      if self.0.load(Ordering::SeqCst) {
+        panic!("boom");
+      }
+
+      limits.min_size()
+    }
+
+    fn draw(&self, _: &mut dyn DrawSurface) {}
+  }
+
+  #[test]
+  fn panicking_child_is_created_again_on_reset() {
+    run_scope(create_runtime(), |cx| {
+      let armed = Arc::new(AtomicBool::new(true));
+      let created = Arc::new(AtomicUsize::new(0));
+
+      let mut view = ErrorBoundary::new({
+        let armed = armed.clone();
+        let created = created.clone();
+
+        move |_| {
+          created.fetch_add(1, Ordering::SeqCst);
+
+          View::Widget(Arc::new(Mutex::new(Bomb(armed.clone()))))
+        }
+      })
+      .into_view(cx);
+
+      let output = render(&mut view);
+
+      assert!(output.contains("boom"));
+      assert!(output.contains("<Retry>"));
+
+      armed.store(false, Ordering::SeqCst);
+
+      view.on(Event::NextFocus);
+      view.on(Event::Key(KeyEvent::new(
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+      )));
+
+      assert!(!render(&mut view).contains("boom"));
+      assert_eq!(created.load(Ordering::SeqCst), 2);
+    });
+  }
+}
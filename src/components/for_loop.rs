@@ -0,0 +1,465 @@
+use super::{
+  debug_assert_size_within_limits,
+  CoreComponent,
+};
+use crate::{
+  dispatch_to_children,
+  ArcView,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Rect,
+  Size,
+  View,
+  Widget,
+  XY,
+};
+use core::fmt;
+use leptos_reactive::{
+  create_effect,
+  Scope,
+};
+use std::{
+  collections::HashMap,
+  hash::Hash,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Renders a keyed list of children, stacked vertically.
+///
+/// Whenever `each` changes, the new items are diffed against the previous
+/// ones by their key, so that only the children of new items are created,
+/// the children of removed items are disposed of along with their reactive
+/// scopes, and the rest are moved into place, keeping their widget state,
+/// such as focus, intact.
+pub struct For {
+  setup_fn: Option<Box<dyn FnOnce(Scope, PendingChildren) + Send + Sync>>,
+  /// Children produced by the last diff, which have not been laid out yet.
+  pending: PendingChildren,
+  children: Vec<ArcView>,
+  child_rects: Vec<Rect>,
+  created_at: &'static std::panic::Location<'static>,
+}
+
+type PendingChildren = Arc<Mutex<Option<Vec<ArcView>>>>;
+
+/// A child created for a keyed item, and the scope it was created in.
+struct KeyedChild<K> {
+  key: K,
+  view: ArcView,
+  cx: Scope,
+}
+
+impl fmt::Debug for For {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("For")
+      .field("children", &self.children)
+      .field("child_rects", &self.child_rects)
+      .field("created_at", &self.created_at)
+      .finish()
+  }
+}
+
+impl fmt::Display for For {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "<For>")?;
+    for child in &self.children {
+      child.lock().unwrap().fmt(f)?;
+    }
+    writeln!(f, "</For>")
+  }
+}
+
+impl IntoView for For {
+  fn into_view(mut self, cx: Scope) -> View {
+    if let Some(setup_fn) = self.setup_fn.take() {
+      setup_fn(cx, self.pending.clone());
+    }
+
+    CoreComponent::For(self).into_view(cx)
+  }
+}
+
+impl Widget for For {
+  fn name(&self) -> std::borrow::Cow<'static, str> {
+    "For".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    if let Some(children) = self.pending.lock().unwrap().take() {
+      self.children = children;
+    }
+
+    self.child_rects.clear();
+
+    let mut width = 0;
+    let mut y = 0;
+
+    for child in &self.children {
+      let child_limits = Limits {
+        min_width: 0,
+        max_width: limits.max_width,
+        min_height: 0,
+        max_height: limits.max_height - y,
+      };
+
+      let size = child.lock().unwrap().layout(child_limits);
+
+      self.child_rects.push(Rect {
+        top_left: XY { x: 0, y },
+        size,
+      });
+
+      width = width.max(size.width);
+      y += size.height;
+    }
+
+    let size = Size {
+      width: width.max(limits.min_width),
+      height: y.max(limits.min_height),
+    };
+
+    debug_assert_size_within_limits(limits, size, self.created_at);
+
+    size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    for (child, rect) in self.children.iter().zip(&self.child_rects) {
+      if rect.size.width == 0 || rect.size.height == 0 {
+        continue;
+      }
+
+      surface.shrink(
+        rect.top_left,
+        rect.size,
+        Box::new(|surface| child.lock().unwrap().draw(surface)),
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self
+      .children
+      .iter()
+      .filter_map(|child| child.lock().unwrap().needs_focus())
+      .reduce(|a, b| a || b)
+  }
+
+  fn has_focus(&self) -> bool {
+    self
+      .children
+      .iter()
+      .any(|child| child.lock().unwrap().has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let mut children = self
+      .children
+      .iter()
+      .map(|child| child.lock().unwrap())
+      .collect::<Vec<_>>();
+
+    let mut children = children
+      .iter_mut()
+      .zip(&self.child_rects)
+      .map(|(child, rect)| (*rect, &mut **child as &mut dyn Widget))
+      .collect::<Vec<_>>();
+
+    dispatch_to_children(&mut children, event)
+  }
+
+  fn focus(&mut self) {
+    if let Some(child) = self
+      .children
+      .iter()
+      .find(|child| child.lock().unwrap().needs_focus() == Some(true))
+    {
+      child.lock().unwrap().focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    for child in &self.children {
+      let mut child = child.lock().unwrap();
+
+      if child.has_focus() {
+        child.blur();
+      }
+    }
+  }
+}
+
+impl For {
+  /// Creates a child with `view` for every item returned by `each`, keyed
+  /// by `key`.
+  #[track_caller]
+  pub fn new<I, T, K, V>(
+    each: impl Fn() -> I + Send + Sync + 'static,
+    key: impl Fn(&T) -> K + Send + Sync + 'static,
+    view: impl Fn(T) -> V + Send + Sync + 'static,
+  ) -> Self
+  where
+    I: IntoIterator<Item = T>,
+    K: Eq + Hash + 'static,
+    V: IntoView,
+  {
+    let setup_fn = move |cx: Scope, pending: PendingChildren| {
+      create_effect(cx, move |prev: Option<Vec<KeyedChild<K>>>| {
+        let mut prev_children = HashMap::new();
+
+        for child in prev.into_iter().flatten() {
+          if let Some((_, duplicate_cx)) =
+            prev_children.insert(child.key, (child.view, child.cx))
+          {
+            duplicate_cx.dispose();
+          }
+        }
+
+        let children = each()
+          .into_iter()
+          .map(|item| {
+            let key = key(&item);
+
+            match prev_children.remove(&key) {
+              Some((view, child_cx)) => KeyedChild {
+                key,
+                view,
+                cx: child_cx,
+              },
+              None => {
+                let ((view, child_cx), _) = cx.run_child_scope(|cx| {
+                  let view = cx.untrack(|| view(item).into_view(cx));
+
+                  (Arc::new(Mutex::new(view)), cx)
+                });
+
+                KeyedChild {
+                  key,
+                  view,
+                  cx: child_cx,
+                }
+              }
+            }
+          })
+          .collect::<Vec<_>>();
+
+        for (_, child_cx) in prev_children.into_values() {
+          child_cx.dispose();
+        }
+
+        *pending.lock().unwrap() =
+          Some(children.iter().map(|child| child.view.clone()).collect());
+
+        children
+      });
+    };
+
+    Self {
+      setup_fn: Some(Box::new(setup_fn)),
+      pending: Default::default(),
+      children: vec![],
+      child_rects: vec![],
+      created_at: std::panic::Location::caller(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::get_widget_output,
+    Checkbox,
+  };
+  use crossterm::{
+    cursor::MoveTo,
+    event::{
+      KeyCode,
+      KeyEvent,
+      KeyModifiers,
+    },
+    style::Print,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalGet,
+    SignalSet,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn child_ptrs(view: &mut View) -> Vec<*const Mutex<View>> {
+    view.layout(Limits::strict(10, 10));
+
+    let View::CoreComponent(CoreComponent::For(for_)) = view else {
+      unreachable!()
+    };
+
+    for_.children.iter().map(Arc::as_ptr).collect()
+  }
+
+  #[test]
+  fn stacks_children_vertically() {
+    run_scope(create_runtime(), |cx| {
+      let items = create_rw_signal(cx, vec!["a", "bc"]);
+
+      let view =
+        For::new(move || items.get(), |item| *item, |item| item).into_view(cx);
+
+      let (size, output) = get_widget_output(view, (5, 5), (0, 0));
+
+      assert_eq!(size, (2, 2).into());
+
+      assert_eq!(
+        output,
+        commands![MoveTo(0, 0), Print("a"), MoveTo(0, 1), Print("bc")]
+      );
+    });
+  }
+
+  #[test]
+  fn reordering_moves_existing_children() {
+    run_scope(create_runtime(), |cx| {
+      let items = create_rw_signal(cx, vec![1, 2, 3]);
+
+      let mut view =
+        For::new(move || items.get(), |item| *item, |item| item.to_string())
+          .into_view(cx);
+
+      let before = child_ptrs(&mut view);
+
+      items.set(vec![3, 1, 4]);
+
+      let after = child_ptrs(&mut view);
+
+      assert_eq!(after[0], before[2]);
+      assert_eq!(after[1], before[0]);
+      assert!(!before.contains(&after[2]));
+    });
+  }
+
+  #[test]
+  fn removed_children_are_disposed() {
+    run_scope(create_runtime(), |cx| {
+      let items = create_rw_signal(cx, vec![1, 2]);
+      let tick = create_rw_signal(cx, 0);
+      let runs = Arc::new(AtomicUsize::new(0));
+
+      let mut view = For::new(move || items.get(), |item| *item, {
+        let runs = runs.clone();
+
+        move |item: i32| {
+          let runs = runs.clone();
+
+          move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+
+            format!("{item} {}", tick.get())
+          }
+        }
+      })
+      .into_view(cx);
+
+      child_ptrs(&mut view);
+
+      assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+      items.set(vec![2]);
+      tick.set(1);
+
+      assert_eq!(runs.load(Ordering::SeqCst), 3);
+    });
+  }
+
+  #[test]
+  fn focus_is_kept_across_reorders() {
+    run_scope(create_runtime(), |cx| {
+      let items = create_rw_signal(cx, vec![1, 2]);
+
+      let mut view = For::new(
+        move || items.get(),
+        |item| *item,
+        move |_| Checkbox::new("", create_rw_signal(cx, false)),
+      )
+      .into_view(cx);
+
+      view.layout(Limits::strict(10, 10));
+      view.on(Event::NextFocus);
+      view.on(Event::NextFocus);
+
+      items.set(vec![2, 1]);
+      view.layout(Limits::strict(10, 10));
+
+      let View::CoreComponent(CoreComponent::For(for_)) = &view else {
+        unreachable!()
+      };
+
+      assert!(for_.children[0].lock().unwrap().has_focus());
+      assert!(!for_.children[1].lock().unwrap().has_focus());
+    });
+  }
+
+  #[test]
+  fn focus_goes_to_the_first_child() {
+    run_scope(create_runtime(), |cx| {
+      let mut view = For::new(
+        || vec![1, 2],
+        |item| *item,
+        move |_| Checkbox::new("", create_rw_signal(cx, false)),
+      )
+      .into_view(cx);
+
+      view.layout(Limits::strict(10, 10));
+      view.focus();
+
+      let View::CoreComponent(CoreComponent::For(for_)) = &view else {
+        unreachable!()
+      };
+
+      assert!(for_.children[0].lock().unwrap().has_focus());
+      assert!(!for_.children[1].lock().unwrap().has_focus());
+    });
+  }
+
+  #[test]
+  fn uncaptured_batched_events_are_handed_back() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, false);
+
+      let mut view = For::new(
+        || vec![1],
+        |item| *item,
+        move |_| Checkbox::new("", checked),
+      )
+      .into_view(cx);
+
+      view.layout(Limits::strict(10, 10));
+      view.focus();
+
+      let key =
+        |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+      let result = view.on(Event::Batch(vec![key(' '), key('x')]));
+
+      assert!(checked.get());
+      assert!(matches!(
+        result,
+        EventHandlerResult::Mapped(Event::Batch(events))
+          if matches!(
+            events.as_slice(),
+            [Event::Key(KeyEvent { code: KeyCode::Char('x'), .. })]
+          )
+      ));
+    });
+  }
+}
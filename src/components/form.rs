@@ -0,0 +1,1199 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  dispatch_to_children,
+  spawn_local,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Rect,
+  Size,
+  Style,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  create_effect,
+  create_rw_signal,
+  RwSignal,
+  Scope,
+  SignalGet,
+  SignalGetUntracked,
+  SignalSet,
+  SignalWith,
+  SignalWithUntracked,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  future::{
+    ready,
+    Future,
+  },
+  pin::Pin,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// The outcome of a validator, with the message to show if it failed.
+pub type ValidationResult = Result<(), Cow<'static, str>>;
+
+type Validator<T> = Arc<dyn Fn(&T) -> ValidationResult + Send + Sync>;
+
+type AsyncValidator<T> = Arc<
+  dyn Fn(T) -> Pin<Box<dyn Future<Output = ValidationResult>>> + Send + Sync,
+>;
+
+/// When the validators of a [`Field`] run, besides on submit.
+///
+/// Once a field shows an error, or once the form was submitted, its
+/// validators also run on every change so that errors go away as soon as
+/// they are fixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateOn {
+  /// Whenever the value changes, once the field is dirty or touched.
+  Change,
+  /// When the field's input loses focus.
+  #[default]
+  Blur,
+  /// Only when the form is submitted.
+  Submit,
+}
+
+/// A value edited as part of a [`Form`], created with [`Form::field`].
+///
+/// The value is held in a signal, which is what inputs such as
+/// [`Checkbox`](crate::Checkbox) or [`Select`](crate::Select) should be
+/// bound to.
+pub struct Field<T: 'static> {
+  value: RwSignal<T>,
+  error: RwSignal<Option<Cow<'static, str>>>,
+  touched: RwSignal<bool>,
+  validating: RwSignal<bool>,
+  submitted: RwSignal<bool>,
+  inner: Arc<Mutex<FieldInner<T>>>,
+}
+
+struct FieldInner<T> {
+  initial: T,
+  validators: Vec<Validator<T>>,
+  async_validators: Vec<AsyncValidator<T>>,
+  validate_on: ValidateOn,
+  /// Incremented on every validation, so that the results of outdated
+  /// async validators are ignored.
+  generation: usize,
+}
+
+impl<T> Clone for Field<T> {
+  fn clone(&self) -> Self {
+    Self {
+      value: self.value,
+      error: self.error,
+      touched: self.touched,
+      validating: self.validating,
+      submitted: self.submitted,
+      inner: self.inner.clone(),
+    }
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Field<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let inner = self.inner.lock().unwrap();
+
+    f.debug_struct("Field")
+      .field("value", &self.value)
+      .field("error", &self.error)
+      .field("touched", &self.touched)
+      .field("validating", &self.validating)
+      .field("initial", &inner.initial)
+      .field("validators", &inner.validators.len())
+      .field("async_validators", &inner.async_validators.len())
+      .field("validate_on", &inner.validate_on)
+      .finish()
+  }
+}
+
+impl<T> Field<T>
+where
+  T: Clone + PartialEq + Send + Sync + 'static,
+{
+  fn new(cx: Scope, initial: T, submitted: RwSignal<bool>) -> Self {
+    Self {
+      value: create_rw_signal(cx, initial.clone()),
+      error: create_rw_signal(cx, None),
+      touched: create_rw_signal(cx, false),
+      validating: create_rw_signal(cx, false),
+      submitted,
+      inner: Arc::new(Mutex::new(FieldInner {
+        initial,
+        validators: vec![],
+        async_validators: vec![],
+        validate_on: ValidateOn::default(),
+        generation: 0,
+      })),
+    }
+  }
+
+  /// Adds a validator, which runs before any async one.
+  ///
+  /// The validator may read other fields with [`SignalGet::get`], making
+  /// it a cross-field validator which also runs when they change, such as
+  /// one checking that a confirmation matches the original value.
+  pub fn validate(
+    self,
+    validator: impl Fn(&T) -> ValidationResult + Send + Sync + 'static,
+  ) -> Self {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .validators
+      .push(Arc::new(validator));
+
+    self
+  }
+
+  /// Adds a validator which needs to wait, such as one checking that a
+  /// name isn't taken. It only runs once every other validator passed.
+  /// While it is pending, the field is [validating](Self::is_validating).
+  pub fn validate_async<F>(
+    self,
+    validator: impl Fn(T) -> F + Send + Sync + 'static,
+  ) -> Self
+  where
+    F: Future<Output = ValidationResult> + 'static,
+  {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .async_validators
+      .push(Arc::new(move |value| {
+        Box::pin(validator(value)) as Pin<Box<_>>
+      }));
+
+    self
+  }
+
+  /// Sets when the validators run. Defaults to [`ValidateOn::Blur`].
+  pub fn validate_on(self, validate_on: ValidateOn) -> Self {
+    self.inner.lock().unwrap().validate_on = validate_on;
+
+    self
+  }
+
+  /// Returns the signal holding the field's value.
+  pub fn value(&self) -> RwSignal<T> {
+    self.value
+  }
+
+  /// Returns the message of the last failed validation, if any.
+  pub fn error(&self) -> Option<Cow<'static, str>> {
+    self.error.get()
+  }
+
+  /// Returns `true` while async validators are running.
+  pub fn is_validating(&self) -> bool {
+    self.validating.get()
+  }
+
+  /// Returns `true` if the value differs from the initial one.
+  pub fn is_dirty(&self) -> bool {
+    let inner = self.inner.lock().unwrap();
+
+    self.value.with(|value| *value != inner.initial)
+  }
+
+  /// Returns `true` if the field's input has lost focus since the form was
+  /// created or reset.
+  pub fn is_touched(&self) -> bool {
+    self.touched.get()
+  }
+
+  /// Marks the field as touched, validating it depending on
+  /// [`Field::validate_on`]. This is done by [`FormView`] when the field's
+  /// input loses focus.
+  pub fn touch(&self) {
+    self.touched.set(true);
+
+    let validate_on = self.inner.lock().unwrap().validate_on;
+
+    if validate_on != ValidateOn::Submit || self.should_revalidate() {
+      let validation = self.run_validation();
+
+      spawn_local(async move {
+        validation.await;
+      });
+    }
+  }
+
+  /// Resets the value to the initial one, and forgets about errors and
+  /// whether the field was touched.
+  pub fn reset(&self) {
+    let initial = {
+      let mut inner = self.inner.lock().unwrap();
+
+      // Outdated async validators can't bring errors back
+      inner.generation += 1;
+
+      inner.initial.clone()
+    };
+
+    self.value.set(initial);
+    self.error.set(None);
+    self.touched.set(false);
+    self.validating.set(false);
+  }
+
+  /// Returns `true` if validators should run on every change, regardless
+  /// of [`Field::validate_on`].
+  fn should_revalidate(&self) -> bool {
+    self.error.with_untracked(|error| error.is_some())
+      || self.submitted.get_untracked()
+  }
+
+  /// Runs the validators which don't need to wait, tracking whatever they
+  /// read.
+  fn check(&self) -> ValidationResult {
+    let validators = self.inner.lock().unwrap().validators.clone();
+
+    self.value.with(|value| {
+      validators.iter().try_for_each(|validator| validator(value))
+    })
+  }
+
+  /// Validates the field, returning whether it is valid once async
+  /// validators are done. Errors of sync validators are shown right away.
+  fn run_validation(&self) -> Pin<Box<dyn Future<Output = bool>>> {
+    let result = self.check();
+
+    self.apply(result)
+  }
+
+  /// Shows the result of sync validators, then runs async ones if they
+  /// passed.
+  fn apply(
+    &self,
+    result: ValidationResult,
+  ) -> Pin<Box<dyn Future<Output = bool>>> {
+    let (generation, async_validators) = {
+      let mut inner = self.inner.lock().unwrap();
+
+      inner.generation += 1;
+
+      (inner.generation, inner.async_validators.clone())
+    };
+
+    if let Err(error) = result {
+      self.error.set(Some(error));
+      self.validating.set(false);
+
+      return Box::pin(ready(false));
+    }
+
+    if async_validators.is_empty() {
+      self.error.set(None);
+      self.validating.set(false);
+
+      return Box::pin(ready(true));
+    }
+
+    self.validating.set(true);
+
+    let value = self.value.get_untracked();
+
+    let futures = async_validators
+      .iter()
+      .map(|validator| validator(value.clone()))
+      .collect::<Vec<_>>();
+
+    let field = self.clone();
+
+    Box::pin(async move {
+      let mut result = Ok(());
+
+      for future in futures {
+        result = future.await;
+
+        if result.is_err() {
+          break;
+        }
+      }
+
+      if field.inner.lock().unwrap().generation != generation {
+        return false;
+      }
+
+      field.validating.set(false);
+      field.error.set(result.as_ref().err().cloned());
+
+      result.is_ok()
+    })
+  }
+
+  /// Validates the field when its value, or anything its validators read,
+  /// changes.
+  fn watch(&self, cx: Scope) {
+    let field = self.clone();
+
+    create_effect(cx, move |previous: Option<()>| {
+      let result = field.check();
+
+      if previous.is_none() {
+        return;
+      }
+
+      let validate_on = field.inner.lock().unwrap().validate_on;
+
+      let should_validate = field.should_revalidate()
+        || (validate_on == ValidateOn::Change
+          && (field.touched.get_untracked() || field.is_dirty_untracked()));
+
+      if should_validate {
+        let validation = field.apply(result);
+
+        spawn_local(async move {
+          validation.await;
+        });
+      }
+    });
+  }
+
+  fn is_dirty_untracked(&self) -> bool {
+    let inner = self.inner.lock().unwrap();
+
+    self.value.with_untracked(|value| *value != inner.initial)
+  }
+}
+
+/// A [`Field`] of any type, as stored by its [`Form`].
+trait AnyField: Send + Sync {
+  fn run_validation(&self) -> Pin<Box<dyn Future<Output = bool>>>;
+  fn watch(&self, cx: Scope);
+  fn touch(&self);
+  fn reset(&self);
+  fn error(&self) -> Option<Cow<'static, str>>;
+  fn is_validating(&self) -> bool;
+  fn is_dirty(&self) -> bool;
+  fn is_touched(&self) -> bool;
+}
+
+impl<T> AnyField for Field<T>
+where
+  T: Clone + PartialEq + Send + Sync + 'static,
+{
+  fn run_validation(&self) -> Pin<Box<dyn Future<Output = bool>>> {
+    Field::run_validation(self)
+  }
+
+  fn watch(&self, cx: Scope) {
+    Field::watch(self, cx)
+  }
+
+  fn touch(&self) {
+    Field::touch(self)
+  }
+
+  fn reset(&self) {
+    Field::reset(self)
+  }
+
+  fn error(&self) -> Option<Cow<'static, str>> {
+    Field::error(self)
+  }
+
+  fn is_validating(&self) -> bool {
+    Field::is_validating(self)
+  }
+
+  fn is_dirty(&self) -> bool {
+    Field::is_dirty(self)
+  }
+
+  fn is_touched(&self) -> bool {
+    Field::is_touched(self)
+  }
+}
+
+/// A group of [`Field`]s which are validated and submitted together,
+/// usually shown with a [`FormView`].
+///
+/// Submitting the form with [`Form::submit`] validates every field, and
+/// calls the handler set with [`Form::on_submit`] only if they are all
+/// valid, once async validators are done.
+#[derive(Clone)]
+pub struct Form {
+  cx: Scope,
+  inner: Arc<Mutex<FormInner>>,
+  /// Form-level validators, whose error is the form's.
+  checks: Field<()>,
+  submitted: RwSignal<bool>,
+  submitting: RwSignal<bool>,
+}
+
+#[derive(Default)]
+struct FormInner {
+  fields: Vec<Arc<dyn AnyField>>,
+  on_submit: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl fmt::Debug for Form {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Form")
+      .field("fields", &self.inner.lock().unwrap().fields.len())
+      .field("submitted", &self.submitted)
+      .field("submitting", &self.submitting)
+      .finish()
+  }
+}
+
+impl Form {
+  pub fn new(cx: Scope) -> Self {
+    let submitted = create_rw_signal(cx, false);
+
+    Self {
+      cx,
+      inner: Default::default(),
+      checks: Field::new(cx, (), submitted).validate_on(ValidateOn::Submit),
+      submitted,
+      submitting: create_rw_signal(cx, false),
+    }
+  }
+
+  /// Sets the handler called when the form is submitted while valid.
+  pub fn on_submit(self, on_submit: impl Fn() + Send + Sync + 'static) -> Self {
+    self.inner.lock().unwrap().on_submit = Some(Arc::new(on_submit));
+
+    self
+  }
+
+  /// Adds a field starting with the value `initial`.
+  pub fn field<T>(&self, initial: T) -> Field<T>
+  where
+    T: Clone + PartialEq + Send + Sync + 'static,
+  {
+    let field = Field::new(self.cx, initial, self.submitted);
+
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .fields
+      .push(Arc::new(field.clone()));
+
+    field
+  }
+
+  /// Adds a validator for the form as a whole, which usually reads
+  /// several fields. It runs on submit, and on every change after that.
+  pub fn validate(
+    &self,
+    validator: impl Fn() -> ValidationResult + Send + Sync + 'static,
+  ) {
+    self.checks.clone().validate(move |_| validator());
+  }
+
+  /// Adds an async validator for the form as a whole, see
+  /// [`Form::validate`].
+  pub fn validate_async<F>(
+    &self,
+    validator: impl Fn() -> F + Send + Sync + 'static,
+  ) where
+    F: Future<Output = ValidationResult> + 'static,
+  {
+    self.checks.clone().validate_async(move |_| validator());
+  }
+
+  /// Returns the message of the last failed form-level validation, if
+  /// any.
+  pub fn error(&self) -> Option<Cow<'static, str>> {
+    self.checks.error()
+  }
+
+  /// Returns `true` if no field nor form-level validator currently has an
+  /// error, and none is validating.
+  pub fn is_valid(&self) -> bool {
+    self
+      .all_fields()
+      .iter()
+      .all(|field| field.error().is_none() && !field.is_validating())
+  }
+
+  /// Returns `true` if any field differs from its initial value.
+  pub fn is_dirty(&self) -> bool {
+    self.all_fields().iter().any(|field| field.is_dirty())
+  }
+
+  /// Returns `true` if any field has been touched.
+  pub fn is_touched(&self) -> bool {
+    self.all_fields().iter().any(|field| field.is_touched())
+  }
+
+  /// Returns `true` while a submission is waiting on async validators.
+  pub fn is_submitting(&self) -> bool {
+    self.submitting.get()
+  }
+
+  /// Validates every field, and calls the submit handler if they are all
+  /// valid. Does nothing if a submission is already in progress.
+  pub fn submit(&self) {
+    if self.submitting.get_untracked() {
+      return;
+    }
+
+    self.submitted.set(true);
+
+    let validations = self
+      .all_fields()
+      .iter()
+      .map(|field| field.run_validation())
+      .collect::<Vec<_>>();
+
+    let on_submit = self.inner.lock().unwrap().on_submit.clone();
+    let submitting = self.submitting;
+
+    submitting.set(true);
+
+    spawn_local(async move {
+      let mut is_valid = true;
+
+      // Every validation is awaited, so that all errors are shown
+      for validation in validations {
+        is_valid &= validation.await;
+      }
+
+      submitting.set(false);
+
+      if let (true, Some(on_submit)) = (is_valid, on_submit) {
+        on_submit();
+      }
+    });
+  }
+
+  /// Resets every field to its initial value, and forgets about errors
+  /// and previous submissions.
+  pub fn reset(&self) {
+    self.submitted.set(false);
+
+    for field in self.all_fields() {
+      field.reset();
+    }
+  }
+
+  fn all_fields(&self) -> Vec<Arc<dyn AnyField>> {
+    let mut fields = self.inner.lock().unwrap().fields.clone();
+
+    fields.push(Arc::new(self.checks.clone()));
+
+    fields
+  }
+}
+
+/// Shows the fields of a [`Form`] one below the other, each with a label
+/// and its error underneath, followed by the form-level error and a submit
+/// button.
+///
+/// A field is touched when its input loses focus. Tab moves through the
+/// inputs and then to the submit button, which submits the form when
+/// activated with Enter, Space or a click.
+pub struct FormView {
+  form: Form,
+  rows: Vec<FormRow>,
+  submit: SubmitButton,
+  label_width: u16,
+  /// Form-level error, as laid out.
+  error: Option<(u16, String)>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+struct FormRow {
+  label: Cow<'static, str>,
+  field: Arc<dyn AnyField>,
+  input_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  input: Option<View>,
+  input_rect: Rect,
+  /// The line under the input, as laid out.
+  message: Option<(String, Style)>,
+  was_focused: bool,
+}
+
+impl fmt::Debug for FormView {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("FormView")
+      .field("form", &self.form)
+      .field("rows", &self.rows)
+      .field("submit", &self.submit)
+      .field("label_width", &self.label_width)
+      .field("error", &self.error)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl fmt::Debug for FormRow {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("FormRow")
+      .field("label", &self.label)
+      .field("input", &self.input)
+      .field("input_rect", &self.input_rect)
+      .field("message", &self.message)
+      .field("was_focused", &self.was_focused)
+      .finish()
+  }
+}
+
+impl Widget for FormView {
+  fn name(&self) -> Cow<'static, str> {
+    "FormView".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let label_width = self
+      .rows
+      .iter()
+      .map(|row| row.label.chars().count() as u16)
+      .max()
+      .unwrap_or_default();
+
+    // Labels are followed by a space, and dropped if there's no room
+    self.label_width = if label_width + 1 < limits.max_width / 2 {
+      label_width + 1
+    } else {
+      0
+    };
+
+    let theme = self.theme.get(&self.name());
+    let input_width = limits.max_width - self.label_width;
+    let mut y = 0;
+    let mut width = 0;
+
+    for row in &mut self.rows {
+      let available = limits.max_height - y;
+
+      let size = row.input.as_mut().map_or(Size::default(), |input| {
+        input.layout(Limits {
+          min_width: 0,
+          max_width: input_width,
+          min_height: 0,
+          max_height: available,
+        })
+      });
+
+      row.input_rect = Rect {
+        top_left: XY {
+          x: self.label_width,
+          y,
+        },
+        size,
+      };
+
+      y += size.height;
+
+      row.message = if row.field.is_validating() {
+        Some(("…".to_string(), theme.muted))
+      } else {
+        row
+          .field
+          .error()
+          .map(|error| (format!("✖ {error}"), theme.error))
+      }
+      .filter(|_| y < limits.max_height)
+      .map(|(message, style)| (clip_to_width(&message, input_width), style));
+
+      if row.message.is_some() {
+        y += 1;
+      }
+
+      width = width.max(self.label_width + size.width);
+    }
+
+    self.error = self
+      .form
+      .error()
+      .filter(|_| y < limits.max_height)
+      .map(|error| (y, clip_to_width(&format!("✖ {error}"), limits.max_width)));
+
+    if self.error.is_some() {
+      y += 1;
+    }
+
+    let submit_size = self.submit.layout(Limits {
+      min_width: 0,
+      max_width: limits.max_width,
+      min_height: 0,
+      max_height: limits.max_height - y,
+    });
+
+    self.submit.y = y;
+
+    y += submit_size.height;
+    width = width.max(submit_size.width);
+
+    self.size = Size {
+      width: width.max(limits.min_width),
+      height: y.max(limits.min_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let theme = self.theme.get(&self.name());
+
+    for row in &self.rows {
+      let rect = row.input_rect;
+
+      if rect.size.height == 0 {
+        continue;
+      }
+
+      if self.label_width > 0 {
+        surface.write_with_style(
+          XY {
+            x: 0,
+            y: rect.top_left.y,
+          },
+          &row.label,
+          theme.text,
+        );
+      }
+
+      if let Some(input) = &row.input {
+        if rect.size.width > 0 {
+          surface.shrink(
+            rect.top_left,
+            rect.size,
+            Box::new(|surface| input.draw(surface)),
+          );
+        }
+      }
+
+      if let Some((message, style)) = &row.message {
+        surface.write_with_style(
+          XY {
+            x: rect.top_left.x,
+            y: rect.top_left.y + rect.size.height,
+          },
+          message,
+          *style,
+        );
+      }
+    }
+
+    if let Some((y, error)) = &self.error {
+      surface.write_with_style(XY { x: 0, y: *y }, error, theme.error);
+    }
+
+    if self.submit.size.height > 0 {
+      surface.shrink(
+        XY {
+          x: 0,
+          y: self.submit.y,
+        },
+        self.submit.size,
+        Box::new(|surface| self.submit.draw(surface)),
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.submit.focused
+      || self
+        .rows
+        .iter()
+        .any(|row| row.input.as_ref().is_some_and(|input| input.has_focus()))
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let submit_rect = Rect {
+      top_left: XY {
+        x: 0,
+        y: self.submit.y,
+      },
+      size: self.submit.size,
+    };
+
+    let mut children = self
+      .rows
+      .iter_mut()
+      .filter_map(|row| {
+        row
+          .input
+          .as_mut()
+          .map(|input| (row.input_rect, input as &mut dyn Widget))
+      })
+      .chain([(submit_rect, &mut self.submit as &mut dyn Widget)])
+      .collect::<Vec<_>>();
+
+    let result = dispatch_to_children(&mut children, event);
+
+    self.touch_blurred_fields();
+
+    result
+  }
+
+  fn focus(&mut self) {}
+
+  fn blur(&mut self) {
+    for row in &mut self.rows {
+      if let Some(input) = &mut row.input {
+        if input.has_focus() {
+          input.blur();
+        }
+      }
+    }
+
+    self.submit.blur();
+
+    self.touch_blurred_fields();
+  }
+}
+
+impl IntoView for FormView {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+    self.submit.theme = self.theme;
+
+    for row in &mut self.rows {
+      if let Some(input_fn) = row.input_fn.take() {
+        row.input = Some(input_fn(cx));
+      }
+    }
+
+    for field in self.form.all_fields() {
+      field.watch(cx);
+    }
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl FormView {
+  pub fn new(form: Form) -> Self {
+    Self {
+      submit: SubmitButton {
+        form: form.clone(),
+        label: "Submit".into(),
+        text: String::new(),
+        y: 0,
+        size: Size::default(),
+        focused: false,
+        theme: ThemeHandle::default(),
+      },
+      form,
+      rows: vec![],
+      label_width: 0,
+      error: None,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Adds a row showing `input` for `field`, with a label to its left.
+  pub fn field<T>(
+    mut self,
+    label: impl Into<Cow<'static, str>>,
+    field: &Field<T>,
+    input: impl IntoView + Send + Sync + 'static,
+  ) -> Self
+  where
+    T: Clone + PartialEq + Send + Sync + 'static,
+  {
+    self.rows.push(FormRow {
+      label: label.into(),
+      field: Arc::new(field.clone()),
+      input_fn: Some(Box::new(|cx| input.into_view(cx))),
+      input: None,
+      input_rect: Rect::default(),
+      message: None,
+      was_focused: false,
+    });
+
+    self
+  }
+
+  /// Sets the label of the submit button. Defaults to `"Submit"`.
+  pub fn submit_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+    self.submit.label = label.into();
+
+    self
+  }
+
+  /// Touches the fields whose input lost focus since this was last called.
+  fn touch_blurred_fields(&mut self) {
+    for row in &mut self.rows {
+      let is_focused =
+        row.input.as_ref().is_some_and(|input| input.has_focus());
+
+      if row.was_focused && !is_focused {
+        row.field.touch();
+      }
+
+      row.was_focused = is_focused;
+    }
+  }
+}
+
+/// The button at the bottom of a [`FormView`].
+struct SubmitButton {
+  form: Form,
+  label: Cow<'static, str>,
+  text: String,
+  y: u16,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for SubmitButton {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SubmitButton")
+      .field("label", &self.label)
+      .field("y", &self.y)
+      .field("size", &self.size)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl Widget for SubmitButton {
+  fn name(&self) -> Cow<'static, str> {
+    "SubmitButton".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.text = clip_to_width(&format!("<{}>", self.label), limits.max_width);
+
+    self.size = Size {
+      width: self.text.chars().count() as u16,
+      height: limits.max_height.min(1),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let theme = self.theme.get(&self.name());
+
+    let style = if self.form.submitting.get_untracked() {
+      theme.muted
+    } else if self.focused {
+      theme.focus
+    } else {
+      theme.text
+    };
+
+    surface.write_with_style(XY::default(), &self.text, style);
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent {
+        code: KeyCode::Enter | KeyCode::Char(' '),
+        ..
+      }) if self.focused => {
+        self.form.submit();
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        ..
+      }) => {
+        self.focused = true;
+        self.form.submit();
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::Checkbox,
+    executor::poll_tasks,
+  };
+  use crossterm::event::KeyModifiers;
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn required(accepted: &bool) -> ValidationResult {
+    if *accepted {
+      Ok(())
+    } else {
+      Err("Required".into())
+    }
+  }
+
+  #[test]
+  fn submits_only_when_valid() {
+    run_scope(create_runtime(), |cx| {
+      let submits = Arc::new(AtomicUsize::new(0));
+
+      let form = Form::new(cx).on_submit({
+        let submits = submits.clone();
+
+        move || {
+          submits.fetch_add(1, Ordering::SeqCst);
+        }
+      });
+
+      let terms = form.field(false).validate(required);
+
+      form.submit();
+      poll_tasks();
+
+      assert_eq!(submits.load(Ordering::SeqCst), 0);
+      assert_eq!(terms.error().as_deref(), Some("Required"));
+
+      terms.value().set(true);
+
+      assert!(terms.is_dirty());
+
+      form.submit();
+      poll_tasks();
+
+      assert_eq!(submits.load(Ordering::SeqCst), 1);
+      assert!(form.is_valid());
+    });
+  }
+
+  #[test]
+  fn outdated_async_results_are_ignored() {
+    run_scope(create_runtime(), |cx| {
+      let (first_tx, first_rx) = tokio::sync::oneshot::channel();
+      let (second_tx, second_rx) = tokio::sync::oneshot::channel();
+      let receivers = Arc::new(Mutex::new(vec![second_rx, first_rx]));
+
+      let form = Form::new(cx);
+
+      let name = form.field("taken").validate_async(move |_| {
+        let rx = receivers.lock().unwrap().pop().unwrap();
+
+        async move { rx.await.unwrap() }
+      });
+
+      name.touch();
+      poll_tasks();
+
+      assert!(name.is_validating());
+
+      name.touch();
+      poll_tasks();
+
+      first_tx.send(Err("Taken".into())).unwrap();
+      poll_tasks();
+
+      assert!(name.is_validating());
+      assert_eq!(name.error(), None);
+
+      second_tx.send(Ok(())).unwrap();
+      poll_tasks();
+
+      assert!(!name.is_validating());
+      assert_eq!(name.error(), None);
+    });
+  }
+
+  #[test]
+  fn cross_field_validators_rerun_when_other_fields_change() {
+    run_scope(create_runtime(), |cx| {
+      let form = Form::new(cx);
+
+      let enabled = form.field(false);
+      let confirmed = form.field(false).validate_on(ValidateOn::Change);
+
+      let enabled_value = enabled.value();
+
+      let confirmed = confirmed.validate(move |confirmed| {
+        if enabled_value.get() && !confirmed {
+          Err("Confirm to enable".into())
+        } else {
+          Ok(())
+        }
+      });
+
+      let mut view = FormView::new(form.clone())
+        .field("Enabled", &enabled, Checkbox::new("", enabled.value()))
+        .field(
+          "Confirmed",
+          &confirmed,
+          Checkbox::new("", confirmed.value()),
+        )
+        .into_view(cx);
+
+      view.layout(Limits::strict(30, 5));
+
+      // Untouched fields don't show errors
+      enabled.value().set(true);
+
+      assert_eq!(confirmed.error(), None);
+
+      // Tabbing through the field touches it
+      view.on(Event::NextFocus);
+      view.on(Event::NextFocus);
+      view.on(Event::NextFocus);
+
+      assert!(confirmed.is_touched());
+      assert_eq!(confirmed.error().as_deref(), Some("Confirm to enable"));
+
+      enabled.value().set(false);
+
+      assert_eq!(confirmed.error(), None);
+
+      view.on(Event::Key(KeyEvent::new(
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+      )));
+      poll_tasks();
+
+      assert!(!form.is_submitting());
+    });
+  }
+}
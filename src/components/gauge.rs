@@ -0,0 +1,171 @@
+use super::progress_bar::clamp_ratio;
+use crate::{
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::style::Attribute;
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A block filled from the left up to a ratio, with a label centered on
+/// top of it, which is the percentage unless [`Gauge::label`] is set.
+///
+/// The gauge takes up all of the available width, and is one line tall
+/// unless it is given a larger minimum height.
+pub struct Gauge {
+  ratio_fn: Box<dyn Fn() -> f64 + Send + Sync>,
+  label: Option<Cow<'static, str>>,
+  /// Ratio when the gauge was last laid out, clamped to `0.0..=1.0`.
+  ratio: f64,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for Gauge {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Gauge")
+      .field("ratio_fn", &"Fn() -> f64")
+      .field("label", &self.label)
+      .field("ratio", &self.ratio)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for Gauge {
+  fn name(&self) -> Cow<'static, str> {
+    "Gauge".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.ratio = clamp_ratio((self.ratio_fn)());
+
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size {
+      width: limits.max_width,
+      height,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Size { width, height } = self.size;
+    let theme = self.theme.get(&self.name());
+
+    let label = match &self.label {
+      Some(label) => label.clone(),
+      None => format!("{}%", (self.ratio * 100.0).round()).into(),
+    };
+
+    let label_len = label.chars().count() as u16;
+    let label_x = width.saturating_sub(label_len) / 2;
+    let label_y = height.saturating_sub(1) / 2;
+
+    // Filled cells are drawn reversed, so that the label stays readable on
+    // top of them
+    let filled = (self.ratio * width as f64).round() as u16;
+
+    for y in 0..height {
+      let mut line = vec![' '; width as usize];
+
+      if y == label_y {
+        for (cell, c) in
+          line.iter_mut().skip(label_x as usize).zip(label.chars())
+        {
+          *cell = c;
+        }
+      }
+
+      let filled_part = line[..filled as usize].iter().collect::<String>();
+      let empty_part = line[filled as usize..].iter().collect::<String>();
+
+      surface.write_with_style(
+        XY { x: 0, y },
+        &filled_part,
+        theme.text.attribute(Attribute::Reverse),
+      );
+
+      surface.write_with_style(XY { x: filled, y }, &empty_part, theme.text);
+    }
+  }
+}
+
+impl IntoView for Gauge {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Gauge {
+  /// Creates a gauge filled up to `ratio()`, which ranges from `0.0` to
+  /// `1.0`.
+  pub fn new(ratio: impl Fn() -> f64 + Send + Sync + 'static) -> Self {
+    Self {
+      ratio_fn: Box::new(ratio),
+      label: None,
+      ratio: 0.0,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Shows `label` instead of the percentage.
+  pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+    self.label = Some(label.into());
+
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Print,
+      Stylize,
+    },
+  };
+
+  #[test]
+  fn label_is_centered_over_the_fill() {
+    let gauge = Gauge::new(|| 0.5);
+
+    let (size, output) = get_widget_output(gauge, (8, 3), (0, 0));
+
+    assert_eq!(size, (8, 1).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print("  50".attribute(Attribute::Reverse)),
+        MoveTo(4, 0),
+        Print("%   "),
+      ]
+    );
+  }
+}
@@ -0,0 +1,379 @@
+use super::braille::{
+  BrailleGrid,
+  DotGrid,
+};
+use crate::{
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::style::Color;
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A named series of `(x, y)` points plotted by a [`LineChart`].
+pub struct Series {
+  name: Cow<'static, str>,
+  data: Box<dyn Fn() -> Vec<(f64, f64)> + Send + Sync>,
+  color: Option<Color>,
+  points: Vec<(f64, f64)>,
+}
+
+impl fmt::Debug for Series {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Series")
+      .field("name", &self.name)
+      .field("color", &self.color)
+      .field("points", &self.points)
+      .finish()
+  }
+}
+
+impl Series {
+  /// Creates a series of the points returned by `data`, which are
+  /// connected in order.
+  pub fn new(
+    name: impl Into<Cow<'static, str>>,
+    data: impl Fn() -> Vec<(f64, f64)> + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      name: name.into(),
+      data: Box::new(data),
+      color: None,
+      points: vec![],
+    }
+  }
+
+  /// Sets the color of the line, instead of the theme's primary color.
+  pub fn color(mut self, color: Color) -> Self {
+    self.color = Some(color);
+
+    self
+  }
+}
+
+/// A chart of one or more series plotted as lines with braille dots, with
+/// tick labels along both axes and a legend in the top-right corner.
+///
+/// Bounds are computed from the data unless they are set with
+/// [`LineChart::x_bounds`] and [`LineChart::y_bounds`]. The chart takes up
+/// all of the space it is given.
+#[derive(Debug)]
+pub struct LineChart {
+  series: Vec<Series>,
+  x_bounds: Option<(f64, f64)>,
+  y_bounds: Option<(f64, f64)>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl Widget for LineChart {
+  fn name(&self) -> Cow<'static, str> {
+    "LineChart".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    for series in &mut self.series {
+      series.points = (series.data)();
+    }
+
+    self.size = limits.max_size();
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Size { width, height } = self.size;
+
+    // One row for the x axis and one for its tick labels
+    if height < 3 {
+      return;
+    }
+
+    let points = || self.series.iter().flat_map(|series| &series.points);
+
+    let (x_min, x_max) = self
+      .x_bounds
+      .unwrap_or_else(|| bounds(points().map(|(x, _)| *x)));
+    let (y_min, y_max) = self
+      .y_bounds
+      .unwrap_or_else(|| bounds(points().map(|(_, y)| *y)));
+
+    let plot_height = height - 2;
+
+    let y_ticks = [
+      (0, y_max),
+      (plot_height / 2, (y_min + y_max) / 2.0),
+      (plot_height - 1, y_min),
+    ]
+    .map(|(y, value)| (y, format_tick(value)));
+
+    let label_width = y_ticks
+      .iter()
+      .map(|(_, label)| label.chars().count() as u16)
+      .max()
+      .unwrap_or_default();
+
+    if label_width + 2 > width {
+      return;
+    }
+
+    let plot_width = width - label_width - 1;
+
+    let theme = self.theme.get(&self.name());
+
+    // Axes
+    for (y, label) in &y_ticks {
+      surface.write_with_style(
+        XY { x: 0, y: *y },
+        &format!("{label:>width$}", width = label_width as usize),
+        theme.text,
+      );
+    }
+
+    for y in 0..plot_height {
+      surface.write_with_style(XY { x: label_width, y }, "│", theme.border);
+    }
+
+    surface.write_with_style(
+      XY {
+        x: label_width,
+        y: plot_height,
+      },
+      &format!("└{}", "─".repeat(plot_width as usize)),
+      theme.border,
+    );
+
+    let x_min_label = format_tick(x_min);
+    let x_max_label = format_tick(x_max);
+
+    surface.write_with_style(
+      XY {
+        x: label_width + 1,
+        y: height - 1,
+      },
+      &x_min_label,
+      theme.text,
+    );
+
+    surface.write_with_style(
+      XY {
+        x: (width as usize)
+          .saturating_sub(x_max_label.chars().count())
+          .max((label_width + 2) as usize + x_min_label.chars().count())
+          as u16,
+        y: height - 1,
+      },
+      &x_max_label,
+      theme.text,
+    );
+
+    // Lines
+    let mut grid = BrailleGrid::new(Size {
+      width: plot_width,
+      height: plot_height,
+    });
+
+    let to_dots = |(x, y): (f64, f64)| {
+      let dot_x = scale(x, x_min, x_max, grid.width());
+      let dot_y =
+        grid.height() as isize - 1 - scale(y, y_min, y_max, grid.height());
+
+      (dot_x, dot_y)
+    };
+
+    let lines = self
+      .series
+      .iter()
+      .map(|series| {
+        let dots = series
+          .points
+          .iter()
+          .map(|point| to_dots(*point))
+          .collect::<Vec<_>>();
+
+        (dots, series.color.or(theme.primary.foreground))
+      })
+      .collect::<Vec<_>>();
+
+    for (dots, color) in lines {
+      match dots.as_slice() {
+        [dot] => grid.set(dot.0, dot.1, color),
+        dots => {
+          for pair in dots.windows(2) {
+            grid.line(pair[0], pair[1], color);
+          }
+        }
+      }
+    }
+
+    grid.draw(
+      surface,
+      XY {
+        x: label_width + 1,
+        y: 0,
+      },
+    );
+
+    // Legend
+    if self.series.iter().any(|series| !series.name.is_empty()) {
+      let legend_width = self
+        .series
+        .iter()
+        .map(|series| series.name.chars().count() as u16 + 2)
+        .max()
+        .unwrap_or_default();
+
+      let x = width.saturating_sub(legend_width).max(label_width + 1);
+
+      for (y, series) in self.series.iter().enumerate() {
+        let y = y as u16;
+
+        if y >= plot_height {
+          break;
+        }
+
+        surface.write_styled(
+          XY { x, y },
+          "■",
+          series.color.or(theme.primary.foreground),
+          None,
+          None,
+        );
+        surface.write_with_style(
+          XY { x: x + 1, y },
+          &format!(" {}", series.name),
+          theme.text,
+        );
+      }
+    }
+  }
+}
+
+impl IntoView for LineChart {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl LineChart {
+  pub fn new() -> Self {
+    Self {
+      series: vec![],
+      x_bounds: None,
+      y_bounds: None,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Adds a series to the chart.
+  pub fn series(mut self, series: Series) -> Self {
+    self.series.push(series);
+
+    self
+  }
+
+  /// Sets the range of the x axis, instead of fitting it to the data.
+  pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+    self.x_bounds = Some((min, max));
+
+    self
+  }
+
+  /// Sets the range of the y axis, instead of fitting it to the data.
+  pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+    self.y_bounds = Some((min, max));
+
+    self
+  }
+}
+
+impl Default for LineChart {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Returns the smallest and largest of `values`, making sure the range
+/// isn't empty.
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+  let (min, max) = values
+    .filter(|value| value.is_finite())
+    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+      (min.min(value), max.max(value))
+    });
+
+  if min > max {
+    (0.0, 1.0)
+  } else if min == max {
+    (min - 1.0, max + 1.0)
+  } else {
+    (min, max)
+  }
+}
+
+/// Scales `value` within `min..=max` to a dot within `0..len`.
+fn scale(value: f64, min: f64, max: f64, len: usize) -> isize {
+  let ratio = (value - min) / (max - min);
+
+  (ratio * (len - 1) as f64).round() as isize
+}
+
+/// Formats a tick label with at most two decimals, dropping trailing
+/// zeroes.
+fn format_tick(value: f64) -> String {
+  let label = format!("{value:.2}");
+  let label = label.trim_end_matches('0').trim_end_matches('.');
+
+  if label == "-0" {
+    "0".into()
+  } else {
+    label.into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+
+  #[test]
+  fn formats_ticks() {
+    assert_eq!(format_tick(1.0), "1");
+    assert_eq!(format_tick(2.5), "2.5");
+    assert_eq!(format_tick(1.0 / 3.0), "0.33");
+    assert_eq!(format_tick(-0.001), "0");
+  }
+
+  #[test]
+  fn plots_series_with_axes_and_legend() {
+    let chart = LineChart::new()
+      .series(Series::new("up", || vec![(0.0, 0.0), (4.0, 4.0)]))
+      .series(
+        Series::new("flat", || vec![(0.0, 2.0), (4.0, 2.0)]).color(Color::Blue),
+      );
+
+    let (size, output) = get_widget_output(chart, (12, 6), (0, 0));
+
+    assert_eq!(size, (12, 6).into());
+
+    for text in ["4", "2", "0", "└─", "■", " up", " flat", "⣀⣀"] {
+      assert!(output.contains(text), "{text:?} not drawn");
+    }
+  }
+}
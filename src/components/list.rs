@@ -0,0 +1,595 @@
+use crate::{
+  cycle_leaf_focus,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  create_effect,
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+  SignalUpdate,
+};
+use std::{
+  borrow::Cow,
+  collections::{
+    BTreeMap,
+    BTreeSet,
+  },
+  fmt,
+  sync::{
+    atomic::{
+      AtomicBool,
+      Ordering,
+    },
+    Arc,
+    Mutex,
+  },
+};
+
+/// Width of the gutter drawn before every row, which shows the selection
+/// and multi-select marks.
+const GUTTER_WIDTH: u16 = 2;
+
+/// A virtualized, single-line-per-row list.
+///
+/// Only the rows which fit within the list's [`Limits`] are created, laid
+/// out and drawn, so the list can hold any number of items. Rows are
+/// created from their index by the closure given to [`List::new`] when they
+/// scroll into view, within a reactive scope which is disposed when they
+/// scroll out of view. Every row is re-created when the signals read by
+/// `len` change, which is also when the selection is moved back within
+/// the list if it shrank.
+///
+/// While focused, Up, Down, PageUp, PageDown, Home and End move the
+/// selection, which is always kept visible, and Space toggles whether the
+/// selected row is marked, if [`List::marked`] was set.
+pub struct List {
+  len: Arc<dyn Fn() -> usize + Send + Sync>,
+  row_fn: Box<dyn Fn(Scope, usize) -> View + Send + Sync>,
+  selected: Option<RwSignal<usize>>,
+  marked: Option<RwSignal<BTreeSet<usize>>>,
+  cx: Option<Scope>,
+  /// Index of the first visible row.
+  offset: usize,
+  /// The selected row when the list was last scrolled to it, so that
+  /// scrolling with the mouse wheel isn't undone until the selection
+  /// changes.
+  scrolled_to: Option<usize>,
+  /// The visible rows by index, with the scope each was created in.
+  rows: BTreeMap<usize, (View, Scope)>,
+  /// Set when the items change, so the rows are re-created.
+  items_changed: Arc<AtomicBool>,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for List {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("List")
+      .field("len", &"Fn() -> usize")
+      .field("row_fn", &"Fn(usize) -> View")
+      .field("selected", &self.selected)
+      .field("marked", &self.marked)
+      .field("offset", &self.offset)
+      .field("rows", &self.rows.keys())
+      .field("size", &self.size)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl Widget for List {
+  fn name(&self) -> Cow<'static, str> {
+    "List".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let len = (self.len)();
+    let height = limits.max_height as usize;
+
+    let selected = self.selected_index(len);
+
+    // Keep the selection visible
+    if self.scrolled_to != Some(selected) {
+      if selected < self.offset {
+        self.offset = selected;
+      } else if height > 0 && selected >= self.offset + height {
+        self.offset = selected + 1 - height;
+      }
+
+      self.scrolled_to = Some(selected);
+    }
+
+    self.offset = self.offset.min(len.saturating_sub(height));
+
+    if self.items_changed.swap(false, Ordering::SeqCst) {
+      self.dispose_rows();
+    }
+
+    let visible = self.offset..(self.offset + height).min(len);
+
+    // Rows which are still visible are kept as they are
+    self.rows.retain(|index, (_, cx)| {
+      let is_visible = visible.contains(index);
+
+      if !is_visible {
+        cx.dispose();
+      }
+
+      is_visible
+    });
+
+    if let Some(cx) = self.cx {
+      let row_fn = &self.row_fn;
+
+      for index in visible {
+        self.rows.entry(index).or_insert_with(|| {
+          let ((row, row_cx), _) =
+            cx.run_child_scope(|cx| (row_fn(cx, index), cx));
+
+          (row, row_cx)
+        });
+      }
+    }
+
+    let row_limits = Limits {
+      min_width: 0,
+      max_width: limits.max_width.saturating_sub(GUTTER_WIDTH),
+      min_height: 0,
+      max_height: 1,
+    };
+
+    let width = self
+      .rows
+      .values_mut()
+      .map(|(row, _)| row.layout(row_limits).width + GUTTER_WIDTH)
+      .max()
+      .unwrap_or_default()
+      .clamp(limits.min_width, limits.max_width);
+
+    self.size = Size {
+      width,
+      height: (self.rows.len() as u16).max(limits.min_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let selected = self.selected.map(|selected| selected.get_untracked());
+    let marked = self.marked.map(|marked| marked.get_untracked());
+    let theme = self.theme.get(&self.name());
+
+    for (&index, (row, _)) in &self.rows {
+      let Some(i) = index.checked_sub(self.offset) else {
+        continue;
+      };
+      let is_selected = selected == Some(index);
+      let is_marked = marked
+        .as_ref()
+        .is_some_and(|marked| marked.contains(&index));
+
+      let gutter = match (is_selected, is_marked) {
+        (true, true) => ">*",
+        (true, false) => "> ",
+        (false, true) => " *",
+        (false, false) => "  ",
+      };
+
+      surface.write_with_style(
+        XY { x: 0, y: i as u16 },
+        gutter,
+        if is_selected && self.focused {
+          theme.focus
+        } else {
+          theme.text
+        },
+      );
+
+      let width = self.size.width.saturating_sub(GUTTER_WIDTH);
+
+      if width > 0 {
+        surface.shrink(
+          XY {
+            x: GUTTER_WIDTH,
+            y: i as u16,
+          },
+          Size { width, height: 1 },
+          Box::new(|surface| row.draw(surface)),
+        );
+      }
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(self.selected.is_some())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let page = (self.size.height as usize).max(1);
+
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => {
+        let len = (self.len)();
+
+        let Some(selected) = self.selected else {
+          return EventHandlerResult::Bubble;
+        };
+
+        if len == 0 {
+          return EventHandlerResult::Bubble;
+        }
+
+        let current = self.selected_index(len);
+
+        let new = match code {
+          KeyCode::Up => current.saturating_sub(1),
+          KeyCode::Down => current + 1,
+          KeyCode::PageUp => current.saturating_sub(page),
+          KeyCode::PageDown => current + page,
+          KeyCode::Home => 0,
+          KeyCode::End => len - 1,
+          KeyCode::Char(' ') if self.marked.is_some() => {
+            self.toggle_marked(current);
+
+            return EventHandlerResult::Captured;
+          }
+          _ => return EventHandlerResult::Bubble,
+        };
+
+        selected.set(new.min(len - 1));
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) if column < self.size.width && row < self.size.height => {
+        let len = (self.len)();
+
+        match kind {
+          MouseEventKind::Down(MouseButton::Left) => {
+            let index = self.offset + row as usize;
+
+            if index < len {
+              self.focus();
+
+              if let Some(selected) = self.selected {
+                selected.set(index);
+              }
+            }
+          }
+          MouseEventKind::ScrollUp => {
+            self.offset = self.offset.saturating_sub(1);
+          }
+          MouseEventKind::ScrollDown => {
+            self.offset = (self.offset + 1)
+              .min(len.saturating_sub(self.size.height as usize));
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for List {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.mount(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl List {
+  /// Creates a list of `len()` rows, each of which is created with
+  /// `row(index)`.
+  pub fn new<V: IntoView>(
+    len: impl Fn() -> usize + Send + Sync + 'static,
+    row: impl Fn(usize) -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      len: Arc::new(len),
+      row_fn: Box::new(move |cx, i| row(i).into_view(cx)),
+      selected: None,
+      marked: None,
+      cx: None,
+      offset: 0,
+      scrolled_to: None,
+      rows: BTreeMap::new(),
+      items_changed: Arc::new(AtomicBool::new(false)),
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Tracks the selected row in `selected`. The list can only be focused
+  /// if a selection signal is set.
+  pub fn selected(mut self, selected: RwSignal<usize>) -> Self {
+    self.selected = Some(selected);
+
+    self
+  }
+
+  /// Enables multi-selection, tracking the marked rows in `marked`.
+  pub fn marked(mut self, marked: RwSignal<BTreeSet<usize>>) -> Self {
+    self.marked = Some(marked);
+
+    self
+  }
+
+  fn mount(&mut self, cx: Scope) {
+    self.cx = Some(cx);
+    self.theme = ThemeHandle::new(cx);
+
+    let len = self.len.clone();
+    let selected = self.selected;
+    let items_changed = self.items_changed.clone();
+
+    // Keeps the selection within the list when it shrinks
+    create_effect(cx, move |_| {
+      let len = len();
+
+      if let Some(selected) = selected {
+        let current = selected.get_untracked();
+
+        if current >= len && current > 0 {
+          selected.set(len.saturating_sub(1));
+        }
+      }
+
+      items_changed.store(true, Ordering::SeqCst);
+    });
+  }
+
+  /// Returns the selected index, within bounds even if the selection was
+  /// set past the end of the list.
+  fn selected_index(&self, len: usize) -> usize {
+    self.selected.map_or(self.offset, |selected| {
+      selected.get_untracked().min(len.saturating_sub(1))
+    })
+  }
+
+  fn dispose_rows(&mut self) {
+    for (_, (_, cx)) in std::mem::take(&mut self.rows) {
+      cx.dispose();
+    }
+  }
+
+  fn toggle_marked(&self, index: usize) {
+    if let Some(marked) = self.marked {
+      marked.update(|marked| {
+        if !marked.remove(&index) {
+          marked.insert(index);
+        }
+      });
+    }
+  }
+}
+
+impl Drop for List {
+  fn drop(&mut self) {
+    self.dispose_rows();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::Print,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalWith,
+  };
+  use std::sync::atomic::AtomicUsize;
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn render(list: &mut List, size: (u16, u16)) -> String {
+    let mut buf = vec![];
+
+    let mut surface = crate::BufDrawSurface::new(&mut buf, size);
+
+    list.layout(Limits {
+      max_width: size.0,
+      max_height: size.1,
+      ..Default::default()
+    });
+    list.draw(&mut surface);
+
+    drop(surface);
+
+    String::from_utf8(buf).unwrap()
+  }
+
+  fn list(cx: Scope, selected: RwSignal<usize>) -> List {
+    let mut list = List::new(|| 10_000, |i| format!("item {i}"))
+      .selected(selected)
+      .marked(create_rw_signal(cx, BTreeSet::new()));
+
+    list.mount(cx);
+
+    list
+  }
+
+  #[test]
+  fn only_renders_visible_rows() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 0);
+      let mut list = list(cx, selected);
+
+      let output = render(&mut list, (10, 2));
+
+      assert_eq!(list.rows.len(), 2);
+
+      assert_eq!(
+        output,
+        commands![
+          MoveTo(0, 0),
+          Print("> "),
+          MoveTo(2, 0),
+          Print("item 0"),
+          MoveTo(0, 1),
+          Print("  "),
+          MoveTo(2, 1),
+          Print("item 1"),
+        ]
+      );
+    });
+  }
+
+  #[test]
+  fn scrolls_to_keep_selection_visible() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 0);
+      let mut list = list(cx, selected);
+
+      render(&mut list, (10, 3));
+
+      list.focus();
+      list.on(key(KeyCode::PageDown));
+      list.on(key(KeyCode::Down));
+
+      assert_eq!(selected.get_untracked(), 4);
+
+      render(&mut list, (10, 3));
+
+      assert_eq!(list.offset, 2);
+
+      list.on(key(KeyCode::End));
+      render(&mut list, (10, 3));
+
+      assert_eq!(selected.get_untracked(), 9_999);
+      assert_eq!(list.offset, 9_997);
+
+      list.on(key(KeyCode::Home));
+      render(&mut list, (10, 3));
+
+      assert_eq!(list.offset, 0);
+    });
+  }
+
+  #[test]
+  fn only_creates_rows_scrolling_into_view() {
+    run_scope(create_runtime(), |cx| {
+      let created = Arc::new(AtomicUsize::new(0));
+      let mut list = List::new(|| 100, {
+        let created = created.clone();
+
+        move |i| {
+          created.fetch_add(1, Ordering::SeqCst);
+
+          format!("item {i}")
+        }
+      });
+
+      list.mount(cx);
+
+      render(&mut list, (10, 3));
+      render(&mut list, (10, 3));
+
+      assert_eq!(created.load(Ordering::SeqCst), 3);
+
+      list.on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+      }));
+      render(&mut list, (10, 3));
+
+      assert_eq!(created.load(Ordering::SeqCst), 4);
+      assert_eq!(list.rows.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    });
+  }
+
+  #[test]
+  fn clamps_selection_when_items_shrink() {
+    run_scope(create_runtime(), |cx| {
+      let items = create_rw_signal(cx, vec!["a", "b", "c"]);
+      let selected = create_rw_signal(cx, 2);
+      let mut list = List::new(
+        move || items.with(Vec::len),
+        move |i| items.with(|items| items.get(i).copied().unwrap_or_default()),
+      )
+      .selected(selected);
+
+      list.mount(cx);
+
+      render(&mut list, (10, 3));
+
+      items.set(vec!["d"]);
+
+      assert_eq!(selected.get_untracked(), 0);
+
+      let output = render(&mut list, (10, 3));
+
+      assert_eq!(
+        output,
+        commands![MoveTo(0, 0), Print("> "), MoveTo(2, 0), Print("d")]
+      );
+    });
+  }
+
+  #[test]
+  fn space_toggles_marked_rows() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 3);
+      let mut list = list(cx, selected);
+      let marked = list.marked.unwrap();
+
+      list.focus();
+      list.on(key(KeyCode::Char(' ')));
+      list.on(key(KeyCode::Down));
+      list.on(key(KeyCode::Char(' ')));
+
+      assert_eq!(marked.get_untracked(), BTreeSet::from([3, 4]));
+
+      list.on(key(KeyCode::Char(' ')));
+
+      assert_eq!(marked.get_untracked(), BTreeSet::from([3]));
+    });
+  }
+}
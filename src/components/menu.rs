@@ -0,0 +1,1321 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  use_overlays,
+  use_shortcuts,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  OverlayId,
+  Overlays,
+  Placement,
+  Rect,
+  Shortcut,
+  Size,
+  Style,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::{
+  event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+  },
+  style::Attribute,
+};
+use leptos_reactive::{
+  on_cleanup,
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalUpdate,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  ops::Range,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// An entry of a [`Menu`], a [`ContextMenu`] or a submenu.
+///
+/// Labels can mark their mnemonic with `&`, such as `"&Save"`, in which case
+/// the character following it is underlined, and typing it while the menu
+/// is open selects the item. `&&` stands for a literal `&`.
+#[derive(Clone)]
+pub struct MenuItem {
+  label: String,
+  mnemonic: Option<(usize, char)>,
+  kind: MenuItemKind,
+  shortcut: Option<Cow<'static, str>>,
+  disabled: bool,
+}
+
+#[derive(Clone)]
+enum MenuItemKind {
+  Action(Arc<dyn Fn() + Send + Sync>),
+  Checkable(RwSignal<bool>),
+  Submenu(Vec<MenuItem>),
+  Separator,
+}
+
+impl fmt::Debug for MenuItem {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut f = f.debug_struct("MenuItem");
+
+    f.field("label", &self.label)
+      .field("mnemonic", &self.mnemonic);
+
+    match &self.kind {
+      MenuItemKind::Action(_) => f.field("action", &"Fn()"),
+      MenuItemKind::Checkable(checked) => f.field("checked", checked),
+      MenuItemKind::Submenu(items) => f.field("items", items),
+      MenuItemKind::Separator => f.field("separator", &true),
+    };
+
+    f.field("shortcut", &self.shortcut)
+      .field("disabled", &self.disabled)
+      .finish()
+  }
+}
+
+impl MenuItem {
+  /// Creates an item which calls `on_select` when it is selected.
+  pub fn new(
+    label: impl AsRef<str>,
+    on_select: impl Fn() + Send + Sync + 'static,
+  ) -> Self {
+    Self::with_kind(label.as_ref(), MenuItemKind::Action(Arc::new(on_select)))
+  }
+
+  /// Creates an item with a check mark, which toggles `checked` when it is
+  /// selected.
+  pub fn checkable(label: impl AsRef<str>, checked: RwSignal<bool>) -> Self {
+    Self::with_kind(label.as_ref(), MenuItemKind::Checkable(checked))
+  }
+
+  /// Creates an item which opens a submenu of `items` when it is selected.
+  pub fn submenu(
+    label: impl AsRef<str>,
+    items: impl IntoIterator<Item = MenuItem>,
+  ) -> Self {
+    Self::with_kind(
+      label.as_ref(),
+      MenuItemKind::Submenu(items.into_iter().collect()),
+    )
+  }
+
+  /// Creates a line separating groups of items, which can't be selected.
+  pub fn separator() -> Self {
+    Self::with_kind("", MenuItemKind::Separator)
+  }
+
+  /// Sets the hint shown on the right of the item, such as `"Ctrl+S"`. The
+  /// shortcut itself has to be registered separately, see
+  /// [`Shortcuts`](crate::Shortcuts).
+  pub fn shortcut(mut self, shortcut: impl Into<Cow<'static, str>>) -> Self {
+    self.shortcut = Some(shortcut.into());
+
+    self
+  }
+
+  /// Sets whether the item is grayed out and can't be selected.
+  pub fn disabled(mut self, disabled: bool) -> Self {
+    self.disabled = disabled;
+
+    self
+  }
+
+  fn with_kind(label: &str, kind: MenuItemKind) -> Self {
+    let (label, mnemonic) = parse_mnemonic(label);
+
+    Self {
+      label,
+      mnemonic,
+      kind,
+      shortcut: None,
+      disabled: false,
+    }
+  }
+
+  fn is_selectable(&self) -> bool {
+    !self.disabled && !matches!(self.kind, MenuItemKind::Separator)
+  }
+}
+
+/// A top-level menu of a [`MenuBar`].
+#[derive(Clone, Debug)]
+pub struct Menu {
+  title: String,
+  mnemonic: Option<(usize, char)>,
+  items: Vec<MenuItem>,
+}
+
+impl Menu {
+  /// Creates a menu titled `title`, which can mark its mnemonic with `&`,
+  /// see [`MenuItem`].
+  pub fn new(
+    title: impl AsRef<str>,
+    items: impl IntoIterator<Item = MenuItem>,
+  ) -> Self {
+    let (title, mnemonic) = parse_mnemonic(title.as_ref());
+
+    Self {
+      title,
+      mnemonic,
+      items: items.into_iter().collect(),
+    }
+  }
+}
+
+/// A horizontal bar of menu titles, each of which opens a dropdown menu on
+/// the [`Overlays`] layer.
+///
+/// Alt and the mnemonic of a menu's title open it from anywhere. When the
+/// bar is focused, Left and Right move between titles, and Enter, Space
+/// or Down open the highlighted menu. Clicking a title opens its menu.
+///
+/// While a menu is open, it has keyboard focus: Up and Down move through
+/// its items, Right opens submenus or the next menu of the bar, Left closes
+/// submenus or opens the previous menu of the bar, Enter and Space select
+/// the highlighted item, typing an item's mnemonic selects it, and Esc
+/// closes the innermost menu. Clicking anywhere outside of the open menus
+/// closes them.
+#[derive(Debug)]
+pub struct MenuBar {
+  state: Arc<Mutex<MenuState>>,
+  overlays: Option<Overlays>,
+  title_ranges: Vec<Range<u16>>,
+  highlighted: usize,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl Widget for MenuBar {
+  fn name(&self) -> Cow<'static, str> {
+    "MenuBar".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let state = self.state.lock().unwrap();
+
+    self.title_ranges.clear();
+
+    let mut x = 0;
+
+    for (menu, _) in &state.bar {
+      let width = menu.title.chars().count() as u16 + 2;
+
+      self.title_ranges.push(x..x + width);
+
+      x += width;
+    }
+
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size {
+      width: limits.max_width,
+      height,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let top_left = surface.top_left();
+
+    let mut state = self.state.lock().unwrap();
+    let open = state.open_bar_menu;
+    let theme = self.theme.get(&self.name());
+
+    for (i, ((menu, at), range)) in
+      state.bar.iter_mut().zip(&self.title_ranges).enumerate()
+    {
+      // Dropdowns open right below their title
+      *at = XY {
+        x: top_left.x + range.start,
+        y: top_left.y + 1,
+      };
+
+      let is_highlighted = self.focused && i == self.highlighted;
+
+      let style = if is_highlighted {
+        theme.focus
+      } else {
+        theme.text
+      };
+
+      write_label(
+        surface,
+        XY {
+          x: range.start,
+          y: 0,
+        },
+        &format!(" {} ", menu.title),
+        menu.mnemonic.map(|(at, c)| (at + 1, c)),
+        if open == Some(i) {
+          Style {
+            attribute: theme.selected.attribute,
+            ..style
+          }
+        } else {
+          style
+        },
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => {
+        let len = self.title_ranges.len();
+
+        if len == 0 {
+          return EventHandlerResult::Bubble;
+        }
+
+        match code {
+          KeyCode::Left => {
+            self.highlighted = (self.highlighted + len - 1) % len
+          }
+          KeyCode::Right => self.highlighted = (self.highlighted + 1) % len,
+          KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => {
+            self.controller().open_bar_menu(self.highlighted);
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) if row < self.size.height && column < self.size.width => {
+        let Some(i) = self
+          .title_ranges
+          .iter()
+          .position(|range| range.contains(&column))
+        else {
+          return EventHandlerResult::Captured;
+        };
+
+        let controller = self.controller();
+        let open = self.state.lock().unwrap().open_bar_menu;
+
+        match kind {
+          MouseEventKind::Down(MouseButton::Left) => {
+            controller.open_bar_menu(i);
+          }
+          // Hovering another title while a menu is open switches to its menu
+          MouseEventKind::Moved if open.is_some() && open != Some(i) => {
+            controller.open_bar_menu(i);
+          }
+          _ => {}
+        }
+
+        self.highlighted = i;
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for MenuBar {
+  fn into_view(mut self, cx: Scope) -> View {
+    let overlays = use_overlays(cx);
+    let shortcuts = use_shortcuts(cx);
+
+    self.overlays = Some(overlays.clone());
+    self.theme = ThemeHandle::new(cx);
+
+    let controller = self.controller();
+
+    let mnemonics = self
+      .state
+      .lock()
+      .unwrap()
+      .bar
+      .iter()
+      .map(|(menu, _)| menu.mnemonic)
+      .collect::<Vec<_>>();
+
+    let ids = mnemonics
+      .into_iter()
+      .enumerate()
+      .filter_map(|(i, mnemonic)| {
+        let (_, c) = mnemonic?;
+        let controller = controller.clone();
+
+        Some(shortcuts.register(
+          Shortcut::new(KeyCode::Char(c), KeyModifiers::ALT),
+          move || controller.open_bar_menu(i),
+        ))
+      })
+      .collect::<Vec<_>>();
+
+    on_cleanup(cx, move || {
+      for id in ids {
+        shortcuts.unregister(id);
+      }
+
+      controller.close_to(0);
+    });
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl MenuBar {
+  pub fn new(menus: impl IntoIterator<Item = Menu>) -> Self {
+    Self {
+      state: Arc::new(Mutex::new(MenuState {
+        levels: vec![],
+        bar: menus
+          .into_iter()
+          .map(|menu| (menu, XY::default()))
+          .collect(),
+        ..Default::default()
+      })),
+      overlays: None,
+      title_ranges: vec![],
+      highlighted: 0,
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  #[track_caller]
+  fn controller(&self) -> MenuController {
+    MenuController {
+      state: self.state.clone(),
+      overlays: self
+        .overlays
+        .clone()
+        .expect("`MenuBar` to have been turned into a view"),
+      theme: self.theme,
+    }
+  }
+}
+
+/// Wraps a child, opening a menu of `items` at the mouse position when it
+/// is right-clicked, or at its top-left corner when Shift+F10 is pressed
+/// while focus is within it.
+///
+/// The menu is navigated like the menus of a [`MenuBar`].
+pub struct ContextMenu {
+  child_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  child: Option<View>,
+  items: Vec<MenuItem>,
+  state: Arc<Mutex<MenuState>>,
+  overlays: Option<Overlays>,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for ContextMenu {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ContextMenu")
+      .field("child", &self.child)
+      .field("items", &self.items)
+      .field("state", &self.state)
+      .finish()
+  }
+}
+
+impl Widget for ContextMenu {
+  fn name(&self) -> Cow<'static, str> {
+    "ContextMenu".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self
+      .child
+      .as_mut()
+      .map(|child| child.layout(limits))
+      .unwrap_or_else(|| limits.min_size())
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    self.state.lock().unwrap().origin = Some(surface.top_left());
+
+    if let Some(child) = &self.child {
+      child.draw(surface);
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.child.as_ref().and_then(|child| child.needs_focus())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.child.as_ref().is_some_and(|child| child.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Right),
+        column,
+        row,
+        ..
+      }) => {
+        self.open(XY { x: column, y: row });
+
+        EventHandlerResult::Captured
+      }
+      Event::Key(KeyEvent {
+        code: KeyCode::F(10),
+        modifiers: KeyModifiers::SHIFT,
+        ..
+      }) if self.has_focus() => {
+        self.open(XY::default());
+
+        EventHandlerResult::Captured
+      }
+      event => self
+        .child
+        .as_mut()
+        .map_or(EventHandlerResult::Bubble, |child| child.on(event)),
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(child) = &mut self.child {
+      child.focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    if let Some(child) = &mut self.child {
+      child.blur();
+    }
+  }
+}
+
+impl IntoView for ContextMenu {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.overlays = Some(use_overlays(cx));
+    self.theme = ThemeHandle::new(cx);
+
+    if let Some(child_fn) = self.child_fn.take() {
+      self.child = Some(child_fn(cx));
+    }
+
+    let controller = self.controller();
+
+    on_cleanup(cx, move || controller.close_to(0));
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl ContextMenu {
+  pub fn new(
+    child: impl IntoView + Send + Sync + 'static,
+    items: impl IntoIterator<Item = MenuItem>,
+  ) -> Self {
+    Self {
+      child_fn: Some(Box::new(|cx| child.into_view(cx))),
+      child: None,
+      items: items.into_iter().collect(),
+      state: Default::default(),
+      overlays: None,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  #[track_caller]
+  fn controller(&self) -> MenuController {
+    MenuController {
+      state: self.state.clone(),
+      overlays: self
+        .overlays
+        .clone()
+        .expect("`ContextMenu` to have been turned into a view"),
+      theme: self.theme,
+    }
+  }
+
+  /// Opens the menu at a position relative to the child.
+  fn open(&mut self, at: XY) {
+    let controller = self.controller();
+    let mut state = self.state.lock().unwrap();
+
+    let origin = state.origin.unwrap_or_default();
+
+    controller.close_levels(&mut state, 0);
+    controller.open_level(
+      &mut state,
+      self.items.clone(),
+      XY {
+        x: origin.x + at.x,
+        y: origin.y + at.y,
+      },
+    );
+  }
+}
+
+/// State shared between a [`MenuBar`] or a [`ContextMenu`] and the menus
+/// it opened.
+#[derive(Debug, Default)]
+struct MenuState {
+  /// Open menus, each one a submenu of the previous one.
+  levels: Vec<OpenMenu>,
+  /// The menus of a [`MenuBar`], and the absolute position each one opens
+  /// at.
+  bar: Vec<(Menu, XY)>,
+  /// The menu of the bar which is open, if any.
+  open_bar_menu: Option<usize>,
+  /// Where a [`ContextMenu`]'s child was last drawn, on the screen.
+  origin: Option<XY>,
+}
+
+#[derive(Debug)]
+struct OpenMenu {
+  items: Vec<MenuItem>,
+  highlighted: Option<usize>,
+  overlay: OverlayId,
+  /// Where the menu was last drawn, on the screen.
+  rect: Rect,
+}
+
+/// Opens and closes the menus of a [`MenuBar`] or a [`ContextMenu`].
+#[derive(Clone, Debug)]
+struct MenuController {
+  state: Arc<Mutex<MenuState>>,
+  overlays: Overlays,
+  theme: ThemeHandle,
+}
+
+impl MenuController {
+  fn open_bar_menu(&self, i: usize) {
+    let mut state = self.state.lock().unwrap();
+
+    self.open_bar_menu_locked(&mut state, i);
+  }
+
+  fn open_bar_menu_locked(&self, state: &mut MenuState, i: usize) {
+    self.close_levels(state, 0);
+
+    if let Some((menu, at)) = state.bar.get(i) {
+      let (items, at) = (menu.items.clone(), *at);
+
+      self.open_level(state, items, at);
+
+      state.open_bar_menu = Some(i);
+    }
+  }
+
+  /// Closes every menu from `level` on.
+  fn close_to(&self, level: usize) {
+    let mut state = self.state.lock().unwrap();
+
+    self.close_levels(&mut state, level);
+  }
+
+  fn close_levels(&self, state: &mut MenuState, level: usize) {
+    while state.levels.len() > level {
+      if let Some(menu) = state.levels.pop() {
+        self.overlays.remove(menu.overlay);
+      }
+    }
+
+    if level == 0 {
+      state.open_bar_menu = None;
+    }
+  }
+
+  fn open_level(&self, state: &mut MenuState, items: Vec<MenuItem>, at: XY) {
+    let popup = MenuPopup {
+      controller: self.clone(),
+      level: state.levels.len(),
+      check_width: 0,
+      label_width: 0,
+      size: Size::default(),
+    };
+
+    let overlay = self
+      .overlays
+      .push(Arc::new(Mutex::new(popup)), Placement::Near(at));
+
+    state.levels.push(OpenMenu {
+      highlighted: items.iter().position(MenuItem::is_selectable),
+      items,
+      overlay,
+      rect: Rect::default(),
+    });
+  }
+
+  /// Opens the submenu of the highlighted item of the innermost menu, if it
+  /// has one.
+  fn open_submenu(&self, state: &mut MenuState) {
+    let Some(menu) = state.levels.last() else {
+      return;
+    };
+
+    let Some(i) = menu.highlighted else {
+      return;
+    };
+
+    if let MenuItemKind::Submenu(items) = &menu.items[i].kind {
+      let at = XY {
+        x: menu.rect.top_left.x + menu.rect.size.width,
+        y: menu.rect.top_left.y + 1 + i as u16,
+      };
+
+      let items = items.clone();
+
+      self.open_level(state, items, at);
+    }
+  }
+
+  /// Selects an item, returning the callback to run once the state is
+  /// unlocked, if any.
+  fn select(
+    &self,
+    state: &mut MenuState,
+    level: usize,
+    i: usize,
+  ) -> Option<Box<dyn FnOnce()>> {
+    let item = state.levels.get(level)?.items.get(i)?;
+
+    if !item.is_selectable() {
+      return None;
+    }
+
+    let kind = item.kind.clone();
+
+    self.close_levels(state, level + 1);
+
+    state.levels[level].highlighted = Some(i);
+
+    match kind {
+      MenuItemKind::Action(on_select) => {
+        self.close_levels(state, 0);
+
+        Some(Box::new(move || on_select()))
+      }
+      MenuItemKind::Checkable(checked) => {
+        self.close_levels(state, 0);
+
+        Some(Box::new(move || {
+          checked.update(|checked| *checked = !*checked)
+        }))
+      }
+      MenuItemKind::Submenu(_) => {
+        self.open_submenu(state);
+
+        None
+      }
+      MenuItemKind::Separator => None,
+    }
+  }
+
+  /// Handles a key for the innermost menu.
+  fn on_key(&self, key: KeyEvent) {
+    let mut on_select = None;
+
+    {
+      let mut state = self.state.lock().unwrap();
+
+      let Some(level) = state.levels.len().checked_sub(1) else {
+        return;
+      };
+
+      let menu = &mut state.levels[level];
+      let highlighted = menu.highlighted;
+
+      match key.code {
+        KeyCode::Up => move_highlight(menu, -1),
+        KeyCode::Down => move_highlight(menu, 1),
+        KeyCode::Home => {
+          menu.highlighted =
+            menu.items.iter().position(MenuItem::is_selectable);
+        }
+        KeyCode::End => {
+          menu.highlighted =
+            menu.items.iter().rposition(MenuItem::is_selectable);
+        }
+        KeyCode::Right => {
+          let has_submenu = highlighted.is_some_and(|i| {
+            matches!(menu.items[i].kind, MenuItemKind::Submenu(_))
+          });
+
+          if has_submenu {
+            self.open_submenu(&mut state);
+          } else if let Some(open) = state.open_bar_menu {
+            let next = (open + 1) % state.bar.len();
+
+            self.open_bar_menu_locked(&mut state, next);
+          }
+        }
+        KeyCode::Left if level > 0 => self.close_levels(&mut state, level),
+        KeyCode::Left => {
+          if let Some(open) = state.open_bar_menu {
+            let len = state.bar.len();
+
+            self.open_bar_menu_locked(&mut state, (open + len - 1) % len);
+          }
+        }
+        KeyCode::Esc => self.close_levels(&mut state, level),
+        KeyCode::Enter | KeyCode::Char(' ') => {
+          if let Some(i) = highlighted {
+            on_select = self.select(&mut state, level, i);
+          }
+        }
+        // Menus have focus while open, so their global shortcuts are
+        // handled here
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => {
+          if let Some(i) =
+            find_mnemonic(state.bar.iter().map(|(menu, _)| menu.mnemonic), c)
+          {
+            self.open_bar_menu_locked(&mut state, i);
+          }
+        }
+        KeyCode::Char(c) => {
+          let i = find_mnemonic(
+            menu.items.iter().map(|item| {
+              if item.is_selectable() {
+                item.mnemonic
+              } else {
+                None
+              }
+            }),
+            c,
+          );
+
+          if let Some(i) = i {
+            on_select = self.select(&mut state, level, i);
+          }
+        }
+        _ => {}
+      }
+    }
+
+    if let Some(on_select) = on_select {
+      on_select();
+    }
+  }
+}
+
+/// One open menu of a [`MenuController`], drawn on the overlay layer.
+#[derive(Debug)]
+struct MenuPopup {
+  controller: MenuController,
+  level: usize,
+  check_width: u16,
+  label_width: u16,
+  size: Size,
+}
+
+impl Widget for MenuPopup {
+  fn name(&self) -> Cow<'static, str> {
+    "MenuPopup".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let state = self.controller.state.lock().unwrap();
+
+    let Some(menu) = state.levels.get(self.level) else {
+      self.size = Size::default();
+
+      return self.size;
+    };
+
+    let is_checkable = menu
+      .items
+      .iter()
+      .any(|item| matches!(item.kind, MenuItemKind::Checkable(_)));
+
+    self.check_width = if is_checkable { 2 } else { 0 };
+
+    self.label_width = menu
+      .items
+      .iter()
+      .map(|item| item.label.chars().count() as u16)
+      .max()
+      .unwrap_or_default();
+
+    let hint_width = menu
+      .items
+      .iter()
+      .map(|item| hint(item).chars().count() as u16)
+      .max()
+      .unwrap_or_default();
+
+    // Borders and a space of padding on both sides, with two spaces
+    // between labels and hints
+    let width = 4
+      + self.check_width
+      + self.label_width
+      + if hint_width > 0 { hint_width + 2 } else { 0 };
+
+    self.size = Size {
+      width: width.clamp(limits.min_width, limits.max_width),
+      height: (menu.items.len() as u16 + 2)
+        .clamp(limits.min_height, limits.max_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let mut state = self.controller.state.lock().unwrap();
+
+    let Some(menu) = state.levels.get_mut(self.level) else {
+      return;
+    };
+
+    menu.rect = Rect {
+      top_left: surface.top_left(),
+      size: self.size,
+    };
+
+    let Size { width, height } = self.size;
+
+    if width < 2 || height < 2 {
+      return;
+    }
+
+    let inner_width = width - 2;
+    let theme = self.controller.theme.get(&self.name());
+    let border = theme.surface.patch(theme.border);
+
+    surface.write_with_style(
+      XY { x: 0, y: 0 },
+      &format!("┌{}┐", "─".repeat(inner_width as usize)),
+      border,
+    );
+
+    for (i, item) in menu.items.iter().enumerate() {
+      let y = i as u16 + 1;
+
+      if y >= height - 1 {
+        break;
+      }
+
+      if let MenuItemKind::Separator = item.kind {
+        surface.write_with_style(
+          XY { x: 0, y },
+          &format!("├{}┤", "─".repeat(inner_width as usize)),
+          border,
+        );
+
+        continue;
+      }
+
+      surface.write_with_style(XY { x: 0, y }, "│", border);
+      surface.write_with_style(XY { x: width - 1, y }, "│", border);
+
+      let is_highlighted = menu.highlighted == Some(i);
+
+      let check = match item.kind {
+        MenuItemKind::Checkable(checked) if checked.get_untracked() => "✓ ",
+        _ => "  ",
+      };
+
+      let hint = hint(item);
+      let label_width = inner_width
+        .saturating_sub(2 + self.check_width)
+        .saturating_sub(hint.chars().count() as u16);
+
+      let label = clip_to_width(
+        &format!(
+          "{:label_width$}",
+          item.label,
+          label_width = label_width as usize
+        ),
+        label_width,
+      );
+
+      let row = format!(
+        " {}{label}{hint} ",
+        &check[..if self.check_width > 0 { check.len() } else { 0 }],
+      );
+
+      let style = theme.surface.patch(if item.disabled {
+        theme.muted
+      } else if is_highlighted {
+        theme.focus
+      } else {
+        theme.text
+      });
+
+      write_label(
+        surface,
+        XY { x: 1, y },
+        &clip_to_width(&row, inner_width),
+        item
+          .mnemonic
+          .map(|(at, c)| (at + 1 + self.check_width as usize, c)),
+        if is_highlighted {
+          Style {
+            attribute: theme.selected.attribute,
+            ..style
+          }
+        } else {
+          style
+        },
+      );
+    }
+
+    surface.write_with_style(
+      XY {
+        x: 0,
+        y: height - 1,
+      },
+      &format!("└{}┘", "─".repeat(inner_width as usize)),
+      border,
+    );
+  }
+
+  fn has_focus(&self) -> bool {
+    true
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::Key(key) => self.controller.on_key(key),
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) => {
+        let is_inside = row > 0
+          && row < self.size.height.saturating_sub(1)
+          && column > 0
+          && column < self.size.width.saturating_sub(1);
+
+        if !is_inside {
+          return EventHandlerResult::Captured;
+        }
+
+        let i = row as usize - 1;
+
+        let on_select = {
+          let mut state = self.controller.state.lock().unwrap();
+
+          match kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+              self.controller.select(&mut state, self.level, i)
+            }
+            MouseEventKind::Moved => {
+              let menu = &mut state.levels[self.level];
+
+              let is_selectable =
+                menu.items.get(i).is_some_and(MenuItem::is_selectable);
+
+              if is_selectable && menu.highlighted != Some(i) {
+                menu.highlighted = Some(i);
+
+                self.controller.close_levels(&mut state, self.level + 1);
+                self.controller.open_submenu(&mut state);
+              }
+
+              None
+            }
+            _ => None,
+          }
+        };
+
+        if let Some(on_select) = on_select {
+          on_select();
+        }
+      }
+      _ => return EventHandlerResult::Bubble,
+    }
+
+    EventHandlerResult::Captured
+  }
+
+  fn blur(&mut self) {
+    self.controller.close_to(0);
+  }
+}
+
+/// Splits the mnemonic marked with `&` out of a label, returning the label
+/// without it, and the index and lowercase version of the marked
+/// character.
+fn parse_mnemonic(label: &str) -> (String, Option<(usize, char)>) {
+  let mut parsed = String::new();
+  let mut mnemonic = None;
+  let mut chars = label.chars();
+
+  while let Some(c) = chars.next() {
+    if c != '&' {
+      parsed.push(c);
+
+      continue;
+    }
+
+    match chars.next() {
+      Some('&') => parsed.push('&'),
+      Some(c) => {
+        if mnemonic.is_none() {
+          mnemonic =
+            Some((parsed.chars().count(), c.to_lowercase().next().unwrap()));
+        }
+
+        parsed.push(c);
+      }
+      None => {}
+    }
+  }
+
+  (parsed, mnemonic)
+}
+
+/// Returns the index of the first mnemonic which is `c`, regardless of
+/// case.
+fn find_mnemonic(
+  mnemonics: impl Iterator<Item = Option<(usize, char)>>,
+  c: char,
+) -> Option<usize> {
+  let c = c.to_lowercase().next()?;
+
+  mnemonics
+    .enumerate()
+    .find(|(_, mnemonic)| matches!(mnemonic, Some((_, m)) if *m == c))
+    .map(|(i, _)| i)
+}
+
+/// Returns the hint shown on the right of an item.
+fn hint(item: &MenuItem) -> Cow<'static, str> {
+  match (&item.kind, &item.shortcut) {
+    (_, Some(shortcut)) => shortcut.clone(),
+    (MenuItemKind::Submenu(_), None) => "▸".into(),
+    _ => "".into(),
+  }
+}
+
+/// Moves the highlight to the next selectable item in `direction`,
+/// wrapping around.
+fn move_highlight(menu: &mut OpenMenu, direction: isize) {
+  let len = menu.items.len() as isize;
+
+  if len == 0 {
+    return;
+  }
+
+  let start = menu
+    .highlighted
+    .map_or(if direction > 0 { -1 } else { len }, |i| i as isize);
+
+  for step in 1..=len {
+    let i = (start + direction * step).rem_euclid(len) as usize;
+
+    if menu.items[i].is_selectable() {
+      menu.highlighted = Some(i);
+
+      return;
+    }
+  }
+}
+
+/// Writes a label with its mnemonic, the character at `mnemonic`'s index,
+/// underlined, unless the whole label has another attribute.
+fn write_label(
+  surface: &mut dyn DrawSurface,
+  at: XY,
+  label: &str,
+  mnemonic: Option<(usize, char)>,
+  style: Style,
+) {
+  let Some((i, _)) = mnemonic.filter(|_| style.attribute.is_none()) else {
+    surface.write_with_style(at, label, style);
+
+    return;
+  };
+
+  let before = label.chars().take(i).collect::<String>();
+  let marked = label.chars().skip(i).take(1).collect::<String>();
+  let after = label.chars().skip(i + 1).collect::<String>();
+
+  let x = at.x + i as u16;
+
+  surface.write_with_style(at, &before, style);
+  surface.write_with_style(
+    XY { x, y: at.y },
+    &marked,
+    style.attribute(Attribute::Underlined),
+  );
+  surface.write_with_style(XY { x: x + 1, y: at.y }, &after, style);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Shortcuts;
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    provide_context,
+    run_scope,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn provide(cx: Scope) -> (Overlays, Shortcuts) {
+    let overlays = Overlays::default();
+    let shortcuts = Shortcuts::default();
+
+    provide_context(cx, overlays.clone());
+    provide_context(cx, shortcuts.clone());
+
+    (overlays, shortcuts)
+  }
+
+  #[test]
+  fn parses_mnemonics() {
+    assert_eq!(parse_mnemonic("&File"), ("File".into(), Some((0, 'f'))));
+    assert_eq!(
+      parse_mnemonic("Save &As"),
+      ("Save As".into(), Some((5, 'a')))
+    );
+    assert_eq!(parse_mnemonic("A && B"), ("A & B".into(), None));
+  }
+
+  #[test]
+  fn navigates_and_selects_items() {
+    run_scope(create_runtime(), |cx| {
+      let (overlays, shortcuts) = provide(cx);
+
+      let saved = Arc::new(AtomicUsize::new(0));
+      let wrap = create_rw_signal(cx, false);
+
+      let menu_bar = MenuBar::new([Menu::new(
+        "&File",
+        [
+          MenuItem::new("&Open", || {}).disabled(true),
+          MenuItem::separator(),
+          MenuItem::new("&Save", {
+            let saved = saved.clone();
+
+            move || {
+              saved.fetch_add(1, Ordering::SeqCst);
+            }
+          })
+          .shortcut("Ctrl+S"),
+          MenuItem::checkable("&Wrap", wrap),
+        ],
+      )]);
+
+      let _view = menu_bar.into_view(cx);
+
+      let alt_f =
+        Event::Key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::ALT));
+
+      assert!(shortcuts.on(&alt_f).is_some());
+      assert!(!overlays.is_empty());
+
+      // The disabled item and the separator are skipped
+      overlays.on(&key(KeyCode::Down));
+      overlays.on(&key(KeyCode::Enter));
+
+      assert!(overlays.is_empty());
+      assert!(wrap.get_untracked());
+
+      shortcuts.on(&alt_f);
+      overlays.on(&key(KeyCode::Char('s')));
+
+      assert_eq!(saved.load(Ordering::SeqCst), 1);
+      assert!(overlays.is_empty());
+    });
+  }
+
+  #[test]
+  fn opens_and_closes_submenus() {
+    run_scope(create_runtime(), |cx| {
+      let (overlays, _) = provide(cx);
+
+      let context_menu = ContextMenu::new(
+        "target",
+        [MenuItem::submenu("Recent", [MenuItem::new("a", || {})])],
+      );
+
+      let view = context_menu.into_view(cx);
+
+      let View::Widget(widget) = &view else {
+        unreachable!()
+      };
+
+      widget.lock().unwrap().on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Right),
+        column: 2,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert!(!overlays.is_empty());
+
+      overlays.on(&key(KeyCode::Right));
+      overlays.on(&key(KeyCode::Left));
+      overlays.on(&key(KeyCode::Right));
+
+      let levels = || {
+        let mut buf = vec![];
+        let mut surface = crate::BufDrawSurface::new(&mut buf, (40, 10));
+
+        overlays.draw(&mut surface);
+
+        drop(surface);
+
+        String::from_utf8(buf).unwrap().matches('┌').count()
+      };
+
+      assert_eq!(levels(), 2);
+
+      overlays.on(&key(KeyCode::Esc));
+
+      assert_eq!(levels(), 1);
+
+      // Clicking outside of the menu closes it
+      overlays.on(&Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 39,
+        row: 9,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert!(overlays.is_empty());
+    });
+  }
+}
@@ -0,0 +1,362 @@
+use crate::{
+  use_overlays,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  OverlayId,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+};
+use leptos_reactive::{
+  create_effect,
+  on_cleanup,
+  RwSignal,
+  Scope,
+  SignalGet,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A dialog shown in a bordered window centered above the rest of the UI
+/// while `open` is `true`.
+///
+/// Everything behind the dialog is dimmed and receives no events while it
+/// is open. Tab and Shift+Tab cycle through the focusable widgets inside the
+/// dialog, and Esc closes it by setting `open` to `false`, unless a widget
+/// inside the dialog handles Esc itself.
+///
+/// Since the widgets behind the dialog never lose focus, whichever was
+/// focused before the dialog opened is focused again once it closes.
+///
+/// The dialog's content is created every time it opens, in its own
+/// reactive scope, which is disposed of when it closes. The `Modal` itself
+/// takes up no space where it is placed in the view tree.
+pub struct Modal {
+  open: RwSignal<bool>,
+  title: Option<Cow<'static, str>>,
+  view_fn: Box<dyn Fn(Scope) -> View + Send + Sync>,
+}
+
+impl fmt::Debug for Modal {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Modal")
+      .field("open", &self.open)
+      .field("title", &self.title)
+      .field("view_fn", &"Fn(Scope) -> View")
+      .finish()
+  }
+}
+
+impl IntoView for Modal {
+  fn into_view(self, cx: Scope) -> View {
+    let overlays = use_overlays(cx);
+    let theme = ThemeHandle::new(cx);
+
+    let Self {
+      open,
+      title,
+      view_fn,
+    } = self;
+
+    // The dialog's overlay and the scope its content was created in, while
+    // it is open
+    let shown = Arc::new(Mutex::new(None::<(OverlayId, Scope)>));
+
+    // The content's scope is a child of `cx`, so it is already disposed of
+    // by the time this runs
+    on_cleanup(cx, {
+      let overlays = overlays.clone();
+      let shown = shown.clone();
+
+      move || {
+        if let Some((id, _)) = shown.lock().unwrap().take() {
+          overlays.remove(id);
+        }
+      }
+    });
+
+    create_effect(cx, move |_| {
+      let mut shown = shown.lock().unwrap();
+
+      if !open.get() {
+        if let Some((id, cx)) = shown.take() {
+          overlays.remove(id);
+
+          cx.dispose();
+        }
+
+        return;
+      }
+
+      if shown.is_some() {
+        return;
+      }
+
+      let ((content, content_cx), _) =
+        cx.run_child_scope(|cx| (cx.untrack(|| view_fn(cx)), cx));
+
+      let mut window = ModalWindow {
+        open,
+        title: title.clone(),
+        content,
+        size: Size::default(),
+        content_size: Size::default(),
+        theme,
+      };
+
+      // Focus the first focusable widget of the dialog
+      window.on(Event::NextFocus);
+
+      let id = overlays.push_modal(Arc::new(Mutex::new(window)));
+
+      *shown = Some((id, content_cx));
+    });
+
+    ().into_view(cx)
+  }
+}
+
+impl Modal {
+  /// Creates a dialog shown while `open` is `true`, with the content
+  /// returned by `view`.
+  pub fn new<V: IntoView>(
+    open: RwSignal<bool>,
+    view: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      open,
+      title: None,
+      view_fn: Box::new(move |cx| view().into_view(cx)),
+    }
+  }
+
+  /// Sets the title shown in the dialog's top border.
+  pub fn title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+    self.title = Some(title.into());
+
+    self
+  }
+}
+
+/// The bordered window of an open [`Modal`], pushed onto the overlay layer.
+#[derive(Debug)]
+struct ModalWindow {
+  open: RwSignal<bool>,
+  title: Option<Cow<'static, str>>,
+  content: View,
+  size: Size,
+  content_size: Size,
+  theme: ThemeHandle,
+}
+
+impl Widget for ModalWindow {
+  fn name(&self) -> Cow<'static, str> {
+    "Modal".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.content_size = self.content.layout(Limits {
+      min_width: 0,
+      max_width: limits.max_width.saturating_sub(2),
+      min_height: 0,
+      max_height: limits.max_height.saturating_sub(2),
+    });
+
+    let title_width = self
+      .title
+      .as_ref()
+      .map(|title| title.chars().count() as u16 + 4)
+      .unwrap_or_default();
+
+    self.size = Size {
+      width: (self.content_size.width.max(title_width) + 2)
+        .clamp(limits.min_width, limits.max_width),
+      height: (self.content_size.height + 2)
+        .clamp(limits.min_height, limits.max_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Size { width, height } = self.size;
+
+    if width < 2 || height < 2 {
+      return;
+    }
+
+    let inner_width = width as usize - 2;
+
+    let top = match &self.title {
+      Some(title) => {
+        let title = format!("─ {title} ");
+        let title = title.chars().take(inner_width).collect::<String>();
+        let fill = inner_width - title.chars().count();
+
+        format!("┌{title}{}┐", "─".repeat(fill))
+      }
+      None => format!("┌{}┐", "─".repeat(inner_width)),
+    };
+
+    let theme = self.theme.get(&self.name());
+    let border = theme.surface.patch(theme.border);
+
+    surface.write_with_style(XY { x: 0, y: 0 }, &top, border);
+
+    for y in 1..height - 1 {
+      surface.write_with_style(XY { x: 0, y }, "│", border);
+      surface.write_with_style(XY { x: width - 1, y }, "│", border);
+    }
+
+    surface.write_with_style(
+      XY {
+        x: 0,
+        y: height - 1,
+      },
+      &format!("└{}┘", "─".repeat(inner_width)),
+      border,
+    );
+
+    if self.content_size.width > 0 && self.content_size.height > 0 {
+      surface.shrink(
+        XY { x: 1, y: 1 },
+        self.content_size,
+        Box::new(|surface| self.content.draw(surface)),
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.content.needs_focus()
+  }
+
+  fn has_focus(&self) -> bool {
+    self.content.has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::Key(KeyEvent {
+        code: KeyCode::Esc, ..
+      }) => match self.content.on(event) {
+        EventHandlerResult::Bubble => {
+          self.open.set(false);
+
+          EventHandlerResult::Captured
+        }
+        result => result,
+      },
+      Event::Mouse(_) => {
+        match event.relative_to(XY { x: 1, y: 1 }, self.content_size) {
+          Some(event) => self.content.on(event),
+          None => EventHandlerResult::Captured,
+        }
+      }
+      event => self.content.on(event),
+    }
+  }
+
+  fn focus(&mut self) {
+    self.content.focus();
+  }
+
+  fn blur(&mut self) {
+    self.content.blur();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::Checkbox,
+    Overlays,
+  };
+  use crossterm::event::KeyModifiers;
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    provide_context,
+    run_scope,
+    SignalGetUntracked,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  #[test]
+  fn opens_and_closes_with_signal_and_esc() {
+    run_scope(create_runtime(), |cx| {
+      let overlays = Overlays::default();
+      provide_context(cx, overlays.clone());
+
+      let open = create_rw_signal(cx, false);
+
+      let _view = Modal::new(open, || "hello").title("Hi").into_view(cx);
+
+      assert!(!overlays.has_modal());
+
+      open.set(true);
+
+      assert!(overlays.has_modal());
+
+      assert!(matches!(
+        overlays.on(&key(KeyCode::Esc)),
+        Some(EventHandlerResult::Captured)
+      ));
+
+      assert!(!open.get_untracked());
+      assert!(overlays.is_empty());
+    });
+  }
+
+  #[test]
+  fn traps_focus_and_swallows_events() {
+    run_scope(create_runtime(), |cx| {
+      let overlays = Overlays::default();
+      provide_context(cx, overlays.clone());
+
+      let open = create_rw_signal(cx, true);
+      let checked = create_rw_signal(cx, false);
+
+      let _view =
+        Modal::new(open, move || Checkbox::new("check", checked)).into_view(cx);
+
+      // Focus wraps around to the only checkbox instead of leaving the
+      // dialog
+      for _ in 0..3 {
+        assert!(matches!(
+          overlays.on(&Event::NextFocus),
+          Some(EventHandlerResult::Captured)
+        ));
+      }
+
+      overlays.on(&key(KeyCode::Char(' ')));
+
+      assert!(checked.get_untracked());
+
+      // Keys the dialog doesn't handle don't reach the views behind it
+      assert!(matches!(
+        overlays.on(&key(KeyCode::Char('x'))),
+        Some(EventHandlerResult::Captured)
+      ));
+    });
+  }
+}
@@ -0,0 +1,278 @@
+use crate::{
+  use_router,
+  use_shortcuts,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  View,
+  Widget,
+};
+use leptos_reactive::{
+  create_effect,
+  on_cleanup,
+  Scope,
+  SignalWith,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Shows the current screen of the [`Router`](crate::Router) created above
+/// it.
+///
+/// Every screen is created in its own reactive scope when it becomes the
+/// current one, and that scope is disposed of once another screen replaces
+/// it on the outlet, including when going back to a screen below it on the
+/// stack, which is created anew.
+///
+/// If the outlet had focus when the screen changed, the first focusable
+/// widget of the new screen is focused.
+pub struct Outlet {
+  /// The screen to show next, set as soon as the stack changes and swapped
+  /// in when the outlet is next laid out or gets an event. This way,
+  /// navigating from an event handler of the current screen, while the
+  /// outlet is busy dispatching the event, doesn't need to replace the
+  /// screen in the middle of it.
+  pending: Arc<Mutex<Option<Option<Screen>>>>,
+  current: Option<Screen>,
+}
+
+struct Screen {
+  view: View,
+  cx: Scope,
+}
+
+impl fmt::Debug for Outlet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Outlet")
+      .field("current", &self.current.as_ref().map(|screen| &screen.view))
+      .finish()
+  }
+}
+
+impl Widget for Outlet {
+  fn name(&self) -> Cow<'static, str> {
+    "Outlet".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.swap_screens();
+
+    match &mut self.current {
+      Some(screen) => screen.view.layout(limits),
+      None => limits.min_size(),
+    }
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if let Some(screen) = &self.current {
+      screen.view.draw(surface);
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self
+      .current
+      .as_ref()
+      .and_then(|screen| screen.view.needs_focus())
+  }
+
+  fn has_focus(&self) -> bool {
+    self
+      .current
+      .as_ref()
+      .is_some_and(|screen| screen.view.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    self.swap_screens();
+
+    match &mut self.current {
+      Some(screen) => screen.view.on(event),
+      None => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(screen) = &mut self.current {
+      screen.view.focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    if let Some(screen) = &mut self.current {
+      screen.view.blur();
+    }
+  }
+}
+
+impl IntoView for Outlet {
+  fn into_view(self, cx: Scope) -> View {
+    let router = use_router(cx);
+
+    if let Some(shortcut) = *router.back_shortcut.lock().unwrap() {
+      let shortcuts = use_shortcuts(cx);
+
+      let id = shortcuts.register(shortcut, {
+        let router = router.clone();
+
+        move || {
+          router.pop();
+        }
+      });
+
+      on_cleanup(cx, move || shortcuts.unregister(id));
+    }
+
+    let stack = router.stack;
+    let pending = self.pending.clone();
+
+    create_effect(cx, move |shown: Option<Option<usize>>| {
+      let entry = stack.with(|stack| stack.last().cloned());
+      let id = entry.as_ref().map(|entry| entry.id);
+
+      if shown == Some(id) {
+        return id;
+      }
+
+      let screen = entry.map(|entry| {
+        let ((view, cx), _) =
+          cx.run_child_scope(|cx| (cx.untrack(|| (entry.view)(cx)), cx));
+
+        Screen { view, cx }
+      });
+
+      // A screen which was replaced before it was ever shown
+      if let Some(Some(skipped)) = pending.lock().unwrap().replace(screen) {
+        skipped.cx.dispose();
+      }
+
+      id
+    });
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Outlet {
+  pub fn new() -> Self {
+    Self {
+      pending: Default::default(),
+      current: None,
+    }
+  }
+
+  /// Replaces the current screen with the pending one, if any.
+  fn swap_screens(&mut self) {
+    let Some(screen) = self.pending.lock().unwrap().take() else {
+      return;
+    };
+
+    let had_focus = self.has_focus();
+
+    if let Some(old) = std::mem::replace(&mut self.current, screen) {
+      old.cx.dispose();
+    }
+
+    if let (true, Some(screen)) = (had_focus, &mut self.current) {
+      screen.view.on(Event::NextFocus);
+    }
+  }
+}
+
+impl Default for Outlet {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::Checkbox,
+    Route,
+    Router,
+    Shortcuts,
+  };
+  use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    provide_context,
+    run_scope,
+    SignalGetUntracked,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  #[test]
+  fn replaced_screens_are_disposed_of() {
+    run_scope(create_runtime(), |cx| {
+      let shortcuts = Shortcuts::default();
+
+      provide_context(cx, shortcuts.clone());
+
+      let router = Router::new(cx);
+      let disposed = Arc::new(AtomicUsize::new(0));
+
+      let detail = Route::new("detail", {
+        let disposed = disposed.clone();
+
+        move |cx, id: usize| {
+          let disposed = disposed.clone();
+
+          on_cleanup(cx, move || {
+            disposed.fetch_add(1, Ordering::SeqCst);
+          });
+
+          Checkbox::new(format!("item {id}"), create_rw_signal(cx, false))
+        }
+      });
+
+      router.push(&detail, 1);
+
+      let mut outlet = Outlet::new().into_view(cx);
+
+      outlet.layout(Limits::strict(10, 1));
+      outlet.on(Event::NextFocus);
+
+      router.push(&detail, 2);
+
+      // Pushed and replaced before it was ever shown
+      router.replace(&detail, 3);
+
+      assert_eq!(disposed.load(Ordering::SeqCst), 1);
+
+      outlet.layout(Limits::strict(10, 1));
+
+      assert_eq!(disposed.load(Ordering::SeqCst), 2);
+      assert!(outlet.has_focus());
+      assert_eq!(router.current().as_deref(), Some("detail"));
+      assert_eq!(router.depth(), 2);
+
+      shortcuts
+        .on(&Event::Key(KeyEvent::new(KeyCode::Left, KeyModifiers::ALT)));
+      outlet.layout(Limits::strict(10, 1));
+
+      assert_eq!(disposed.load(Ordering::SeqCst), 3);
+      assert!(!router.can_go_back());
+      assert!(!router.pop());
+      assert_eq!(router.stack.get_untracked().len(), 1);
+    });
+  }
+}
@@ -0,0 +1,235 @@
+use crate::{
+  use_overlays,
+  ArcWidget,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Placement,
+  Rect,
+  Size,
+  View,
+  Widget,
+};
+use leptos_reactive::{
+  on_cleanup,
+  Scope,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Where the content of a [`Portal`] is placed.
+#[derive(Clone, Debug)]
+enum PortalPlacement {
+  /// At the portal's own position.
+  Over,
+  /// Right below the portal's position, or above it if there isn't enough
+  /// room below.
+  Below,
+  /// Regardless of where the portal is.
+  Fixed(Placement),
+}
+
+/// Draws its content on the [`Overlays`](crate::Overlays) layer, above the
+/// main view tree and unclipped by the area of the widgets it is in, such
+/// as for popups and tooltips.
+///
+/// The portal itself takes up no space where it is placed in the view
+/// tree, and its content is placed at that position unless told otherwise.
+/// The content stays on the overlay layer for as long as the scope the
+/// portal was created in, which can be limited with [`Show`](crate::Show).
+///
+/// Focus moves through the content in the portal's place in the view tree,
+/// while keys and mouse events are sent to it by the overlay layer.
+pub struct Portal {
+  view_fn: Box<dyn FnOnce(Scope) -> View + Send + Sync>,
+  placement: PortalPlacement,
+}
+
+impl fmt::Debug for Portal {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Portal")
+      .field("view_fn", &"FnOnce(Scope) -> View")
+      .field("placement", &self.placement)
+      .finish()
+  }
+}
+
+impl IntoView for Portal {
+  fn into_view(self, cx: Scope) -> View {
+    let overlays = use_overlays(cx);
+    let anchor = Arc::<Mutex<Rect>>::default();
+
+    let placement = match self.placement {
+      PortalPlacement::Over => Placement::Over(anchor.clone()),
+      PortalPlacement::Below => Placement::Below(anchor.clone()),
+      PortalPlacement::Fixed(placement) => placement,
+    };
+
+    let content: ArcWidget = Arc::new(Mutex::new((self.view_fn)(cx)));
+
+    let id = overlays.push(content.clone(), placement);
+
+    on_cleanup(cx, move || overlays.remove(id));
+
+    View::Widget(Arc::new(Mutex::new(PortalAnchor {
+      anchor,
+      content,
+      size: Size::default(),
+    })))
+  }
+}
+
+impl Portal {
+  /// Creates a portal whose content is `view`.
+  pub fn new(view: impl IntoView + Send + Sync + 'static) -> Self {
+    Self {
+      view_fn: Box::new(|cx| view.into_view(cx)),
+      placement: PortalPlacement::Over,
+    }
+  }
+
+  /// Places the content right below the portal's position, or above it if
+  /// there isn't enough room below, like a dropdown.
+  pub fn below(mut self) -> Self {
+    self.placement = PortalPlacement::Below;
+
+    self
+  }
+
+  /// Places the content regardless of where the portal is, such as at an
+  /// absolute position with [`Placement::At`].
+  pub fn placement(mut self, placement: Placement) -> Self {
+    self.placement = PortalPlacement::Fixed(placement);
+
+    self
+  }
+}
+
+/// What a [`Portal`] leaves in the view tree, recording where it is drawn
+/// for its content to be anchored to.
+struct PortalAnchor {
+  anchor: Arc<Mutex<Rect>>,
+  content: ArcWidget,
+  size: Size,
+}
+
+impl fmt::Debug for PortalAnchor {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Portal")
+      .field("anchor", &self.anchor)
+      .field("content", &self.content)
+      .finish()
+  }
+}
+
+impl Widget for PortalAnchor {
+  fn name(&self) -> Cow<'static, str> {
+    "Portal".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.size = limits.min_size();
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    *self.anchor.lock().unwrap() = Rect {
+      top_left: surface.top_left(),
+      size: self.size,
+    };
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.content.lock().unwrap().needs_focus()
+  }
+
+  fn has_focus(&self) -> bool {
+    self.content.lock().unwrap().has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      // Keys and mouse events already reach the content through the
+      // overlay layer
+      Event::NextFocus | Event::PrevFocus | Event::Batch(_) => {
+        self.content.lock().unwrap().on(event)
+      }
+      Event::Key(_) | Event::Mouse(_) => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.content.lock().unwrap().focus();
+  }
+
+  fn blur(&mut self) {
+    self.content.lock().unwrap().blur();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::Checkbox,
+    BufDrawSurface,
+    Overlays,
+    XY,
+  };
+  use crossterm::cursor::MoveTo;
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    provide_context,
+    run_scope,
+  };
+
+  #[test]
+  fn content_is_drawn_over_the_portal_position() {
+    run_scope(create_runtime(), |cx| {
+      let overlays = Overlays::default();
+
+      provide_context(cx, overlays.clone());
+
+      let (mut portal, disposer) = cx.run_child_scope(|cx| {
+        Portal::new(Checkbox::new("hi", create_rw_signal(cx, false)))
+          .into_view(cx)
+      });
+
+      let mut buf = vec![];
+      let mut surface = BufDrawSurface::new(&mut buf, (10u16, 4u16));
+
+      portal.layout(Limits::default());
+      surface.shrink(
+        XY { x: 2, y: 3 },
+        Size::default(),
+        Box::new(|surface| portal.draw(surface)),
+      );
+      overlays.draw(&mut surface);
+
+      drop(surface);
+
+      assert!(String::from_utf8(buf)
+        .unwrap()
+        .contains(&format!("{}[ ] hi", MoveTo(2, 3))));
+
+      // Focus moves through the content in the portal's place
+      portal.on(Event::NextFocus);
+
+      assert!(portal.has_focus());
+
+      disposer.dispose();
+
+      assert!(overlays.is_empty());
+    });
+  }
+}
@@ -0,0 +1,226 @@
+use crate::{
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Blocks filling one to seven eighths of a cell, from the left.
+const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// A single-line bar showing how far along a task is, filled with
+/// sub-cell precision, optionally preceded by a label and followed by the
+/// percentage.
+pub struct ProgressBar {
+  progress: Box<dyn Fn() -> f64 + Send + Sync>,
+  label: Option<Cow<'static, str>>,
+  show_percentage: bool,
+  /// Progress when the bar was last laid out, clamped to `0.0..=1.0`.
+  ratio: f64,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for ProgressBar {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ProgressBar")
+      .field("progress", &"Fn() -> f64")
+      .field("label", &self.label)
+      .field("show_percentage", &self.show_percentage)
+      .field("ratio", &self.ratio)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for ProgressBar {
+  fn name(&self) -> Cow<'static, str> {
+    "ProgressBar".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.ratio = clamp_ratio((self.progress)());
+
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size {
+      width: limits.max_width,
+      height,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if self.size.height == 0 {
+      return;
+    }
+
+    let theme = self.theme.get(&self.name());
+    let mut x = 0;
+
+    if let Some(label) = &self.label {
+      surface.write_with_style(XY { x, y: 0 }, label, theme.text);
+
+      x += label.chars().count() as u16 + 1;
+    }
+
+    let percentage = format!(" {:>3}%", (self.ratio * 100.0).round());
+
+    let width = self.size.width.saturating_sub(x).saturating_sub(
+      if self.show_percentage {
+        percentage.len() as u16
+      } else {
+        0
+      },
+    );
+
+    // The track is drawn in the muted color, behind the filled blocks
+    surface.write_styled(
+      XY { x, y: 0 },
+      &block_bar(self.ratio, width),
+      theme.text.foreground,
+      theme.muted.foreground,
+      None,
+    );
+
+    if self.show_percentage {
+      surface.write_with_style(
+        XY { x: x + width, y: 0 },
+        &percentage,
+        theme.text,
+      );
+    }
+  }
+}
+
+impl IntoView for ProgressBar {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl ProgressBar {
+  /// Creates a bar showing `progress()`, which ranges from `0.0` to
+  /// `1.0`.
+  pub fn new(progress: impl Fn() -> f64 + Send + Sync + 'static) -> Self {
+    Self {
+      progress: Box::new(progress),
+      label: None,
+      show_percentage: true,
+      ratio: 0.0,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Shows `label` before the bar.
+  pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+    self.label = Some(label.into());
+
+    self
+  }
+
+  /// Sets whether the percentage is shown after the bar, which it is by
+  /// default.
+  pub fn show_percentage(mut self, show_percentage: bool) -> Self {
+    self.show_percentage = show_percentage;
+
+    self
+  }
+}
+
+/// Clamps `ratio` to `0.0..=1.0`, treating `NaN` as `0.0`.
+pub(crate) fn clamp_ratio(ratio: f64) -> f64 {
+  if ratio.is_nan() {
+    0.0
+  } else {
+    ratio.clamp(0.0, 1.0)
+  }
+}
+
+/// Returns a bar `width` cells wide, filled up to `ratio` in eighths of a
+/// cell, and padded with spaces.
+pub(crate) fn block_bar(ratio: f64, width: u16) -> String {
+  let eighths = (ratio * width as f64 * 8.0).round() as usize;
+  let full = eighths / 8;
+
+  let mut bar = "█".repeat(full);
+
+  if let Some(partial) = (eighths % 8).checked_sub(1) {
+    bar.push(EIGHTHS[partial]);
+  }
+
+  let padding = (width as usize).saturating_sub(bar.chars().count());
+
+  bar.extend(std::iter::repeat_n(' ', padding));
+
+  bar
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Color,
+      Print,
+      ResetColor,
+      SetBackgroundColor,
+    },
+  };
+
+  #[test]
+  fn fills_in_eighths() {
+    assert_eq!(block_bar(0.0, 4), "    ");
+    assert_eq!(block_bar(0.5, 4), "██  ");
+    assert_eq!(block_bar(0.55, 4), "██▎ ");
+    assert_eq!(block_bar(1.0, 4), "████");
+  }
+
+  #[test]
+  fn draws_label_bar_and_percentage() {
+    let bar = ProgressBar::new(|| 0.25).label("job");
+
+    let (size, output) = get_widget_output(bar, (17, 3), (0, 0));
+
+    assert_eq!(size, (17, 1).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print("job"),
+        MoveTo(4, 0),
+        Print(format!(
+          "{}██      {}",
+          SetBackgroundColor(Color::DarkGrey),
+          ResetColor
+        )),
+        MoveTo(12, 0),
+        Print("  25%"),
+      ]
+    );
+  }
+}
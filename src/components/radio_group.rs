@@ -0,0 +1,266 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A vertical list of `(*) option` choices, of which exactly one is
+/// selected. The index of the selected option is bound to a signal.
+///
+/// While focused, the arrow keys move between options, and Space or
+/// Enter selects the highlighted one.
+#[derive(Debug)]
+pub struct RadioGroup {
+  options: Vec<Cow<'static, str>>,
+  selected: RwSignal<usize>,
+  highlighted: usize,
+  formatted_options: Vec<String>,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl Widget for RadioGroup {
+  fn name(&self) -> Cow<'static, str> {
+    "RadioGroup".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let selected = self.selected.get_untracked();
+
+    self.formatted_options = self
+      .options
+      .iter()
+      .take(limits.max_height as usize)
+      .enumerate()
+      .map(|(i, option)| {
+        let mark = if i == selected { '*' } else { ' ' };
+
+        clip_to_width(&format!("({mark}) {option}"), limits.max_width)
+      })
+      .collect();
+
+    let width = self
+      .formatted_options
+      .iter()
+      .map(|option| option.chars().count() as u16)
+      .max()
+      .unwrap_or_default()
+      .max(limits.min_width);
+    let height = (self.formatted_options.len() as u16).max(limits.min_height);
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let theme = self.theme.get(&self.name());
+
+    for (i, option) in self.formatted_options.iter().enumerate() {
+      let highlighted = self.focused && i == self.highlighted;
+
+      surface.write_with_style(
+        XY { x: 0, y: i as u16 },
+        option,
+        if highlighted { theme.focus } else { theme.text },
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(!self.options.is_empty())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => match code {
+        KeyCode::Up | KeyCode::Left => {
+          self.highlighted = self.highlighted.saturating_sub(1);
+
+          EventHandlerResult::Captured
+        }
+        KeyCode::Down | KeyCode::Right => {
+          self.highlighted =
+            (self.highlighted + 1).min(self.options.len().saturating_sub(1));
+
+          EventHandlerResult::Captured
+        }
+        KeyCode::Char(' ') | KeyCode::Enter => {
+          self.selected.set(self.highlighted);
+
+          EventHandlerResult::Captured
+        }
+        _ => EventHandlerResult::Bubble,
+      },
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        ..
+      }) if column < self.size.width
+        && (row as usize) < self.formatted_options.len() =>
+      {
+        self.focus();
+        self.highlighted = row as usize;
+        self.selected.set(self.highlighted);
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+    self.highlighted = self.selected.get_untracked();
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for RadioGroup {
+  fn into_view(mut self, cx: leptos_reactive::Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl RadioGroup {
+  pub fn new<O>(
+    options: impl IntoIterator<Item = O>,
+    selected: RwSignal<usize>,
+  ) -> Self
+  where
+    O: Into<Cow<'static, str>>,
+  {
+    Self {
+      options: options.into_iter().map(Into::into).collect(),
+      selected,
+      highlighted: 0,
+      formatted_options: vec![],
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::Print,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  #[test]
+  fn renders_options() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 1);
+
+      let (size, output) = get_widget_output(
+        RadioGroup::new(["one", "three"], selected),
+        (20, 5),
+        (0, 0),
+      );
+
+      assert_eq!(size, (9, 2).into());
+
+      assert_eq!(
+        output,
+        commands![
+          MoveTo(0, 0),
+          Print("( ) one"),
+          MoveTo(0, 1),
+          Print("(*) three")
+        ]
+      );
+    });
+  }
+
+  #[test]
+  fn arrows_move_and_space_selects() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 0);
+      let mut group = RadioGroup::new(["a", "b", "c"], selected);
+
+      group.on(Event::NextFocus);
+      group.on(key(KeyCode::Down));
+      group.on(key(KeyCode::Down));
+      group.on(key(KeyCode::Down));
+
+      assert_eq!(selected.get_untracked(), 0);
+
+      group.on(key(KeyCode::Char(' ')));
+
+      assert_eq!(selected.get_untracked(), 2);
+    });
+  }
+
+  #[test]
+  fn click_selects_option() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 0);
+      let mut group = RadioGroup::new(["a", "b", "c"], selected);
+
+      group.layout(Limits::strict(10, 3));
+      group.on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 1,
+        row: 1,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert!(group.has_focus());
+      assert_eq!(selected.get_untracked(), 1);
+    });
+  }
+}
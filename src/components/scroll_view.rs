@@ -0,0 +1,573 @@
+use crate::{
+  cycle_leaf_focus,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  ScrolledDrawSurface,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  KeyModifiers,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// The limit the child of a [`ScrollView`] is laid out with along the axes
+/// it scrolls on. Kept well below [`u16::MAX`] so that children can add to
+/// their sizes without overflowing.
+const UNBOUNDED: u16 = i16::MAX as u16;
+
+/// Shows part of a child which may be larger than the available space,
+/// with scrollbars for the axes it overflows on.
+///
+/// The child is laid out without a limit on the axes the view scrolls
+/// on, which is only vertical by default, and only the visible window is
+/// drawn. If the child has no focusable widgets, the scroll view can be
+/// focused itself.
+///
+/// Up, Down, PageUp, PageDown, Home and End scroll vertically and Left
+/// and Right scroll horizontally, if focus is within the view and the
+/// focused widget doesn't handle them. The mouse wheel scrolls vertically,
+/// or horizontally while Shift is held.
+pub struct ScrollView {
+  child_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  child: Option<View>,
+  vertical: bool,
+  horizontal: bool,
+  offset: XY,
+  offset_signal: Option<RwSignal<XY>>,
+  content_size: Size,
+  viewport: Size,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for ScrollView {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ScrollView")
+      .field("child", &self.child)
+      .field("vertical", &self.vertical)
+      .field("horizontal", &self.horizontal)
+      .field("offset", &self.offset)
+      .field("content_size", &self.content_size)
+      .field("viewport", &self.viewport)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl Widget for ScrollView {
+  fn name(&self) -> Cow<'static, str> {
+    "ScrollView".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    if let Some(offset) = self.offset_signal {
+      self.offset = offset.get_untracked();
+    }
+
+    let Some(child) = &mut self.child else {
+      return limits.min_size();
+    };
+
+    // Scrollbars take up space which the child can't use, so the child is
+    // laid out again whenever showing one changes whether another is
+    // needed
+    let mut bars = (false, false);
+
+    loop {
+      let viewport = Size {
+        width: limits.max_width - bars.0 as u16,
+        height: limits.max_height - bars.1 as u16,
+      };
+
+      self.content_size = child.layout(Limits {
+        min_width: 0,
+        max_width: if self.horizontal {
+          UNBOUNDED
+        } else {
+          viewport.width
+        },
+        min_height: 0,
+        max_height: if self.vertical {
+          UNBOUNDED
+        } else {
+          viewport.height
+        },
+      });
+
+      let needed = (
+        self.vertical
+          && self.content_size.height > viewport.height
+          && viewport.width > 0,
+        self.horizontal
+          && self.content_size.width > viewport.width
+          && viewport.height > 0,
+      );
+
+      if needed == bars {
+        break;
+      }
+
+      bars = (bars.0 || needed.0, bars.1 || needed.1);
+    }
+
+    let available = Size {
+      width: limits.max_width - bars.0 as u16,
+      height: limits.max_height - bars.1 as u16,
+    };
+
+    self.size = Size {
+      width: (self.content_size.width.min(available.width) + bars.0 as u16)
+        .clamp(limits.min_width, limits.max_width),
+      height: (self.content_size.height.min(available.height) + bars.1 as u16)
+        .clamp(limits.min_height, limits.max_height),
+    };
+
+    self.viewport = Size {
+      width: self.size.width - bars.0 as u16,
+      height: self.size.height - bars.1 as u16,
+    };
+
+    self.set_offset(self.offset);
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Some(child) = &self.child else {
+      return;
+    };
+
+    if self.viewport.width > 0 && self.viewport.height > 0 {
+      surface.shrink(
+        XY::default(),
+        self.viewport,
+        Box::new(|surface| {
+          child.draw(&mut ScrolledDrawSurface::new(
+            surface,
+            self.content_size,
+            self.offset,
+          ))
+        }),
+      );
+    }
+
+    let theme = self.theme.get(&self.name());
+
+    if self.size.width > self.viewport.width {
+      let (start, len) = thumb(
+        self.offset.y,
+        self.viewport.height,
+        self.content_size.height,
+      );
+
+      for y in 0..self.viewport.height {
+        let is_thumb = y >= start && y < start + len;
+
+        surface.write_with_style(
+          XY {
+            x: self.viewport.width,
+            y,
+          },
+          if is_thumb { "█" } else { "│" },
+          theme.border,
+        );
+      }
+    }
+
+    if self.size.height > self.viewport.height {
+      let (start, len) =
+        thumb(self.offset.x, self.viewport.width, self.content_size.width);
+
+      let bar = (0..self.viewport.width)
+        .map(|x| {
+          if x >= start && x < start + len {
+            '█'
+          } else {
+            '─'
+          }
+        })
+        .collect::<String>();
+
+      surface.write_with_style(
+        XY {
+          x: 0,
+          y: self.viewport.height,
+        },
+        &bar,
+        theme.border,
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    match self.child_needs_focus() {
+      true => Some(true),
+      false => Some(self.can_scroll()),
+    }
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused || self.child.as_ref().is_some_and(|child| child.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus if self.child_needs_focus() => {
+        self.child.as_mut().unwrap().on(event)
+      }
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) => {
+        if let Some(child) = &mut self.child {
+          if child.has_focus() {
+            match child.on(event) {
+              EventHandlerResult::Bubble => {}
+              result => return result,
+            }
+          } else if !self.focused {
+            return EventHandlerResult::Bubble;
+          }
+        }
+
+        let XY { x, y } = self.offset;
+        let page = self.viewport.height.max(1);
+
+        let offset = match code {
+          KeyCode::Up if self.vertical => XY {
+            x,
+            y: y.saturating_sub(1),
+          },
+          KeyCode::Down if self.vertical => XY { x, y: y + 1 },
+          KeyCode::PageUp if self.vertical => XY {
+            x,
+            y: y.saturating_sub(page),
+          },
+          KeyCode::PageDown if self.vertical => XY { x, y: y + page },
+          KeyCode::Home if self.vertical => XY { x, y: 0 },
+          KeyCode::End if self.vertical => XY { x, y: UNBOUNDED },
+          KeyCode::Left if self.horizontal => XY {
+            x: x.saturating_sub(1),
+            y,
+          },
+          KeyCode::Right if self.horizontal => XY { x: x + 1, y },
+          _ => return EventHandlerResult::Bubble,
+        };
+
+        self.set_offset(offset);
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(mouse) => {
+        if mouse.column >= self.size.width || mouse.row >= self.size.height {
+          return EventHandlerResult::Bubble;
+        }
+
+        // Scrollbars aren't interactive
+        if mouse.column >= self.viewport.width
+          || mouse.row >= self.viewport.height
+        {
+          return EventHandlerResult::Captured;
+        }
+
+        if let Some(child) = &mut self.child {
+          let result = child.on(Event::Mouse(MouseEvent {
+            column: mouse.column + self.offset.x,
+            row: mouse.row + self.offset.y,
+            ..mouse
+          }));
+
+          if child.has_focus() {
+            self.focused = false;
+          }
+
+          if !matches!(result, EventHandlerResult::Bubble) {
+            return result;
+          }
+        }
+
+        let XY { x, y } = self.offset;
+        let horizontal = self.horizontal
+          && (!self.vertical || mouse.modifiers.contains(KeyModifiers::SHIFT));
+
+        let offset = match mouse.kind {
+          MouseEventKind::ScrollUp if horizontal => XY {
+            x: x.saturating_sub(1),
+            y,
+          },
+          MouseEventKind::ScrollDown if horizontal => XY { x: x + 1, y },
+          MouseEventKind::ScrollUp if self.vertical => XY {
+            x,
+            y: y.saturating_sub(1),
+          },
+          MouseEventKind::ScrollDown if self.vertical => XY { x, y: y + 1 },
+          _ => return EventHandlerResult::Bubble,
+        };
+
+        self.set_offset(offset);
+
+        EventHandlerResult::Captured
+      }
+      Event::Batch(events) => {
+        for event in events {
+          self.on(event);
+        }
+
+        EventHandlerResult::Captured
+      }
+    }
+  }
+
+  fn focus(&mut self) {
+    if self.child_needs_focus() {
+      self.child.as_mut().unwrap().focus();
+    } else {
+      self.focused = true;
+    }
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+
+    if let Some(child) = &mut self.child {
+      if child.has_focus() {
+        child.blur();
+      }
+    }
+  }
+}
+
+impl IntoView for ScrollView {
+  fn into_view(mut self, cx: Scope) -> View {
+    if let Some(child_fn) = self.child_fn.take() {
+      self.child = Some(child_fn(cx));
+    }
+
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl ScrollView {
+  /// Creates a view which scrolls `child` vertically.
+  pub fn new(child: impl IntoView + Send + Sync + 'static) -> Self {
+    Self {
+      child_fn: Some(Box::new(|cx| child.into_view(cx))),
+      child: None,
+      vertical: true,
+      horizontal: false,
+      offset: XY::default(),
+      offset_signal: None,
+      content_size: Size::default(),
+      viewport: Size::default(),
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Sets whether the child can be scrolled vertically.
+  pub fn vertical(mut self, vertical: bool) -> Self {
+    self.vertical = vertical;
+
+    self
+  }
+
+  /// Sets whether the child can be scrolled horizontally.
+  pub fn horizontal(mut self, horizontal: bool) -> Self {
+    self.horizontal = horizontal;
+
+    self
+  }
+
+  /// Tracks the scroll offset in `offset`, which can also be set to scroll
+  /// the view. The offset is the position within the child of the view's
+  /// top-left corner.
+  pub fn offset(mut self, offset: RwSignal<XY>) -> Self {
+    self.offset_signal = Some(offset);
+
+    self
+  }
+
+  fn child_needs_focus(&self) -> bool {
+    self
+      .child
+      .as_ref()
+      .is_some_and(|child| child.needs_focus() == Some(true))
+  }
+
+  /// Returns `true` if the child overflows the view.
+  fn can_scroll(&self) -> bool {
+    self.content_size.width > self.viewport.width
+      || self.content_size.height > self.viewport.height
+  }
+
+  /// Scrolls to `offset`, clamped so that the view stays within the child.
+  fn set_offset(&mut self, offset: XY) {
+    self.offset = XY {
+      x: offset
+        .x
+        .min(self.content_size.width.saturating_sub(self.viewport.width)),
+      y: offset.y.min(
+        self
+          .content_size
+          .height
+          .saturating_sub(self.viewport.height),
+      ),
+    };
+
+    if let Some(signal) = self.offset_signal {
+      if signal.get_untracked() != self.offset {
+        signal.set(self.offset);
+      }
+    }
+  }
+}
+
+/// Returns the start and length of a scrollbar's thumb, for a track of
+/// length `viewport`.
+fn thumb(offset: u16, viewport: u16, content: u16) -> (u16, u16) {
+  if content <= viewport {
+    return (0, viewport);
+  }
+
+  let (offset, viewport, content) =
+    (offset as u32, viewport as u32, content as u32);
+
+  let len = (viewport * viewport / content).clamp(1, viewport);
+  let start = offset * (viewport - len) / (content - viewport);
+
+  (start as u16, len as u16)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Color,
+      Print,
+      ResetColor,
+      SetForegroundColor,
+    },
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn lines(cx: Scope, count: usize) -> ScrollView {
+    let text = (0..count)
+      .map(|i| format!("line {i}"))
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    let mut view = ScrollView::new(text);
+    view.child = Some(view.child_fn.take().unwrap()(cx));
+
+    view
+  }
+
+  #[test]
+  fn draws_visible_window_and_scrollbar() {
+    run_scope(create_runtime(), |cx| {
+      let offset = create_rw_signal(cx, XY { x: 0, y: 2 });
+      let view = lines(cx, 4).offset(offset);
+
+      let (size, output) = get_widget_output(view, (10, 2), (0, 0));
+
+      assert_eq!(size, (7, 2).into());
+
+      let bar = |y, s: &str| {
+        format!(
+          "{}{}{s}{}",
+          MoveTo(6, y),
+          SetForegroundColor(Color::DarkGrey),
+          ResetColor
+        )
+      };
+
+      assert_eq!(
+        output,
+        commands![
+          MoveTo(0, 0),
+          Print("line 2"),
+          MoveTo(0, 1),
+          Print("line 3"),
+          Print(bar(0, "│")),
+          Print(bar(1, "█")),
+        ]
+      );
+    });
+  }
+
+  #[test]
+  fn keys_scroll_within_bounds() {
+    run_scope(create_runtime(), |cx| {
+      let offset = create_rw_signal(cx, XY::default());
+      let mut view = lines(cx, 10).offset(offset);
+
+      view.layout(Limits::strict(10, 3));
+
+      assert_eq!(view.needs_focus(), Some(true));
+
+      view.on(Event::NextFocus);
+      view.on(key(KeyCode::PageDown));
+
+      assert_eq!(offset.get_untracked(), XY { x: 0, y: 3 });
+
+      view.on(key(KeyCode::End));
+
+      assert_eq!(offset.get_untracked(), XY { x: 0, y: 7 });
+
+      view.on(key(KeyCode::Down));
+
+      assert_eq!(offset.get_untracked(), XY { x: 0, y: 7 });
+
+      view.on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollUp,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert_eq!(offset.get_untracked(), XY { x: 0, y: 6 });
+
+      offset.set(XY { x: 0, y: 100 });
+      view.layout(Limits::strict(10, 3));
+
+      assert_eq!(offset.get_untracked(), XY { x: 0, y: 7 });
+    });
+  }
+}
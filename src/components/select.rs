@@ -0,0 +1,564 @@
+use super::clip_to_width;
+use crate::{
+  cycle_leaf_focus,
+  use_overlays,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  OverlayId,
+  Overlays,
+  Placement,
+  Rect,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// The maximum number of options shown at once in the popup list.
+const MAX_POPUP_HEIGHT: u16 = 10;
+
+/// A focusable dropdown showing the currently selected option.
+///
+/// When activated with Enter, Space, Down or a click, a list of options
+/// is opened on the [`Overlays`] layer below the select, or above it if
+/// there's no room. While open, the arrow keys move through the options,
+/// typing filters them, Enter confirms the highlighted option, and Esc
+/// dismisses the list.
+#[derive(Debug)]
+pub struct Select {
+  state: Arc<Mutex<SelectState>>,
+  selected: RwSignal<Option<usize>>,
+  overlays: Option<Overlays>,
+  anchor: Arc<Mutex<Rect>>,
+  formatted_text: String,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+/// State shared between the [`Select`] and its popup list.
+#[derive(Debug)]
+struct SelectState {
+  options: Vec<Cow<'static, str>>,
+  filter: String,
+  /// Indices of the options matching `filter`.
+  matches: Vec<usize>,
+  /// Index into `matches`.
+  highlighted: usize,
+  popup: Option<OverlayId>,
+}
+
+impl SelectState {
+  fn apply_filter(&mut self) {
+    let filter = self.filter.to_lowercase();
+
+    self.matches = self
+      .options
+      .iter()
+      .enumerate()
+      .filter(|(_, option)| option.to_lowercase().contains(&filter))
+      .map(|(i, _)| i)
+      .collect();
+
+    self.highlighted = 0;
+  }
+
+  fn close(&mut self, overlays: &Overlays) {
+    if let Some(id) = self.popup.take() {
+      overlays.remove(id);
+    }
+  }
+
+  /// Selects the highlighted option, if any, and closes the popup.
+  fn confirm(
+    &mut self,
+    selected: RwSignal<Option<usize>>,
+    overlays: &Overlays,
+  ) {
+    if let Some(&option) = self.matches.get(self.highlighted) {
+      selected.set(Some(option));
+    }
+
+    self.close(overlays);
+  }
+}
+
+impl Widget for Select {
+  fn name(&self) -> Cow<'static, str> {
+    "Select".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let state = self.state.lock().unwrap();
+
+    let label = if state.popup.is_some() && !state.filter.is_empty() {
+      format!("/{}", state.filter)
+    } else {
+      self
+        .selected
+        .get_untracked()
+        .and_then(|i| state.options.get(i))
+        .map(ToString::to_string)
+        .unwrap_or_default()
+    };
+
+    let label_width = state
+      .options
+      .iter()
+      .map(|option| option.chars().count())
+      .chain([label.chars().count()])
+      .max()
+      .unwrap_or_default();
+
+    self.formatted_text =
+      clip_to_width(&format!("{label:label_width$} ▾"), limits.max_width);
+
+    let width =
+      (self.formatted_text.chars().count() as u16).max(limits.min_width);
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    *self.anchor.lock().unwrap() = Rect {
+      top_left: surface.top_left(),
+      size: self.size,
+    };
+
+    let theme = self.theme.get(&self.name());
+
+    surface.write_with_style(
+      XY::default(),
+      &self.formatted_text,
+      if self.focused {
+        theme.focus
+      } else {
+        theme.text
+      },
+    );
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => {
+        let overlays = self.overlays();
+        let mut state = self.state.lock().unwrap();
+
+        if state.popup.is_none() {
+          return match code {
+            KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => {
+              drop(state);
+
+              self.open();
+
+              EventHandlerResult::Captured
+            }
+            _ => EventHandlerResult::Bubble,
+          };
+        }
+
+        match code {
+          KeyCode::Up => {
+            state.highlighted = state.highlighted.saturating_sub(1);
+          }
+          KeyCode::Down => {
+            state.highlighted = (state.highlighted + 1)
+              .min(state.matches.len().saturating_sub(1));
+          }
+          KeyCode::Home => state.highlighted = 0,
+          KeyCode::End => {
+            state.highlighted = state.matches.len().saturating_sub(1);
+          }
+          KeyCode::Enter => state.confirm(self.selected, &overlays),
+          KeyCode::Esc => state.close(&overlays),
+          KeyCode::Backspace => {
+            state.filter.pop();
+            state.apply_filter();
+          }
+          KeyCode::Char(c) => {
+            state.filter.push(c);
+            state.apply_filter();
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        ..
+      }) if column < self.size.width && row < self.size.height => {
+        self.focus();
+
+        let is_open = self.state.lock().unwrap().popup.is_some();
+
+        if is_open {
+          self.state.lock().unwrap().close(&self.overlays());
+        } else {
+          self.open();
+        }
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+
+    if let Some(overlays) = &self.overlays {
+      self.state.lock().unwrap().close(overlays);
+    }
+  }
+}
+
+impl IntoView for Select {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.overlays = Some(use_overlays(cx));
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Select {
+  pub fn new<O>(
+    options: impl IntoIterator<Item = O>,
+    selected: RwSignal<Option<usize>>,
+  ) -> Self
+  where
+    O: Into<Cow<'static, str>>,
+  {
+    Self {
+      state: Arc::new(Mutex::new(SelectState {
+        options: options.into_iter().map(Into::into).collect(),
+        filter: String::new(),
+        matches: vec![],
+        highlighted: 0,
+        popup: None,
+      })),
+      selected,
+      overlays: None,
+      anchor: Default::default(),
+      formatted_text: String::new(),
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  #[track_caller]
+  fn overlays(&self) -> Overlays {
+    self
+      .overlays
+      .clone()
+      .expect("`Select` to have been turned into a view")
+  }
+
+  fn open(&mut self) {
+    let overlays = self.overlays();
+    let mut state = self.state.lock().unwrap();
+
+    if state.popup.is_some() {
+      return;
+    }
+
+    state.filter.clear();
+    state.apply_filter();
+
+    let selected = self.selected.get_untracked();
+
+    state.highlighted = state
+      .matches
+      .iter()
+      .position(|&i| Some(i) == selected)
+      .unwrap_or_default();
+
+    let popup = SelectPopup {
+      state: self.state.clone(),
+      selected: self.selected,
+      overlays: overlays.clone(),
+      rows: vec![],
+      scroll: 0,
+      size: Size::default(),
+      theme: self.theme,
+    };
+
+    state.popup = Some(overlays.push(
+      Arc::new(Mutex::new(popup)),
+      Placement::Below(self.anchor.clone()),
+    ));
+  }
+}
+
+/// The list of options opened by a [`Select`].
+#[derive(Debug)]
+struct SelectPopup {
+  state: Arc<Mutex<SelectState>>,
+  selected: RwSignal<Option<usize>>,
+  overlays: Overlays,
+  rows: Vec<String>,
+  /// Index into the matching options of the first visible row.
+  scroll: usize,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl Widget for SelectPopup {
+  fn name(&self) -> Cow<'static, str> {
+    "SelectPopup".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let state = self.state.lock().unwrap();
+
+    let height = (state.matches.len().max(1) as u16)
+      .min(MAX_POPUP_HEIGHT)
+      .min(limits.max_height)
+      .max(limits.min_height);
+
+    // Keep the highlighted option visible
+    if state.highlighted < self.scroll {
+      self.scroll = state.highlighted;
+    } else if height > 0 && state.highlighted >= self.scroll + height as usize {
+      self.scroll = state.highlighted + 1 - height as usize;
+    }
+
+    let width = state
+      .matches
+      .iter()
+      .map(|&i| state.options[i].chars().count() as u16)
+      .max()
+      .unwrap_or(10)
+      .max(limits.min_width)
+      .min(limits.max_width);
+
+    self.rows = state
+      .matches
+      .iter()
+      .skip(self.scroll)
+      .take(height as usize)
+      .map(|&i| {
+        clip_to_width(
+          &format!("{:width$}", state.options[i], width = width as usize),
+          width,
+        )
+      })
+      .collect();
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let highlighted = self.state.lock().unwrap().highlighted;
+    let theme = self.theme.get(&self.name());
+
+    if self.rows.is_empty() {
+      surface.write_with_style(
+        XY::default(),
+        "no matches",
+        theme.surface.patch(theme.muted),
+      );
+    }
+
+    for (i, row) in self.rows.iter().enumerate() {
+      let is_highlighted = self.scroll + i == highlighted;
+
+      surface.write_with_style(
+        XY { x: 0, y: i as u16 },
+        row,
+        if is_highlighted {
+          theme.surface.patch(theme.focus).patch(theme.selected)
+        } else {
+          theme.surface.patch(theme.text)
+        },
+      );
+    }
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let Event::Mouse(MouseEvent { kind, row, .. }) = event else {
+      return EventHandlerResult::Bubble;
+    };
+
+    let mut state = self.state.lock().unwrap();
+
+    match kind {
+      MouseEventKind::Down(MouseButton::Left)
+        if (row as usize) < self.rows.len() =>
+      {
+        state.highlighted = self.scroll + row as usize;
+        state.confirm(self.selected, &self.overlays);
+      }
+      MouseEventKind::ScrollUp => {
+        state.highlighted = state.highlighted.saturating_sub(1);
+      }
+      MouseEventKind::ScrollDown => {
+        state.highlighted =
+          (state.highlighted + 1).min(state.matches.len().saturating_sub(1));
+      }
+      _ => {}
+    }
+
+    EventHandlerResult::Captured
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::Print,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn select(selected: RwSignal<Option<usize>>) -> Select {
+    let mut select = Select::new(["apple", "banana", "cherry"], selected);
+
+    select.overlays = Some(Overlays::default());
+    select.focus();
+
+    select
+  }
+
+  #[test]
+  fn renders_selected_option() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, Some(1));
+
+      let (size, output) = get_widget_output(
+        Select::new(["apple", "banana"], selected),
+        (20, 1),
+        (0, 0),
+      );
+
+      assert_eq!(size, (8, 1).into());
+
+      assert_eq!(output, commands![MoveTo(0, 0), Print("banana ▾")]);
+    });
+  }
+
+  #[test]
+  fn opens_popup_on_enter_and_closes_on_esc() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, None);
+      let mut select = select(selected);
+      let overlays = select.overlays();
+
+      select.on(key(KeyCode::Enter));
+
+      assert!(!overlays.is_empty());
+
+      select.on(key(KeyCode::Esc));
+
+      assert!(overlays.is_empty());
+      assert_eq!(selected.get_untracked(), None);
+    });
+  }
+
+  #[test]
+  fn typing_filters_options() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, None);
+      let mut select = select(selected);
+
+      select.on(key(KeyCode::Enter));
+      select.on(key(KeyCode::Char('e')));
+      select.on(key(KeyCode::Char('r')));
+      select.on(key(KeyCode::Enter));
+
+      assert_eq!(selected.get_untracked(), Some(2));
+      assert!(select.overlays().is_empty());
+    });
+  }
+
+  #[test]
+  fn arrows_move_through_options() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, Some(0));
+      let mut select = select(selected);
+
+      select.on(key(KeyCode::Down));
+      select.on(key(KeyCode::Down));
+      select.on(key(KeyCode::Down));
+      select.on(key(KeyCode::Up));
+      select.on(key(KeyCode::Enter));
+
+      assert_eq!(selected.get_untracked(), Some(1));
+    });
+  }
+
+  #[test]
+  fn blurring_closes_popup() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, None);
+      let mut select = select(selected);
+
+      select.on(key(KeyCode::Enter));
+      select.on(Event::NextFocus);
+
+      assert!(select.overlays().is_empty());
+      assert!(!select.has_focus());
+    });
+  }
+}
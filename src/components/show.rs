@@ -0,0 +1,403 @@
+//! Components showing views depending on conditions.
+//!
+//! [`Show`] and [`Match`] are also exported from the crate root, while
+//! this module's [`Switch`] is only reachable as `show::Switch`, since the
+//! crate root's [`Switch`](crate::Switch) is the on/off toggle.
+//!
+//! ```
+//! # use leptos_reactive::{
+//! #   create_runtime,
+//! #   create_rw_signal,
+//! #   run_scope,
+//! #   SignalGet,
+//! # };
+//! use leptos_tui::{
+//!   show::Switch,
+//!   Match,
+//! };
+//!
+//! # run_scope(create_runtime(), |cx| {
+//! let count = create_rw_signal(cx, 0);
+//!
+//! let view = Switch::new([
+//!   Match::new(move || count.get() == 0, || "none"),
+//!   Match::new(move || count.get() == 1, || "one"),
+//! ])
+//! .fallback(|| "many");
+//! # });
+//! ```
+
+use crate::{
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  View,
+  Widget,
+};
+use leptos_reactive::{
+  create_effect,
+  Scope,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+type ViewFn = Arc<dyn Fn(Scope) -> View + Send + Sync>;
+
+/// Shows its child while `when` returns `true`, and its fallback, if any,
+/// otherwise.
+///
+/// Unlike a closure returning an [`Option`], the child is only created
+/// again when `when` goes from `false` to `true`, so it keeps its state
+/// while it stays shown, even if something else read by `when` changes.
+///
+/// ```
+/// # use leptos_reactive::{
+/// #   create_runtime,
+/// #   create_rw_signal,
+/// #   run_scope,
+/// #   SignalGet,
+/// # };
+/// use leptos_tui::Show;
+///
+/// # run_scope(create_runtime(), |cx| {
+/// let logged_in = create_rw_signal(cx, false);
+///
+/// let view = Show::new(move || logged_in.get(), || "Welcome back")
+///   .fallback(|| "Please log in");
+/// # });
+/// ```
+pub struct Show {
+  switch: Switch,
+}
+
+impl fmt::Debug for Show {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Show")
+      .field("switch", &self.switch)
+      .finish()
+  }
+}
+
+impl IntoView for Show {
+  fn into_view(self, cx: Scope) -> View {
+    self.switch.into_view(cx)
+  }
+}
+
+impl Show {
+  pub fn new<V: IntoView>(
+    when: impl Fn() -> bool + Send + Sync + 'static,
+    view: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      switch: Switch::new([Match::new(when, view)]),
+    }
+  }
+
+  /// Sets what to show while `when` returns `false`. Nothing is shown by
+  /// default.
+  pub fn fallback<V: IntoView>(
+    mut self,
+    fallback: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    self.switch = self.switch.fallback(fallback);
+
+    self
+  }
+}
+
+/// A branch of a [`Switch`], shown while its condition is the first one
+/// to hold.
+pub struct Match {
+  when: Arc<dyn Fn() -> bool + Send + Sync>,
+  view: ViewFn,
+}
+
+impl fmt::Debug for Match {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Match")
+      .field("when", &"Fn() -> bool")
+      .field("view", &"Fn() -> View")
+      .finish()
+  }
+}
+
+impl Match {
+  pub fn new<V: IntoView>(
+    when: impl Fn() -> bool + Send + Sync + 'static,
+    view: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      when: Arc::new(when),
+      view: Arc::new(move |cx| view().into_view(cx)),
+    }
+  }
+}
+
+/// Shows the first [`Match`] whose condition holds, or the fallback if
+/// none does.
+///
+/// Conditions are checked in order whenever something they read changes,
+/// but the shown view is only created again when a different branch is
+/// selected. Each branch's view is created in its own reactive scope,
+/// which is disposed of when another branch replaces it.
+///
+/// If the shown view had focus when the branch changed, the first
+/// focusable widget of the new one is focused.
+pub struct Switch {
+  matches: Arc<Vec<Match>>,
+  fallback: Option<ViewFn>,
+  /// The branch to show next, set as soon as the selected branch changes
+  /// and swapped in when this is next laid out or gets an event.
+  /// This way, a branch can change the condition from its own event
+  /// handlers without being replaced in the middle of handling the event.
+  pending: Arc<Mutex<Option<Branch>>>,
+  current: Option<Branch>,
+}
+
+struct Branch {
+  view: View,
+  cx: Scope,
+}
+
+impl fmt::Debug for Switch {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Switch")
+      .field("matches", &self.matches)
+      .field("current", &self.current.as_ref().map(|branch| &branch.view))
+      .finish()
+  }
+}
+
+impl Widget for Switch {
+  fn name(&self) -> Cow<'static, str> {
+    "Switch".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.swap_branches();
+
+    match &mut self.current {
+      Some(branch) => branch.view.layout(limits),
+      None => limits.min_size(),
+    }
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if let Some(branch) = &self.current {
+      branch.view.draw(surface);
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self
+      .current
+      .as_ref()
+      .and_then(|branch| branch.view.needs_focus())
+  }
+
+  fn has_focus(&self) -> bool {
+    self
+      .current
+      .as_ref()
+      .is_some_and(|branch| branch.view.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    self.swap_branches();
+
+    match &mut self.current {
+      Some(branch) => branch.view.on(event),
+      None => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(branch) = &mut self.current {
+      branch.view.focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    if let Some(branch) = &mut self.current {
+      branch.view.blur();
+    }
+  }
+}
+
+impl IntoView for Switch {
+  fn into_view(self, cx: Scope) -> View {
+    let matches = self.matches.clone();
+    let fallback = self.fallback.clone();
+    let pending = self.pending.clone();
+
+    // `None` is the fallback
+    create_effect(cx, move |shown: Option<Option<usize>>| {
+      let selected = matches.iter().position(|branch| (branch.when)());
+
+      if shown == Some(selected) {
+        return selected;
+      }
+
+      let view_fn = match selected {
+        Some(i) => Some(&matches[i].view),
+        None => fallback.as_ref(),
+      };
+
+      let ((view, cx), _) = cx.run_child_scope(|cx| {
+        let view = cx.untrack(|| match view_fn {
+          Some(view_fn) => view_fn(cx),
+          None => ().into_view(cx),
+        });
+
+        (view, cx)
+      });
+
+      // A branch which was replaced before it was ever shown
+      if let Some(skipped) =
+        pending.lock().unwrap().replace(Branch { view, cx })
+      {
+        skipped.cx.dispose();
+      }
+
+      selected
+    });
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Switch {
+  pub fn new(matches: impl IntoIterator<Item = Match>) -> Self {
+    Self {
+      matches: Arc::new(matches.into_iter().collect()),
+      fallback: None,
+      pending: Default::default(),
+      current: None,
+    }
+  }
+
+  /// Sets what to show when no branch matches. Nothing is shown by
+  /// default.
+  pub fn fallback<V: IntoView>(
+    mut self,
+    fallback: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    self.fallback = Some(Arc::new(move |cx| fallback().into_view(cx)));
+
+    self
+  }
+
+  /// Replaces the current branch with the pending one, if any.
+  fn swap_branches(&mut self) {
+    let Some(branch) = self.pending.lock().unwrap().take() else {
+      return;
+    };
+
+    let had_focus = self.has_focus();
+
+    if let Some(old) = self.current.replace(branch) {
+      old.cx.dispose();
+    }
+
+    if let (true, Some(branch)) = (had_focus, &mut self.current) {
+      branch.view.on(Event::NextFocus);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  // Through the crate root, where `Show` must not be shadowed
+  use crate::{
+    components::Checkbox,
+    show::Switch,
+    Match,
+    Show,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalGet,
+    SignalSet,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  #[test]
+  fn child_is_only_created_when_the_branch_changes() {
+    run_scope(create_runtime(), |cx| {
+      let count = create_rw_signal(cx, 6);
+      let created = Arc::new(AtomicUsize::new(0));
+
+      let mut view = Show::new(move || count.get() > 5, {
+        let created = created.clone();
+
+        move || {
+          created.fetch_add(1, Ordering::SeqCst);
+
+          "big"
+        }
+      })
+      .fallback(|| "small")
+      .into_view(cx);
+
+      view.layout(Limits::strict(5, 1));
+
+      count.set(7);
+      view.layout(Limits::strict(5, 1));
+
+      assert_eq!(created.load(Ordering::SeqCst), 1);
+
+      count.set(1);
+      count.set(8);
+      view.layout(Limits::strict(5, 1));
+
+      assert_eq!(created.load(Ordering::SeqCst), 2);
+    });
+  }
+
+  #[test]
+  fn focus_moves_to_the_new_branch() {
+    run_scope(create_runtime(), |cx| {
+      let tab = create_rw_signal(cx, 0);
+
+      let branch = |i: usize| {
+        Match::new(
+          move || tab.get() == i,
+          move || Checkbox::new(format!("{i}"), create_rw_signal(cx, false)),
+        )
+      };
+
+      let mut view = Switch::new([branch(0), branch(1)]).into_view(cx);
+
+      view.layout(Limits::strict(5, 1));
+      view.on(Event::NextFocus);
+
+      tab.set(1);
+      view.layout(Limits::strict(5, 1));
+
+      assert!(view.has_focus());
+
+      // No branch matches, and there's no fallback
+      tab.set(2);
+      view.layout(Limits::strict(5, 1));
+
+      assert!(!view.has_focus());
+      assert_eq!(view.needs_focus(), None);
+    });
+  }
+}
@@ -0,0 +1,216 @@
+use crate::{
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::style::Color;
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Blocks filling zero to eight eighths of a cell, from the bottom.
+const VERTICAL_EIGHTHS: [char; 9] =
+  [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A compact chart of the most recent values of a series, one column per
+/// value, with columns rising from the bottom in eighths of a cell.
+///
+/// The sparkline takes up all of the available width, showing as many of
+/// the last values as fit, and is one line tall unless it is given a
+/// larger minimum height.
+pub struct Sparkline {
+  data: Box<dyn Fn() -> Vec<f64> + Send + Sync>,
+  max: Option<f64>,
+  color: Option<Color>,
+  values: Vec<f64>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for Sparkline {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Sparkline")
+      .field("data", &"Fn() -> Vec<f64>")
+      .field("max", &self.max)
+      .field("color", &self.color)
+      .field("values", &self.values)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for Sparkline {
+  fn name(&self) -> Cow<'static, str> {
+    "Sparkline".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let mut values = (self.data)();
+
+    values.drain(..values.len().saturating_sub(limits.max_width as usize));
+
+    self.values = values;
+
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size {
+      width: limits.max_width,
+      height,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let max = self
+      .max
+      .unwrap_or_else(|| self.values.iter().copied().fold(0.0, f64::max));
+
+    let height = self.size.height;
+
+    let columns = self
+      .values
+      .iter()
+      .map(|value| scale_to_eighths(*value, max, height))
+      .collect::<Vec<_>>();
+
+    let theme = self.theme.get(&self.name());
+    let style = self
+      .color
+      .map_or(theme.primary, |color| theme.primary.foreground(color));
+
+    for y in 0..height {
+      let row = height - 1 - y;
+
+      let line = columns
+        .iter()
+        .map(|eighths| column_cell(*eighths, row))
+        .collect::<String>();
+
+      surface.write_with_style(XY { x: 0, y }, &line, style);
+    }
+  }
+}
+
+impl IntoView for Sparkline {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Sparkline {
+  /// Creates a sparkline of the values returned by `data`, oldest first.
+  pub fn new(data: impl Fn() -> Vec<f64> + Send + Sync + 'static) -> Self {
+    Self {
+      data: Box::new(data),
+      max: None,
+      color: None,
+      values: vec![],
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Sets the value which fills a column, instead of the largest visible
+  /// value.
+  pub fn max(mut self, max: f64) -> Self {
+    self.max = Some(max);
+
+    self
+  }
+
+  /// Sets the color of the columns, instead of the theme's primary color.
+  pub fn color(mut self, color: Color) -> Self {
+    self.color = Some(color);
+
+    self
+  }
+}
+
+/// Scales `value` to the number of eighths of a cell it fills in a column
+/// `height` cells tall, in which `max` fills every cell.
+pub(crate) fn scale_to_eighths(value: f64, max: f64, height: u16) -> u32 {
+  if max.is_nan() || value.is_nan() || max <= 0.0 || value <= 0.0 {
+    return 0;
+  }
+
+  ((value.min(max) / max) * height as f64 * 8.0).round() as u32
+}
+
+/// Returns the block for the `row`th cell from the bottom of a column
+/// filled up to `eighths`.
+pub(crate) fn column_cell(eighths: u32, row: u16) -> char {
+  let filled = eighths.saturating_sub(row as u32 * 8).min(8);
+
+  VERTICAL_EIGHTHS[filled as usize]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    style::{
+      Print,
+      ResetColor,
+      SetForegroundColor,
+    },
+  };
+
+  #[test]
+  fn shows_the_latest_values_that_fit() {
+    let sparkline = Sparkline::new(|| vec![8.0, 0.0, 1.0, 4.0, 8.0]).max(8.0);
+
+    let (size, output) = get_widget_output(sparkline, (4, 1), (0, 0));
+
+    assert_eq!(size, (4, 1).into());
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        SetForegroundColor(Color::Blue),
+        Print(" ▁▄█"),
+        ResetColor
+      ]
+    );
+  }
+
+  #[test]
+  fn spreads_columns_over_rows() {
+    let sparkline = Sparkline::new(|| vec![1.0, 3.0, 4.0]).color(Color::Green);
+
+    let (_, output) = get_widget_output(sparkline, (3, 2), (0, 2));
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        SetForegroundColor(Color::Green),
+        Print(" ▄█"),
+        ResetColor,
+        MoveTo(0, 1),
+        SetForegroundColor(Color::Green),
+        Print("▄██"),
+        ResetColor
+      ]
+    );
+  }
+}
@@ -0,0 +1,197 @@
+use crate::{
+  AnimationGuard,
+  DrawSurface,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  sync::{
+    Arc,
+    Mutex,
+  },
+  time::{
+    Duration,
+    Instant,
+  },
+};
+
+/// The frames a [`Spinner`] cycles through, and how long each is shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpinnerFrames {
+  pub frames: &'static [&'static str],
+  pub interval: Duration,
+}
+
+impl SpinnerFrames {
+  pub const DOTS: Self = Self {
+    frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    interval: Duration::from_millis(80),
+  };
+
+  pub const LINE: Self = Self {
+    frames: &["-", "\\", "|", "/"],
+    interval: Duration::from_millis(130),
+  };
+
+  pub const ARC: Self = Self {
+    frames: &["◜", "◠", "◝", "◞", "◡", "◟"],
+    interval: Duration::from_millis(100),
+  };
+
+  pub const BOUNCING_BAR: Self = Self {
+    frames: &["[=   ]", "[ =  ]", "[  = ]", "[   =]", "[  = ]", "[ =  ]"],
+    interval: Duration::from_millis(120),
+  };
+}
+
+impl Default for SpinnerFrames {
+  fn default() -> Self {
+    Self::DOTS
+  }
+}
+
+/// An animated indicator for work of unknown length, optionally followed
+/// by a label.
+///
+/// The screen keeps being redrawn while a spinner is mounted.
+#[derive(Debug)]
+pub struct Spinner {
+  frames: SpinnerFrames,
+  label: Option<Cow<'static, str>>,
+  started_at: Instant,
+  animation: Option<AnimationGuard>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl Widget for Spinner {
+  fn name(&self) -> Cow<'static, str> {
+    "Spinner".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let frame_width = self
+      .frames
+      .frames
+      .iter()
+      .map(|frame| frame.chars().count())
+      .max()
+      .unwrap_or_default();
+
+    let label_width = self
+      .label
+      .as_ref()
+      .map_or(0, |label| label.chars().count() + 1);
+
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      limits.min_height.max(1)
+    };
+
+    self.size = Size {
+      width: ((frame_width + label_width) as u16)
+        .clamp(limits.min_width, limits.max_width),
+      height,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let frame = self.frame_at(self.started_at.elapsed());
+    let theme = self.theme.get(&self.name());
+
+    surface.write_with_style(XY::default(), frame, theme.primary);
+
+    if let Some(label) = &self.label {
+      surface.write_with_style(
+        XY {
+          x: frame.chars().count() as u16 + 1,
+          y: 0,
+        },
+        label,
+        theme.text,
+      );
+    }
+  }
+}
+
+impl IntoView for Spinner {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.animation = Some(AnimationGuard::new());
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Spinner {
+  pub fn new() -> Self {
+    Self {
+      frames: SpinnerFrames::default(),
+      label: None,
+      started_at: Instant::now(),
+      animation: None,
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Sets the frames to cycle through, which are [`SpinnerFrames::DOTS`]
+  /// by default.
+  pub fn frames(mut self, frames: SpinnerFrames) -> Self {
+    self.frames = frames;
+
+    self
+  }
+
+  /// Shows `label` after the spinner.
+  pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+    self.label = Some(label.into());
+
+    self
+  }
+
+  /// Returns the frame to show once `elapsed` has passed since the spinner
+  /// was created.
+  fn frame_at(&self, elapsed: Duration) -> &'static str {
+    let SpinnerFrames { frames, interval } = self.frames;
+
+    if frames.is_empty() {
+      return "";
+    }
+
+    let index = elapsed.as_millis() / interval.as_millis().max(1);
+
+    frames[index as usize % frames.len()]
+  }
+}
+
+impl Default for Spinner {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cycles_through_frames() {
+    let spinner = Spinner::new().frames(SpinnerFrames::LINE);
+
+    let frames = [0, 129, 130, 400, 520]
+      .map(|ms| spinner.frame_at(Duration::from_millis(ms)));
+
+    assert_eq!(frames, ["-", "-", "\\", "/", "-"]);
+  }
+}
@@ -0,0 +1,715 @@
+use crate::{
+  dispatch_to_children,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Rect,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  KeyModifiers,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  marker::PhantomData,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// How many cells Shift+arrows move the divider of a [`Split`] by.
+const LARGE_STEP: u16 = 5;
+
+/// Two panes side by side, left and right, with a vertical divider
+/// between them.
+pub type HSplit = Split<Horizontal>;
+
+/// Two panes stacked on top of each other, with a horizontal divider
+/// between them.
+pub type VSplit = Split<Vertical>;
+
+/// Arranges the panes of an [`HSplit`] side by side.
+#[derive(Debug)]
+pub enum Horizontal {}
+
+/// Stacks the panes of a [`VSplit`] on top of each other.
+#[derive(Debug)]
+pub enum Vertical {}
+
+/// The axis along which the panes of a [`Split`] are arranged.
+pub trait SplitDirection: Send + Sync + 'static {
+  const HORIZONTAL: bool;
+}
+
+impl SplitDirection for Horizontal {
+  const HORIZONTAL: bool = true;
+}
+
+impl SplitDirection for Vertical {
+  const HORIZONTAL: bool = false;
+}
+
+/// One of the two panes of a [`Split`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pane {
+  First,
+  Second,
+}
+
+/// Two panes separated by a divider which can be dragged with the mouse to
+/// resize them, see [`HSplit`] and [`VSplit`].
+///
+/// The split takes up all of the space it is given. The first pane gets
+/// `ratio` of the space left by the divider, within its size bounds, and
+/// the second pane gets the rest. The ratio is `0.5` unless it is set with
+/// [`Split::ratio`], whose signal is updated as the divider moves, so that
+/// it can be persisted.
+///
+/// The divider can be focused, between the widgets of the first pane and
+/// those of the second one. While it is focused, the arrow keys along the
+/// split's axis move it by a cell, or by 5 cells with Shift held, and
+/// Enter collapses or restores a collapsible pane.
+///
+/// A collapsible pane is hidden when the ratio is `0.0` for the first pane
+/// or `1.0` for the second, which also happens when the divider is dragged
+/// past half of its minimum size.
+pub struct Split<D> {
+  first_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  second_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  first: Option<View>,
+  second: Option<View>,
+  ratio: f64,
+  ratio_signal: Option<RwSignal<f64>>,
+  /// Minimum and maximum sizes of each pane along the split's axis.
+  bounds: [(u16, u16); 2],
+  collapsible: [bool; 2],
+  /// The ratio to go back to when a collapsed pane is restored.
+  restore_ratio: f64,
+  /// Size of the first pane along the split's axis, which is where the
+  /// divider is.
+  first_len: u16,
+  size: Size,
+  divider_focused: bool,
+  dragging: bool,
+  theme: ThemeHandle,
+  _direction: PhantomData<D>,
+}
+
+impl<D: SplitDirection> fmt::Debug for Split<D> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct(if D::HORIZONTAL { "HSplit" } else { "VSplit" })
+      .field("first", &self.first)
+      .field("second", &self.second)
+      .field("ratio", &self.ratio)
+      .field("bounds", &self.bounds)
+      .field("collapsible", &self.collapsible)
+      .field("first_len", &self.first_len)
+      .field("size", &self.size)
+      .field("divider_focused", &self.divider_focused)
+      .field("dragging", &self.dragging)
+      .finish()
+  }
+}
+
+impl<D: SplitDirection> Widget for Split<D> {
+  fn name(&self) -> Cow<'static, str> {
+    if D::HORIZONTAL { "HSplit" } else { "VSplit" }.into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    if let Some(ratio) = self.ratio_signal.map(|ratio| ratio.get_untracked()) {
+      self.ratio = ratio.clamp(0.0, 1.0);
+    }
+
+    self.size = limits.max_size();
+    self.first_len = self.first_len_for(self.ratio);
+
+    let [first_rect, second_rect] = self.pane_rects();
+
+    if let Some(first) = &mut self.first {
+      first.layout(first_rect.size.into_strict_limits());
+    }
+
+    if let Some(second) = &mut self.second {
+      second.layout(second_rect.size.into_strict_limits());
+    }
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let [first_rect, second_rect] = self.pane_rects();
+
+    for (pane, rect) in [(&self.first, first_rect), (&self.second, second_rect)]
+    {
+      if let (Some(pane), true) =
+        (pane, rect.size.width > 0 && rect.size.height > 0)
+      {
+        surface.shrink(
+          rect.top_left,
+          rect.size,
+          Box::new(|surface| pane.draw(surface)),
+        );
+      }
+    }
+
+    if self.main(self.size) == 0 {
+      return;
+    }
+
+    let is_active = self.divider_focused || self.dragging;
+
+    let theme = self.theme.get(&self.name());
+    let style = if is_active { theme.focus } else { theme.border };
+
+    if D::HORIZONTAL {
+      for y in 0..self.size.height {
+        surface.write_with_style(
+          XY {
+            x: self.first_len,
+            y,
+          },
+          "│",
+          style,
+        );
+      }
+    } else if self.size.height > 0 {
+      surface.write_with_style(
+        XY {
+          x: 0,
+          y: self.first_len,
+        },
+        &"─".repeat(self.size.width as usize),
+        style,
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.divider_focused || self.pane_has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus => self.cycle_focus(true),
+      Event::PrevFocus => self.cycle_focus(false),
+      Event::Key(key) if self.divider_focused => self.on_divider_key(key),
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) => {
+        let at = if D::HORIZONTAL { column } else { row };
+
+        match kind {
+          MouseEventKind::Down(MouseButton::Left) if at == self.first_len => {
+            self.blur_panes();
+
+            self.divider_focused = true;
+            self.dragging = true;
+
+            EventHandlerResult::Captured
+          }
+          MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+            self.drag_to(at);
+
+            EventHandlerResult::Captured
+          }
+          MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+            self.dragging = false;
+
+            EventHandlerResult::Captured
+          }
+          _ => {
+            let result = self.dispatch_to_panes(event);
+
+            if self.pane_has_focus() {
+              self.divider_focused = false;
+            }
+
+            result
+          }
+        }
+      }
+      event => self.dispatch_to_panes(event),
+    }
+  }
+
+  fn focus(&mut self) {
+    self.blur_panes();
+
+    self.divider_focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.blur_panes();
+
+    self.divider_focused = false;
+    self.dragging = false;
+  }
+}
+
+impl<D: SplitDirection> IntoView for Split<D> {
+  fn into_view(mut self, cx: Scope) -> View {
+    if let Some(first_fn) = self.first_fn.take() {
+      self.first = Some(first_fn(cx));
+    }
+
+    if let Some(second_fn) = self.second_fn.take() {
+      self.second = Some(second_fn(cx));
+    }
+
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl<D: SplitDirection> Split<D> {
+  /// Creates a split with `first` on the left or top, and `second` on the
+  /// right or bottom.
+  pub fn new(
+    first: impl IntoView + Send + Sync + 'static,
+    second: impl IntoView + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      first_fn: Some(Box::new(|cx| first.into_view(cx))),
+      second_fn: Some(Box::new(|cx| second.into_view(cx))),
+      first: None,
+      second: None,
+      ratio: 0.5,
+      ratio_signal: None,
+      bounds: [(0, u16::MAX); 2],
+      collapsible: [false; 2],
+      restore_ratio: 0.5,
+      first_len: 0,
+      size: Size::default(),
+      divider_focused: false,
+      dragging: false,
+      theme: ThemeHandle::default(),
+      _direction: PhantomData,
+    }
+  }
+
+  /// Reads the share of the space given to the first pane from `ratio`,
+  /// between `0.0` and `1.0`, and writes it back when the divider moves.
+  pub fn ratio(mut self, ratio: RwSignal<f64>) -> Self {
+    self.ratio_signal = Some(ratio);
+
+    self
+  }
+
+  /// Sets the minimum and maximum sizes of a pane along the split's axis.
+  pub fn pane_size(mut self, pane: Pane, min: u16, max: u16) -> Self {
+    self.bounds[pane as usize] = (min, max.max(min));
+
+    self
+  }
+
+  /// Allows a pane to be collapsed, hiding it entirely.
+  pub fn collapsible(mut self, pane: Pane) -> Self {
+    self.collapsible[pane as usize] = true;
+
+    self
+  }
+
+  fn main(&self, size: Size) -> u16 {
+    if D::HORIZONTAL {
+      size.width
+    } else {
+      size.height
+    }
+  }
+
+  /// Returns the space along the split's axis left by the divider.
+  fn available(&self) -> u16 {
+    self.main(self.size).saturating_sub(1)
+  }
+
+  /// Returns the size of the first pane for a ratio, taking collapsed panes
+  /// and pane bounds into account.
+  fn first_len_for(&self, ratio: f64) -> u16 {
+    let available = self.available();
+
+    if self.collapsible[0] && ratio <= 0.0 {
+      return 0;
+    }
+
+    if self.collapsible[1] && ratio >= 1.0 {
+      return available;
+    }
+
+    let [(first_min, first_max), (second_min, second_max)] = self.bounds;
+
+    let low = first_min.max(available.saturating_sub(second_max));
+    let high = first_max.min(available.saturating_sub(second_min));
+
+    let len = (ratio * available as f64).round() as u16;
+
+    // The first pane's minimum wins if both can't be satisfied
+    len.min(high).max(low).min(available)
+  }
+
+  fn pane_rects(&self) -> [Rect; 2] {
+    let second_start = (self.first_len + 1).min(self.main(self.size));
+    let second_len = self.main(self.size) - second_start;
+
+    if D::HORIZONTAL {
+      [
+        Rect {
+          top_left: XY::default(),
+          size: Size {
+            width: self.first_len,
+            height: self.size.height,
+          },
+        },
+        Rect {
+          top_left: XY {
+            x: second_start,
+            y: 0,
+          },
+          size: Size {
+            width: second_len,
+            height: self.size.height,
+          },
+        },
+      ]
+    } else {
+      [
+        Rect {
+          top_left: XY::default(),
+          size: Size {
+            width: self.size.width,
+            height: self.first_len,
+          },
+        },
+        Rect {
+          top_left: XY {
+            x: 0,
+            y: second_start,
+          },
+          size: Size {
+            width: self.size.width,
+            height: second_len,
+          },
+        },
+      ]
+    }
+  }
+
+  fn set_ratio(&mut self, ratio: f64) {
+    self.ratio = ratio.clamp(0.0, 1.0);
+    self.first_len = self.first_len_for(self.ratio);
+
+    if let Some(signal) = self.ratio_signal {
+      signal.set(self.ratio);
+    }
+  }
+
+  /// Moves the divider to `first_len`, collapsing a pane if the divider
+  /// is past half of its minimum size.
+  fn resize_to(&mut self, first_len: u16) {
+    let available = self.available();
+
+    if available == 0 {
+      return;
+    }
+
+    let [(first_min, _), (second_min, _)] = self.bounds;
+    let second_len = available.saturating_sub(first_len);
+
+    let ratio = if self.collapsible[0] && first_len < first_min.max(1) / 2 {
+      0.0
+    } else if self.collapsible[1] && second_len < second_min.max(1) / 2 {
+      1.0
+    } else {
+      let first_len = self.first_len_for(first_len as f64 / available as f64);
+
+      first_len as f64 / available as f64
+    };
+
+    if ratio > 0.0 && ratio < 1.0 {
+      self.restore_ratio = ratio;
+    }
+
+    self.set_ratio(ratio);
+  }
+
+  fn drag_to(&mut self, at: u16) {
+    self.resize_to(at.min(self.available()));
+  }
+
+  fn on_divider_key(&mut self, key: KeyEvent) -> EventHandlerResult {
+    let KeyEvent {
+      code, modifiers, ..
+    } = key;
+
+    let step = if modifiers.contains(KeyModifiers::SHIFT) {
+      LARGE_STEP
+    } else {
+      1
+    };
+
+    let (back, forward) = if D::HORIZONTAL {
+      (KeyCode::Left, KeyCode::Right)
+    } else {
+      (KeyCode::Up, KeyCode::Down)
+    };
+
+    match code {
+      code if code == back => {
+        self.resize_to(self.first_len.saturating_sub(step));
+      }
+      code if code == forward => {
+        self.resize_to(self.first_len.saturating_add(step));
+      }
+      KeyCode::Home => self.resize_to(0),
+      KeyCode::End => self.resize_to(self.available()),
+      KeyCode::Enter => self.toggle_collapsed(),
+      _ => return EventHandlerResult::Bubble,
+    }
+
+    EventHandlerResult::Captured
+  }
+
+  /// Collapses the first collapsible pane, or restores it if a pane is
+  /// collapsed.
+  fn toggle_collapsed(&mut self) {
+    let is_collapsed = (self.collapsible[0] && self.ratio <= 0.0)
+      || (self.collapsible[1] && self.ratio >= 1.0);
+
+    if is_collapsed {
+      self.set_ratio(self.restore_ratio);
+    } else if self.collapsible[0] {
+      self.restore_ratio = self.ratio;
+      self.set_ratio(0.0);
+    } else if self.collapsible[1] {
+      self.restore_ratio = self.ratio;
+      self.set_ratio(1.0);
+    }
+  }
+
+  /// Moves focus from the first pane's widgets to the divider, and from
+  /// the divider to the second pane's widgets, or back.
+  fn cycle_focus(&mut self, forward: bool) -> EventHandlerResult {
+    let event = if forward {
+      Event::NextFocus
+    } else {
+      Event::PrevFocus
+    };
+
+    let [first_rect, second_rect] = self.pane_rects();
+
+    let is_visible = |rect: Rect| rect.size.width > 0 && rect.size.height > 0;
+
+    // Stops: the first pane, the divider, then the second pane, in order
+    let (before, after, before_visible, after_visible) = if forward {
+      (
+        &mut self.first,
+        &mut self.second,
+        is_visible(first_rect),
+        is_visible(second_rect),
+      )
+    } else {
+      (
+        &mut self.second,
+        &mut self.first,
+        is_visible(second_rect),
+        is_visible(first_rect),
+      )
+    };
+
+    let has_focus =
+      |pane: &Option<View>| pane.as_ref().is_some_and(|pane| pane.has_focus());
+
+    if has_focus(after) {
+      return after.as_mut().unwrap().on(event);
+    }
+
+    if self.divider_focused {
+      self.divider_focused = false;
+
+      return match after {
+        Some(after) if after_visible => after.on(event),
+        _ => EventHandlerResult::Bubble,
+      };
+    }
+
+    if let Some(before) = before.as_mut().filter(|_| before_visible) {
+      if let EventHandlerResult::Captured = before.on(event) {
+        return EventHandlerResult::Captured;
+      }
+    }
+
+    self.divider_focused = true;
+
+    EventHandlerResult::Captured
+  }
+
+  fn pane_has_focus(&self) -> bool {
+    self.first.as_ref().is_some_and(|first| first.has_focus())
+      || self
+        .second
+        .as_ref()
+        .is_some_and(|second| second.has_focus())
+  }
+
+  fn blur_panes(&mut self) {
+    for pane in [&mut self.first, &mut self.second].into_iter().flatten() {
+      if pane.has_focus() {
+        pane.blur();
+      }
+    }
+  }
+
+  fn dispatch_to_panes(&mut self, event: Event) -> EventHandlerResult {
+    let [first_rect, second_rect] = self.pane_rects();
+
+    let mut children = [
+      (first_rect, &mut self.first),
+      (second_rect, &mut self.second),
+    ]
+    .into_iter()
+    .filter_map(|(rect, pane)| {
+      pane.as_mut().map(|pane| (rect, pane as &mut dyn Widget))
+    })
+    .collect::<Vec<_>>();
+
+    dispatch_to_children(&mut children, event)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::Checkbox;
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn mouse(kind: MouseEventKind, column: u16) -> Event {
+    Event::Mouse(MouseEvent {
+      kind,
+      column,
+      row: 0,
+      modifiers: KeyModifiers::NONE,
+    })
+  }
+
+  #[test]
+  fn clamps_panes_to_their_bounds() {
+    let mut split = HSplit::new("a", "b").pane_size(Pane::First, 2, 4);
+
+    split.layout(Limits::strict(11, 1));
+
+    assert_eq!(split.first_len, 4);
+
+    split.ratio = 0.0;
+    split.layout(Limits::strict(11, 1));
+
+    assert_eq!(split.first_len, 2);
+  }
+
+  #[test]
+  fn dragging_the_divider_updates_the_ratio() {
+    run_scope(create_runtime(), |cx| {
+      let ratio = create_rw_signal(cx, 0.5);
+      let mut split = HSplit::new("a", "b")
+        .ratio(ratio)
+        .collapsible(Pane::First)
+        .pane_size(Pane::First, 4, 10);
+
+      split.layout(Limits::strict(11, 1));
+
+      assert_eq!(split.first_len, 5);
+
+      split.on(mouse(MouseEventKind::Down(MouseButton::Left), 5));
+      split.on(mouse(MouseEventKind::Drag(MouseButton::Left), 8));
+      split.on(mouse(MouseEventKind::Up(MouseButton::Left), 8));
+
+      assert_eq!(ratio.get_untracked(), 0.8);
+
+      // Past half of the minimum size, the pane collapses
+      split.on(mouse(MouseEventKind::Down(MouseButton::Left), 8));
+      split.on(mouse(MouseEventKind::Drag(MouseButton::Left), 1));
+
+      assert_eq!(ratio.get_untracked(), 0.0);
+      assert_eq!(split.first_len, 0);
+
+      // Enter restores it
+      split.on(key(KeyCode::Enter));
+
+      assert_eq!(ratio.get_untracked(), 0.8);
+
+      split.on(key(KeyCode::Left));
+
+      assert_eq!(split.first_len, 7);
+    });
+  }
+
+  #[test]
+  fn divider_is_focused_between_panes() {
+    run_scope(create_runtime(), |cx| {
+      let checked = create_rw_signal(cx, false);
+
+      let mut split =
+        VSplit::new(Checkbox::new("a", checked), Checkbox::new("b", checked));
+
+      split.first = Some(split.first_fn.take().unwrap()(cx));
+      split.second = Some(split.second_fn.take().unwrap()(cx));
+
+      split.layout(Limits::strict(5, 5));
+
+      split.on(Event::NextFocus);
+
+      assert!(split.first.as_ref().unwrap().has_focus());
+
+      split.on(Event::NextFocus);
+
+      assert!(split.divider_focused);
+
+      split.on(Event::NextFocus);
+
+      assert!(split.second.as_ref().unwrap().has_focus());
+      assert!(matches!(
+        split.on(Event::NextFocus),
+        EventHandlerResult::Bubble
+      ));
+
+      split.on(Event::PrevFocus);
+
+      assert!(split.second.as_ref().unwrap().has_focus());
+
+      split.on(Event::PrevFocus);
+
+      assert!(split.divider_focused);
+    });
+  }
+}
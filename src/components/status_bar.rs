@@ -0,0 +1,521 @@
+use crate::{
+  dispatch_to_children,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Rect,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use leptos_reactive::Scope;
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Drawn between segments of the same section of a [`StatusBar`].
+const SEPARATOR: &str = " │ ";
+
+/// Drawn over the last cell of a truncated segment.
+const ELLIPSIS: &str = "…";
+
+/// One item of a [`StatusBar`], which can be any view, including a
+/// reactive closure such as `move || format!("Ln {}", line.get())`.
+pub struct Segment {
+  view_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  view: Option<View>,
+  priority: i32,
+  /// Width the segment would like to take.
+  natural_width: u16,
+  /// Width the segment got after truncation.
+  width: u16,
+  x: u16,
+}
+
+impl fmt::Debug for Segment {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Segment")
+      .field("view", &self.view)
+      .field("priority", &self.priority)
+      .field("natural_width", &self.natural_width)
+      .field("width", &self.width)
+      .field("x", &self.x)
+      .finish()
+  }
+}
+
+impl<V: IntoView + Send + Sync + 'static> From<V> for Segment {
+  fn from(view: V) -> Self {
+    Self::new(view)
+  }
+}
+
+impl Segment {
+  pub fn new(view: impl IntoView + Send + Sync + 'static) -> Self {
+    Self {
+      view_fn: Some(Box::new(|cx| view.into_view(cx))),
+      view: None,
+      priority: 0,
+      natural_width: 0,
+      width: 0,
+      x: 0,
+    }
+  }
+
+  /// Sets how important the segment is. When the bar is too narrow,
+  /// segments with a lower priority are truncated, and then hidden, before
+  /// those with a higher one. Defaults to `0`.
+  pub fn priority(mut self, priority: i32) -> Self {
+    self.priority = priority;
+
+    self
+  }
+
+  fn rect(&self) -> Rect {
+    Rect {
+      top_left: XY { x: self.x, y: 0 },
+      size: Size {
+        width: self.width,
+        height: 1,
+      },
+    }
+  }
+}
+
+/// Which part of a [`StatusBar`] a segment is in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Section {
+  Left,
+  Center,
+  Right,
+}
+
+/// A one-line bar docked to the bottom of its content, made of segments
+/// aligned to the left, center and right.
+///
+/// The content takes up all of the space above the bar. Each segment is
+/// as wide as it wants to be, as long as everything fits. When it doesn't,
+/// the segment with the lowest [`Segment::priority`] is truncated, and
+/// hidden once it has no space left, then the next one, and so on. Among
+/// segments of equal priority, those further to the right go first.
+pub struct StatusBar {
+  content_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  content: Option<View>,
+  /// Segments in order from left to right.
+  segments: Vec<(Section, Segment)>,
+  size: Size,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for StatusBar {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("StatusBar")
+      .field("content", &self.content)
+      .field("segments", &self.segments)
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl Widget for StatusBar {
+  fn name(&self) -> Cow<'static, str> {
+    "StatusBar".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.size = limits.max_size();
+
+    let content_limits = self.content_rect().size.into_strict_limits();
+
+    if let Some(content) = &mut self.content {
+      content.layout(content_limits);
+    }
+
+    let segment_limits = Limits {
+      min_width: 0,
+      max_width: self.size.width,
+      min_height: 0,
+      max_height: self.size.height.min(1),
+    };
+
+    for (_, segment) in &mut self.segments {
+      segment.natural_width = segment
+        .view
+        .as_mut()
+        .map_or(0, |view| view.layout(segment_limits).width);
+    }
+
+    self.truncate_segments();
+    self.position_segments();
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if let Some(content) = &self.content {
+      let rect = self.content_rect();
+
+      if rect.size.height > 0 {
+        surface.shrink(
+          rect.top_left,
+          rect.size,
+          Box::new(|surface| content.draw(surface)),
+        );
+      }
+    }
+
+    if self.size.height == 0 {
+      return;
+    }
+
+    let y = self.size.height - 1;
+    let theme = self.theme.get(&self.name());
+
+    let mut previous: Option<&(Section, Segment)> = None;
+
+    for entry in self
+      .segments
+      .iter()
+      .filter(|(_, segment)| segment.width > 0)
+    {
+      let (section, segment) = entry;
+
+      if let Some((previous_section, previous_segment)) = previous {
+        if previous_section == section {
+          surface.write_with_style(
+            XY {
+              x: previous_segment.x + previous_segment.width,
+              y,
+            },
+            SEPARATOR,
+            theme.border,
+          );
+        }
+      }
+
+      if let Some(view) = &segment.view {
+        let rect = segment.rect();
+
+        surface.shrink(
+          XY {
+            x: rect.top_left.x,
+            y,
+          },
+          rect.size,
+          Box::new(|surface| view.draw(surface)),
+        );
+      }
+
+      if segment.width < segment.natural_width {
+        surface.write_with_style(
+          XY {
+            x: segment.x + segment.width - 1,
+            y,
+          },
+          ELLIPSIS,
+          theme.muted,
+        );
+      }
+
+      previous = Some(entry);
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self
+      .content
+      .iter()
+      .chain(self.segments.iter().filter_map(|(_, segment)| {
+        segment.view.as_ref().filter(|_| segment.width > 0)
+      }))
+      .any(|view| view.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let content_rect = self.content_rect();
+    let bar_y = self.size.height.saturating_sub(1);
+
+    let mut children = self
+      .content
+      .as_mut()
+      .map(|content| (content_rect, content as &mut dyn Widget))
+      .into_iter()
+      .chain(self.segments.iter_mut().filter_map(|(_, segment)| {
+        let mut rect = segment.rect();
+
+        rect.top_left.y = bar_y;
+
+        segment
+          .view
+          .as_mut()
+          .filter(|_| rect.size.width > 0)
+          .map(|view| (rect, view as &mut dyn Widget))
+      }))
+      .collect::<Vec<_>>();
+
+    dispatch_to_children(&mut children, event)
+  }
+
+  fn focus(&mut self) {}
+
+  fn blur(&mut self) {
+    let segments = self
+      .segments
+      .iter_mut()
+      .filter_map(|(_, segment)| segment.view.as_mut());
+
+    for view in self.content.iter_mut().chain(segments) {
+      if view.has_focus() {
+        view.blur();
+      }
+    }
+  }
+}
+
+impl IntoView for StatusBar {
+  fn into_view(mut self, cx: Scope) -> View {
+    if let Some(content_fn) = self.content_fn.take() {
+      self.content = Some(content_fn(cx));
+    }
+
+    for (_, segment) in &mut self.segments {
+      if let Some(view_fn) = segment.view_fn.take() {
+        segment.view = Some(view_fn(cx));
+      }
+    }
+
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl StatusBar {
+  /// Creates a status bar below `content`.
+  pub fn new(content: impl IntoView + Send + Sync + 'static) -> Self {
+    Self {
+      content_fn: Some(Box::new(|cx| content.into_view(cx))),
+      content: None,
+      segments: vec![],
+      size: Size::default(),
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Adds a segment after the other left-aligned ones.
+  pub fn left(self, segment: impl Into<Segment>) -> Self {
+    self.segment(Section::Left, segment.into())
+  }
+
+  /// Adds a segment after the other centered ones.
+  pub fn center(self, segment: impl Into<Segment>) -> Self {
+    self.segment(Section::Center, segment.into())
+  }
+
+  /// Adds a segment after the other right-aligned ones.
+  pub fn right(self, segment: impl Into<Segment>) -> Self {
+    self.segment(Section::Right, segment.into())
+  }
+
+  fn segment(mut self, section: Section, segment: Segment) -> Self {
+    // Keep segments sorted from left to right
+    let i = self
+      .segments
+      .iter()
+      .position(|(other, _)| *other as u8 > section as u8)
+      .unwrap_or(self.segments.len());
+
+    self.segments.insert(i, (section, segment));
+
+    self
+  }
+
+  fn content_rect(&self) -> Rect {
+    Rect {
+      top_left: XY::default(),
+      size: Size {
+        width: self.size.width,
+        height: self.size.height.saturating_sub(1),
+      },
+    }
+  }
+
+  /// Returns the width taken by the visible segments and the space
+  /// between them.
+  fn total_width(&self) -> u16 {
+    let mut total = 0u16;
+    let mut previous = false;
+
+    for (_, segment) in &self.segments {
+      if segment.width == 0 {
+        continue;
+      }
+
+      // Sections are kept at least as far apart as segments
+      if previous {
+        total = total.saturating_add(SEPARATOR.chars().count() as u16);
+      }
+
+      total = total.saturating_add(segment.width);
+      previous = true;
+    }
+
+    total
+  }
+
+  fn truncate_segments(&mut self) {
+    for (_, segment) in &mut self.segments {
+      segment.width = segment.natural_width;
+    }
+
+    let mut order = (0..self.segments.len()).collect::<Vec<_>>();
+
+    // Lowest priority first, and rightmost first among equals
+    order.sort_by_key(|&i| (self.segments[i].1.priority, usize::MAX - i));
+
+    for i in order {
+      let excess = self.total_width().saturating_sub(self.size.width);
+
+      if excess == 0 {
+        break;
+      }
+
+      let segment = &mut self.segments[i].1;
+
+      if excess >= segment.width {
+        segment.width = 0;
+      } else {
+        segment.width -= excess;
+      }
+    }
+  }
+
+  fn position_segments(&mut self) {
+    let gap = SEPARATOR.chars().count() as u16;
+    let width = self.size.width;
+
+    let section_width = |segments: &[(Section, Segment)], section| {
+      let widths = segments
+        .iter()
+        .filter(|(s, segment)| *s == section && segment.width > 0)
+        .map(|(_, segment)| segment.width)
+        .collect::<Vec<_>>();
+
+      widths.iter().sum::<u16>() + gap * (widths.len() as u16).saturating_sub(1)
+    };
+
+    let left_width = section_width(&self.segments, Section::Left);
+    let center_width = section_width(&self.segments, Section::Center);
+    let right_width = section_width(&self.segments, Section::Right);
+
+    let left_end = if left_width > 0 { left_width + gap } else { 0 };
+    let right_start = if right_width > 0 {
+      width.saturating_sub(right_width + gap)
+    } else {
+      width
+    };
+
+    // Centered on the whole bar if possible, otherwise pushed away from
+    // whichever side is in the way
+    let center_start = (width.saturating_sub(center_width) / 2)
+      .min(right_start.saturating_sub(center_width))
+      .max(left_end);
+
+    let mut x = [0, center_start, width.saturating_sub(right_width)];
+
+    for (section, segment) in &mut self.segments {
+      if segment.width == 0 {
+        continue;
+      }
+
+      let x = &mut x[*section as usize];
+
+      segment.x = *x;
+
+      *x += segment.width + gap;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+  };
+
+  fn bar(mut status_bar: StatusBar, width: u16) -> StatusBar {
+    run_scope(create_runtime(), move |cx| {
+      status_bar.content = Some(status_bar.content_fn.take().unwrap()(cx));
+
+      for (_, segment) in &mut status_bar.segments {
+        segment.view = Some(segment.view_fn.take().unwrap()(cx));
+      }
+
+      status_bar.layout(Limits::strict(width, 3));
+
+      status_bar
+    })
+  }
+
+  fn widths(status_bar: &StatusBar) -> Vec<(u16, u16)> {
+    status_bar
+      .segments
+      .iter()
+      .map(|(_, segment)| (segment.x, segment.width))
+      .collect()
+  }
+
+  #[test]
+  fn segments_are_aligned_within_their_sections() {
+    let status_bar = bar(
+      StatusBar::new(())
+        .right("UTF-8")
+        .left("main")
+        .center("saved")
+        .left("2 errors"),
+      40,
+    );
+
+    assert_eq!(widths(&status_bar), vec![(0, 4), (7, 8), (18, 5), (35, 5)]);
+  }
+
+  #[test]
+  fn lower_priority_segments_are_truncated_first() {
+    let status_bar = bar(
+      StatusBar::new(())
+        .left(Segment::new("main").priority(2))
+        .center("a long message")
+        .right(Segment::new("Ln 1").priority(1)),
+      20,
+    );
+
+    // 4 + 3 + 4 + 3 + 4 = 18, leaving 6 - 4 for the message
+    assert_eq!(widths(&status_bar), vec![(0, 4), (7, 6), (16, 4)]);
+
+    let status_bar = bar(
+      StatusBar::new(())
+        .left(Segment::new("main").priority(2))
+        .center("a long message")
+        .right(Segment::new("Ln 1").priority(1)),
+      8,
+    );
+
+    // The message is hidden, and the position is cut to fit
+    assert_eq!(widths(&status_bar), vec![(0, 4), (0, 0), (7, 1)]);
+  }
+}
@@ -0,0 +1,382 @@
+use crate::{
+  resource::SuspenseCleanups,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  View,
+  Widget,
+};
+use leptos_reactive::{
+  create_effect,
+  provide_context,
+  RwSignal,
+  Scope,
+  SignalSet,
+  SuspenseContext,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    atomic::{
+      AtomicBool,
+      Ordering,
+    },
+    Arc,
+    Mutex,
+  },
+};
+
+/// Shows a fallback while any resource read by its child is loading.
+///
+/// The suspense provides a `leptos_reactive` [`SuspenseContext`], which
+/// resources created with
+/// [`create_async_resource`](crate::create_async_resource) report to when
+/// they are read under it.
+///
+/// The child is created right away and kept alive while the fallback is
+/// shown, so that its resources can load. The fallback is created in its
+/// own reactive scope every time loading starts, and disposed of once
+/// everything has loaded.
+pub struct Suspense {
+  child_fn: Option<Box<dyn FnOnce(Scope) -> View + Send + Sync>>,
+  child: Option<View>,
+  fallback_fn: Arc<dyn Fn(Scope) -> View + Send + Sync>,
+  /// Whether the fallback is only shown until the child is first ready,
+  /// which is what [`Transition`] does.
+  keep_content: bool,
+  set_pending: Option<RwSignal<bool>>,
+  /// The fallback to show next, or [`None`] to show the child, set as soon
+  /// as loading starts or ends, and swapped in when the suspense is next
+  /// laid out or gets an event.
+  pending: Arc<Mutex<Option<Option<Fallback>>>>,
+  fallback: Option<Fallback>,
+}
+
+/// A fallback view and the scope it was created in.
+type Fallback = (View, Scope);
+
+impl fmt::Debug for Suspense {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Suspense")
+      .field("child", &self.child)
+      .field("fallback", &self.fallback.as_ref().map(|(view, _)| view))
+      .field("keep_content", &self.keep_content)
+      .finish()
+  }
+}
+
+impl Widget for Suspense {
+  fn name(&self) -> Cow<'static, str> {
+    "Suspense".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.swap_fallback();
+
+    self
+      .shown_mut()
+      .map_or(limits.min_size(), |view| view.layout(limits))
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if let Some(view) = self.shown() {
+      view.draw(surface);
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    self.shown().and_then(|view| view.needs_focus())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.shown().is_some_and(|view| view.has_focus())
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    self.swap_fallback();
+
+    match self.shown_mut() {
+      Some(view) => view.on(event),
+      None => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(view) = self.shown_mut() {
+      view.focus();
+    }
+  }
+
+  fn blur(&mut self) {
+    if let Some(view) = self.shown_mut() {
+      view.blur();
+    }
+  }
+}
+
+impl IntoView for Suspense {
+  fn into_view(mut self, cx: Scope) -> View {
+    let suspense_cx = SuspenseContext::new(cx);
+    let cleanups = SuspenseCleanups::new(cx);
+
+    if let Some(child_fn) = self.child_fn.take() {
+      let (child, _) = cx.run_child_scope(|cx| {
+        provide_context(cx, suspense_cx);
+        provide_context(cx, cleanups);
+
+        child_fn(cx)
+      });
+
+      self.child = Some(child);
+    }
+
+    let fallback_fn = self.fallback_fn.clone();
+    let keep_content = self.keep_content;
+    let set_pending = self.set_pending;
+    let pending = self.pending.clone();
+    let was_ready = AtomicBool::new(false);
+
+    create_effect(cx, move |showed_fallback: Option<bool>| {
+      let is_ready = suspense_cx.ready();
+
+      if let Some(set_pending) = set_pending {
+        set_pending.set(!is_ready);
+      }
+
+      let show_fallback =
+        !is_ready && !(keep_content && was_ready.load(Ordering::SeqCst));
+
+      was_ready.fetch_or(is_ready, Ordering::SeqCst);
+
+      if showed_fallback == Some(show_fallback) {
+        return show_fallback;
+      }
+
+      let fallback = show_fallback.then(|| {
+        let ((view, cx), _) =
+          cx.run_child_scope(|cx| (cx.untrack(|| fallback_fn(cx)), cx));
+
+        (view, cx)
+      });
+
+      if let Some(Some((_, skipped))) =
+        pending.lock().unwrap().replace(fallback)
+      {
+        skipped.dispose();
+      }
+
+      show_fallback
+    });
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Suspense {
+  /// Creates a suspense around the view returned by `child`, which must
+  /// read resources with the [`Scope`] it is given for them to be waited
+  /// for.
+  pub fn new<V: IntoView>(
+    child: impl FnOnce(Scope) -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      child_fn: Some(Box::new(|cx| child(cx).into_view(cx))),
+      child: None,
+      fallback_fn: Arc::new(|cx| ().into_view(cx)),
+      keep_content: false,
+      set_pending: None,
+      pending: Default::default(),
+      fallback: None,
+    }
+  }
+
+  /// Sets what to show while loading, such as a
+  /// [`Spinner`](crate::Spinner). Nothing is shown by default.
+  pub fn fallback<V: IntoView>(
+    mut self,
+    fallback: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    self.fallback_fn = Arc::new(move |cx| fallback().into_view(cx));
+
+    self
+  }
+
+  fn shown(&self) -> Option<&View> {
+    match &self.fallback {
+      Some((fallback, _)) => Some(fallback),
+      None => self.child.as_ref(),
+    }
+  }
+
+  fn shown_mut(&mut self) -> Option<&mut View> {
+    match &mut self.fallback {
+      Some((fallback, _)) => Some(fallback),
+      None => self.child.as_mut(),
+    }
+  }
+
+  /// Shows or hides the fallback, if loading started or ended since this
+  /// was last called.
+  fn swap_fallback(&mut self) {
+    let Some(fallback) = self.pending.lock().unwrap().take() else {
+      return;
+    };
+
+    let had_focus = self.has_focus();
+
+    if let Some((_, old)) = std::mem::replace(&mut self.fallback, fallback) {
+      old.dispose();
+    }
+
+    if had_focus {
+      if let Some(view) = self.shown_mut() {
+        view.on(Event::NextFocus);
+      }
+    }
+  }
+}
+
+/// Like [`Suspense`], except that the fallback is only shown until the
+/// child has first loaded. When its resources load again, the child keeps
+/// showing their previous values in the meantime.
+pub struct Transition {
+  suspense: Suspense,
+}
+
+impl fmt::Debug for Transition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Transition")
+      .field("suspense", &self.suspense)
+      .finish()
+  }
+}
+
+impl IntoView for Transition {
+  fn into_view(self, cx: Scope) -> View {
+    self.suspense.into_view(cx)
+  }
+}
+
+impl Transition {
+  /// Creates a transition around the view returned by `child`, see
+  /// [`Suspense::new`].
+  pub fn new<V: IntoView>(
+    child: impl FnOnce(Scope) -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      suspense: Suspense {
+        keep_content: true,
+        ..Suspense::new(child)
+      },
+    }
+  }
+
+  /// Sets what to show until the child has first loaded. Nothing is shown
+  /// by default.
+  pub fn fallback<V: IntoView>(
+    mut self,
+    fallback: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    self.suspense = self.suspense.fallback(fallback);
+
+    self
+  }
+
+  /// Sets `pending` to `true` while resources load, including when they
+  /// load again, so that it can be shown that the content is outdated.
+  pub fn set_pending(mut self, pending: RwSignal<bool>) -> Self {
+    self.suspense.set_pending = Some(pending);
+
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    create_async_resource,
+    executor::poll_tasks,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalGet,
+    SignalGetUntracked,
+  };
+
+  fn render(view: &mut View) -> String {
+    let mut buf = vec![];
+    let mut surface = crate::BufDrawSurface::new(&mut buf, (10u16, 1u16));
+
+    view.layout(Limits {
+      min_width: 0,
+      max_width: 10,
+      min_height: 0,
+      max_height: 1,
+    });
+    view.draw(&mut surface);
+
+    drop(surface);
+
+    String::from_utf8(buf).unwrap()
+  }
+
+  #[test]
+  fn fallback_is_shown_while_loading() {
+    run_scope(create_runtime(), |cx| {
+      let id = create_rw_signal(cx, 1);
+      let senders = Arc::new(Mutex::new(vec![]));
+
+      let resource = create_async_resource(cx, move || id.get(), {
+        let senders = senders.clone();
+
+        move |id: i32| {
+          let (tx, rx) = tokio::sync::oneshot::channel();
+
+          senders.lock().unwrap().push(tx);
+
+          async move { rx.await.unwrap_or_default() + id }
+        }
+      });
+
+      let pending = create_rw_signal(cx, false);
+
+      let mut view = Transition::new(move |cx| {
+        move || format!("item {}", resource.read(cx).unwrap_or_default())
+      })
+      .fallback(|| "loading")
+      .set_pending(pending)
+      .into_view(cx);
+
+      poll_tasks();
+
+      assert!(render(&mut view).contains("loading"));
+      assert!(pending.get_untracked());
+
+      senders.lock().unwrap().remove(0).send(10).unwrap();
+      poll_tasks();
+
+      assert!(render(&mut view).contains("item 11"));
+      assert!(!pending.get_untracked());
+
+      // Reloading keeps the previous content
+      id.set(2);
+      poll_tasks();
+
+      assert!(render(&mut view).contains("item 11"));
+      assert!(pending.get_untracked());
+
+      senders.lock().unwrap().remove(0).send(10).unwrap();
+      poll_tasks();
+
+      assert!(render(&mut view).contains("item 12"));
+      assert!(!pending.get_untracked());
+    });
+  }
+}
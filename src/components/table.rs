@@ -0,0 +1,764 @@
+use super::Alignment;
+use crate::{
+  cycle_leaf_focus,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  Style,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::{
+  event::{
+    KeyCode,
+    KeyEvent,
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+  },
+  style::Attribute,
+};
+use leptos_reactive::{
+  create_effect,
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Number of blank columns between two table columns.
+const COLUMN_GAP: u16 = 1;
+
+/// How the width of a [`Column`] is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnWidth {
+  /// Always exactly this many characters wide.
+  Fixed(u16),
+  /// A percentage of the width available to the table.
+  Percentage(u16),
+  /// As wide as the widest cell, header included, but within `min` and
+  /// `max`. If `min` is larger than `max`, the column is `min` wide.
+  MinMax { min: u16, max: u16 },
+  /// As wide as the widest cell, header included.
+  Auto,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+  Ascending,
+  Descending,
+}
+
+impl SortOrder {
+  pub fn reversed(self) -> Self {
+    match self {
+      Self::Ascending => Self::Descending,
+      Self::Descending => Self::Ascending,
+    }
+  }
+
+  fn indicator(self) -> &'static str {
+    match self {
+      Self::Ascending => " ▲",
+      Self::Descending => " ▼",
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+  title: Cow<'static, str>,
+  width: ColumnWidth,
+  alignment: Alignment,
+}
+
+impl Column {
+  pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+    Self {
+      title: title.into(),
+      width: ColumnWidth::Auto,
+      alignment: Alignment::Start,
+    }
+  }
+
+  pub fn width(mut self, width: ColumnWidth) -> Self {
+    self.width = width;
+
+    self
+  }
+
+  /// Sets the alignment of the header and every cell in this column.
+  pub fn align(mut self, alignment: Alignment) -> Self {
+    self.alignment = alignment;
+
+    self
+  }
+}
+
+/// A table of text cells with a header row which stays visible while the
+/// rows below it scroll.
+///
+/// While focused, Up, Down, PageUp, PageDown, Home and End move the
+/// selected row, Left and Right move between columns, and Enter activates
+/// the current column's header, as does clicking on it, which calls the
+/// [`Table::on_sort`] callback.
+///
+/// The rows are only loaded again when the signals read by the closure
+/// returning them change, which is also when the selection is moved back
+/// within the table if it shrank.
+pub struct Table {
+  columns: Vec<Column>,
+  rows_fn: Arc<dyn Fn() -> Rows + Send + Sync>,
+  /// Every row, as last returned by `rows_fn`, or [`None`] until they are
+  /// first loaded.
+  all_rows: Arc<Mutex<Option<Rows>>>,
+  selected: Option<RwSignal<usize>>,
+  sort_fn: Option<SortFn>,
+  on_sort: Option<Box<dyn Fn(usize) + Send + Sync>>,
+  striped: bool,
+  /// Index of the first visible row.
+  offset: usize,
+  scrolled_to: Option<usize>,
+  /// The column activated by Enter.
+  active_column: usize,
+  column_widths: Vec<u16>,
+  header: Vec<String>,
+  /// The visible rows, with each cell aligned within its column.
+  rows: Rows,
+  len: usize,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+type Rows = Vec<Vec<String>>;
+
+type SortFn = Box<dyn Fn() -> Option<(usize, SortOrder)> + Send + Sync>;
+
+impl fmt::Debug for Table {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Table")
+      .field("columns", &self.columns)
+      .field("rows_fn", &"Fn() -> Vec<Vec<String>>")
+      .field("selected", &self.selected)
+      .field("striped", &self.striped)
+      .field("offset", &self.offset)
+      .field("active_column", &self.active_column)
+      .field("column_widths", &self.column_widths)
+      .field("size", &self.size)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl Widget for Table {
+  fn name(&self) -> Cow<'static, str> {
+    "Table".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let all_rows = self.all_rows.clone();
+    let mut all_rows = all_rows.lock().unwrap();
+    let rows = all_rows.get_or_insert_with(|| (self.rows_fn)());
+    let sort = self.sort_fn.as_ref().and_then(|sort_fn| sort_fn());
+
+    self.len = rows.len();
+
+    let titles = self
+      .columns
+      .iter()
+      .enumerate()
+      .map(|(i, column)| match sort {
+        Some((sorted, order)) if sorted == i => {
+          format!("{}{}", column.title, order.indicator())
+        }
+        _ => column.title.to_string(),
+      })
+      .collect::<Vec<_>>();
+
+    self.column_widths =
+      self.compute_column_widths(&titles, rows, limits.max_width);
+
+    let body_height = limits.max_height.saturating_sub(1) as usize;
+
+    if let Some(selected) = self.selected {
+      let clamped = selected.get_untracked().min(self.len.saturating_sub(1));
+
+      if self.scrolled_to != Some(clamped) {
+        if clamped < self.offset {
+          self.offset = clamped;
+        } else if body_height > 0 && clamped >= self.offset + body_height {
+          self.offset = clamped + 1 - body_height;
+        }
+
+        self.scrolled_to = Some(clamped);
+      }
+    }
+
+    self.offset = self.offset.min(self.len.saturating_sub(body_height));
+
+    self.header = self
+      .columns
+      .iter()
+      .zip(&titles)
+      .zip(&self.column_widths)
+      .map(|((column, title), width)| column.alignment.align(title, *width))
+      .collect();
+
+    self.rows = rows
+      .iter()
+      .skip(self.offset)
+      .take(body_height)
+      .map(|row| {
+        self
+          .columns
+          .iter()
+          .zip(&self.column_widths)
+          .enumerate()
+          .map(|(i, (column, width))| {
+            column
+              .alignment
+              .align(row.get(i).map_or("", String::as_str), *width)
+          })
+          .collect()
+      })
+      .collect();
+
+    let width = self
+      .column_widths
+      .iter()
+      .filter(|width| **width > 0)
+      .map(|width| width + COLUMN_GAP)
+      .sum::<u16>()
+      .saturating_sub(COLUMN_GAP)
+      .clamp(limits.min_width, limits.max_width);
+    let height = if limits.max_height == 0 {
+      0
+    } else {
+      (1 + self.rows.len() as u16).max(limits.min_height)
+    };
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    if self.size.height == 0 {
+      return;
+    }
+
+    let theme = self.theme.get(&self.name());
+    let mut x = 0;
+
+    for (i, (title, width)) in
+      self.header.iter().zip(&self.column_widths).enumerate()
+    {
+      let is_active = self.focused && i == self.active_column;
+
+      surface.write_with_style(
+        XY { x, y: 0 },
+        title,
+        theme.text.attribute(if is_active {
+          Attribute::Underlined
+        } else {
+          Attribute::Bold
+        }),
+      );
+
+      x += width + COLUMN_GAP;
+    }
+
+    let selected = self.selected.map(|selected| selected.get_untracked());
+
+    for (i, row) in self.rows.iter().enumerate() {
+      let index = self.offset + i;
+      let is_selected = selected == Some(index);
+
+      let line = row.join(&" ".repeat(COLUMN_GAP as usize));
+      let line = Alignment::Start.align(&line, self.size.width);
+
+      let style = if is_selected && self.focused {
+        // The focus colors, keeping the selected row's attribute
+        Style {
+          attribute: theme.selected.attribute,
+          ..theme.focus
+        }
+      } else if is_selected {
+        theme.selected
+      } else if self.striped && index % 2 == 1 {
+        theme.text.patch(theme.stripe)
+      } else {
+        theme.text
+      };
+
+      surface.write_with_style(
+        XY {
+          x: 0,
+          y: 1 + i as u16,
+        },
+        &line,
+        style,
+      );
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let page = (self.size.height.saturating_sub(1) as usize).max(1);
+
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => {
+        let last_column = self.columns.len().saturating_sub(1);
+
+        match code {
+          KeyCode::Left => {
+            self.active_column = self.active_column.saturating_sub(1);
+          }
+          KeyCode::Right => {
+            self.active_column = (self.active_column + 1).min(last_column);
+          }
+          KeyCode::Enter => self.activate_header(self.active_column),
+          KeyCode::Up
+          | KeyCode::Down
+          | KeyCode::PageUp
+          | KeyCode::PageDown
+          | KeyCode::Home
+          | KeyCode::End => {
+            let (Some(selected), Some(last)) =
+              (self.selected, self.len.checked_sub(1))
+            else {
+              return EventHandlerResult::Bubble;
+            };
+
+            let current = selected.get_untracked().min(last);
+
+            selected.set(
+              match code {
+                KeyCode::Up => current.saturating_sub(1),
+                KeyCode::Down => current + 1,
+                KeyCode::PageUp => current.saturating_sub(page),
+                KeyCode::PageDown => current + page,
+                KeyCode::Home => 0,
+                _ => last,
+              }
+              .min(last),
+            );
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) if column < self.size.width && row < self.size.height => {
+        match kind {
+          MouseEventKind::Down(MouseButton::Left) => {
+            self.focus();
+
+            if row == 0 {
+              if let Some(i) = self.column_at(column) {
+                self.active_column = i;
+                self.activate_header(i);
+              }
+            } else if let Some(selected) = self.selected {
+              let index = self.offset + row as usize - 1;
+
+              if index < self.len {
+                selected.set(index);
+              }
+            }
+          }
+          MouseEventKind::ScrollUp => {
+            self.offset = self.offset.saturating_sub(1);
+          }
+          MouseEventKind::ScrollDown => {
+            self.offset = (self.offset + 1).min(self.len.saturating_sub(page));
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl IntoView for Table {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.mount(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Table {
+  /// Creates a table with the given columns, whose rows of cells are
+  /// returned by `rows`.
+  pub fn new(
+    columns: impl IntoIterator<Item = Column>,
+    rows: impl Fn() -> Vec<Vec<String>> + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      columns: columns.into_iter().collect(),
+      rows_fn: Arc::new(rows),
+      all_rows: Default::default(),
+      selected: None,
+      sort_fn: None,
+      on_sort: None,
+      striped: false,
+      offset: 0,
+      scrolled_to: None,
+      active_column: 0,
+      column_widths: vec![],
+      header: vec![],
+      rows: vec![],
+      len: 0,
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Tracks the selected row in `selected`.
+  pub fn selected(mut self, selected: RwSignal<usize>) -> Self {
+    self.selected = Some(selected);
+
+    self
+  }
+
+  /// Shows a sort indicator in the header of the column returned by
+  /// `sort`, if any.
+  pub fn sorted_by(
+    mut self,
+    sort: impl Fn() -> Option<(usize, SortOrder)> + Send + Sync + 'static,
+  ) -> Self {
+    self.sort_fn = Some(Box::new(sort));
+
+    self
+  }
+
+  /// Called with the index of a column when its header is activated.
+  pub fn on_sort(mut self, f: impl Fn(usize) + Send + Sync + 'static) -> Self {
+    self.on_sort = Some(Box::new(f));
+
+    self
+  }
+
+  /// Draws every other row with a different background color.
+  pub fn striped(mut self, striped: bool) -> Self {
+    self.striped = striped;
+
+    self
+  }
+
+  fn mount(&mut self, cx: Scope) {
+    self.theme = ThemeHandle::new(cx);
+
+    let rows_fn = self.rows_fn.clone();
+    let all_rows = self.all_rows.clone();
+    let selected = self.selected;
+
+    // Keeps the selection within the table when it shrinks
+    create_effect(cx, move |_| {
+      let rows = rows_fn();
+
+      if let Some(selected) = selected {
+        let current = selected.get_untracked();
+
+        if current >= rows.len() && current > 0 {
+          selected.set(rows.len().saturating_sub(1));
+        }
+      }
+
+      *all_rows.lock().unwrap() = Some(rows);
+    });
+  }
+
+  fn activate_header(&self, column: usize) {
+    if let Some(on_sort) = &self.on_sort {
+      on_sort(column);
+    }
+  }
+
+  fn column_at(&self, x: u16) -> Option<usize> {
+    let mut start = 0;
+
+    for (i, width) in self.column_widths.iter().enumerate() {
+      if x >= start && x < start + width {
+        return Some(i);
+      }
+
+      start += width + COLUMN_GAP;
+    }
+
+    None
+  }
+
+  fn compute_column_widths(
+    &self,
+    titles: &[String],
+    rows: &[Vec<String>],
+    max_width: u16,
+  ) -> Vec<u16> {
+    let gaps = COLUMN_GAP * self.columns.len().saturating_sub(1) as u16;
+    let available = max_width.saturating_sub(gaps);
+
+    let content_width = |i: usize| {
+      rows
+        .iter()
+        .filter_map(|row| row.get(i))
+        .chain([&titles[i]])
+        .map(|cell| cell.chars().count() as u16)
+        .max()
+        .unwrap_or_default()
+    };
+
+    let mut remaining = max_width;
+
+    self
+      .columns
+      .iter()
+      .enumerate()
+      .map(|(i, column)| {
+        let width = match column.width {
+          ColumnWidth::Fixed(width) => width,
+          ColumnWidth::Percentage(percentage) => {
+            (available as u32 * percentage.min(100) as u32 / 100) as u16
+          }
+          ColumnWidth::MinMax { min, max } => {
+            content_width(i).clamp(min, max.max(min))
+          }
+          ColumnWidth::Auto => content_width(i),
+        }
+        .min(remaining);
+
+        remaining = remaining.saturating_sub(width + COLUMN_GAP);
+
+        width
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::components::get_widget_output;
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::{
+      Color,
+      Print,
+      SetAttribute,
+      SetBackgroundColor,
+    },
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalGet,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn processes() -> Vec<Vec<String>> {
+    (1..=20)
+      .map(|pid| vec![pid.to_string(), format!("proc{pid}")])
+      .collect()
+  }
+
+  #[test]
+  fn sizes_columns() {
+    let mut table = Table::new(
+      [
+        Column::new("A").width(ColumnWidth::Fixed(3)),
+        Column::new("B").width(ColumnWidth::Percentage(50)),
+        Column::new("C").width(ColumnWidth::MinMax { min: 2, max: 3 }),
+        Column::new("D"),
+      ],
+      || vec![vec!["".into(), "".into(), "cccc".into(), "dd".into()]],
+    );
+
+    table.layout(Limits::strict(23, 5));
+
+    assert_eq!(table.column_widths, vec![3, 10, 3, 2]);
+  }
+
+  #[test]
+  fn min_wins_over_a_smaller_max() {
+    let mut table = Table::new(
+      [Column::new("A").width(ColumnWidth::MinMax { min: 4, max: 2 })],
+      || vec![vec!["a".into()]],
+    );
+
+    table.layout(Limits::strict(10, 5));
+
+    assert_eq!(table.column_widths, vec![4]);
+  }
+
+  #[test]
+  fn renders_header_and_aligned_cells() {
+    let table = Table::new(
+      [
+        Column::new("PID").align(Alignment::End),
+        Column::new("NAME"),
+      ],
+      || vec![vec!["7".into(), "init".into()]],
+    )
+    .sorted_by(|| Some((1, SortOrder::Descending)));
+
+    let (size, output) = get_widget_output(table, (20, 5), (0, 0));
+
+    assert_eq!(size, (10, 2).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print(format!(
+          "{}PID{}",
+          SetAttribute(Attribute::Bold),
+          SetAttribute(Attribute::Reset)
+        )),
+        MoveTo(4, 0),
+        Print(format!(
+          "{}NAME ▼{}",
+          SetAttribute(Attribute::Bold),
+          SetAttribute(Attribute::Reset)
+        )),
+        MoveTo(0, 1),
+        Print("  7 init  "),
+      ]
+    );
+  }
+
+  #[test]
+  fn header_stays_visible_while_scrolling() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, 0);
+      let mut table =
+        Table::new([Column::new("PID"), Column::new("NAME")], processes)
+          .selected(selected);
+
+      table.layout(Limits::strict(20, 4));
+      table.focus();
+      table.on(Event::Key(KeyEvent::new(KeyCode::End, KeyModifiers::NONE)));
+      table.layout(Limits::strict(20, 4));
+
+      assert_eq!(selected.get_untracked(), 19);
+      assert_eq!(table.offset, 17);
+      assert_eq!(table.header[0].trim(), "PID");
+      assert_eq!(table.rows[2][0].trim(), "20");
+    });
+  }
+
+  #[test]
+  fn clamps_selection_when_rows_shrink() {
+    run_scope(create_runtime(), |cx| {
+      let rows = create_rw_signal(cx, processes());
+      let selected = create_rw_signal(cx, 19);
+      let mut table =
+        Table::new([Column::new("PID")], move || rows.get()).selected(selected);
+
+      table.mount(cx);
+      table.layout(Limits::strict(20, 4));
+
+      rows.set(processes().into_iter().take(5).collect());
+
+      assert_eq!(selected.get_untracked(), 4);
+
+      table.layout(Limits::strict(20, 4));
+
+      assert_eq!(table.len, 5);
+      assert_eq!(table.rows[2][0].trim(), "5");
+    });
+  }
+
+  #[test]
+  fn stripes_every_other_row() {
+    let table = Table::new([Column::new("N")], || {
+      vec![vec!["1".into()], vec!["2".into()], vec!["3".into()]]
+    })
+    .striped(true);
+
+    let (_, output) = get_widget_output(table, (1, 4), (0, 0));
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        SetAttribute(Attribute::Bold),
+        Print("N"),
+        SetAttribute(Attribute::Reset),
+        MoveTo(0, 1),
+        Print("1"),
+        MoveTo(0, 2),
+        SetBackgroundColor(Color::DarkGrey),
+        Print("2"),
+        SetAttribute(Attribute::Reset),
+        MoveTo(0, 3),
+        Print("3"),
+      ]
+    );
+  }
+
+  #[test]
+  fn clicking_header_calls_on_sort() {
+    let sorted = Arc::new(AtomicUsize::new(usize::MAX));
+
+    let mut table =
+      Table::new([Column::new("PID"), Column::new("NAME")], processes).on_sort(
+        {
+          let sorted = sorted.clone();
+
+          move |column| sorted.store(column, Ordering::SeqCst)
+        },
+      );
+
+    table.layout(Limits::strict(20, 4));
+    table.on(Event::Mouse(MouseEvent {
+      kind: MouseEventKind::Down(MouseButton::Left),
+      column: 4,
+      row: 0,
+      modifiers: KeyModifiers::NONE,
+    }));
+
+    assert_eq!(sorted.load(Ordering::SeqCst), 1);
+  }
+}
@@ -0,0 +1,693 @@
+use crate::{
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  Style,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  KeyModifiers,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  ops::Range,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A single tab of a [`Tabs`] container.
+pub struct Tab {
+  title: Cow<'static, str>,
+  view_fn: Box<dyn Fn(Scope) -> View + Send + Sync>,
+  keep_alive: bool,
+  /// The tab's view and the scope it was created in, while it exists.
+  view: Option<(View, Scope)>,
+}
+
+impl fmt::Debug for Tab {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Tab")
+      .field("title", &self.title)
+      .field("keep_alive", &self.keep_alive)
+      .field("view", &self.view.as_ref().map(|(view, _)| view))
+      .finish()
+  }
+}
+
+impl Tab {
+  /// Creates a tab titled `title`, whose content is created with `view`
+  /// when the tab is first shown.
+  pub fn new<V: IntoView>(
+    title: impl Into<Cow<'static, str>>,
+    view: impl Fn() -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      title: title.into(),
+      view_fn: Box::new(move |cx| view().into_view(cx)),
+      keep_alive: false,
+      view: None,
+    }
+  }
+
+  /// Keeps the tab's view and its reactive scope alive while another tab
+  /// is shown, instead of disposing of them and re-creating the view when
+  /// the tab is shown again.
+  pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+    self.keep_alive = keep_alive;
+
+    self
+  }
+
+  fn dispose(&mut self) {
+    if let Some((_, cx)) = self.view.take() {
+      cx.dispose();
+    }
+  }
+}
+
+/// A tab bar, with the view of the active tab below it.
+///
+/// While the tab bar is focused, Left and Right switch tabs, and clicking a
+/// title switches to its tab. Ctrl+PageUp and Ctrl+PageDown switch tabs
+/// while focus is anywhere within the container, so that only the focused
+/// one of several containers switches. When containers are nested, the
+/// innermost one switches.
+pub struct Tabs {
+  tabs: Vec<Tab>,
+  active: RwSignal<usize>,
+  cx: Option<Scope>,
+  /// The tab whose view was last laid out.
+  shown: Option<usize>,
+  /// Columns covered by each title in the tab bar.
+  title_ranges: Vec<Range<u16>>,
+  size: Size,
+  content_size: Size,
+  bar_focused: bool,
+  theme: ThemeHandle,
+}
+
+impl fmt::Debug for Tabs {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Tabs")
+      .field("tabs", &self.tabs)
+      .field("active", &self.active)
+      .field("shown", &self.shown)
+      .field("size", &self.size)
+      .field("bar_focused", &self.bar_focused)
+      .finish()
+  }
+}
+
+impl Widget for Tabs {
+  fn name(&self) -> Cow<'static, str> {
+    "Tabs".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let active = self.active_index();
+
+    if self.shown != Some(active) {
+      // Focus moves to the tab bar if it was within the previous tab's view
+      if self.content_has_focus() {
+        self.bar_focused = true;
+      }
+
+      if let Some(shown) = self.shown.and_then(|i| self.tabs.get_mut(i)) {
+        if shown.keep_alive {
+          if let Some((view, _)) = &mut shown.view {
+            view.blur();
+          }
+        } else {
+          shown.dispose();
+        }
+      }
+
+      self.shown = Some(active);
+    }
+
+    if let (Some(cx), Some(tab)) = (self.cx, self.tabs.get_mut(active)) {
+      if tab.view.is_none() {
+        let view_fn = &tab.view_fn;
+
+        let (view, _) =
+          cx.run_child_scope(|cx| (cx.untrack(|| view_fn(cx)), cx));
+
+        tab.view = Some(view);
+      }
+    }
+
+    self.title_ranges.clear();
+
+    let mut x = 0;
+
+    for tab in &self.tabs {
+      let width = tab.title.chars().count() as u16 + 2;
+
+      self.title_ranges.push(x..x + width);
+
+      x += width;
+    }
+
+    let content_limits = Limits {
+      min_width: 0,
+      max_width: limits.max_width,
+      min_height: 0,
+      max_height: limits.max_height.saturating_sub(1),
+    };
+
+    self.content_size = self
+      .content_mut()
+      .map(|view| view.layout(content_limits))
+      .unwrap_or_default();
+
+    self.size = Size {
+      width: x
+        .max(self.content_size.width)
+        .clamp(limits.min_width, limits.max_width),
+      height: (self.content_size.height + 1)
+        .clamp(limits.min_height, limits.max_height),
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let active = self.active_index();
+    let theme = self.theme.get(&self.name());
+
+    for (i, (tab, range)) in
+      self.tabs.iter().zip(&self.title_ranges).enumerate()
+    {
+      let is_active = i == active;
+
+      let style = match (is_active, self.bar_focused) {
+        // The focus colors, keeping the active tab's attribute
+        (true, true) => Style {
+          attribute: theme.selected.attribute,
+          ..theme.focus
+        },
+        (true, false) => theme.selected,
+        (false, _) => theme.text,
+      };
+
+      surface.write_with_style(
+        XY {
+          x: range.start,
+          y: 0,
+        },
+        &format!(" {} ", tab.title),
+        style,
+      );
+    }
+
+    if self.content_size.width > 0 && self.content_size.height > 0 {
+      if let Some((view, _)) =
+        &self.tabs.get(active).and_then(|tab| tab.view.as_ref())
+      {
+        surface.shrink(
+          XY { x: 0, y: 1 },
+          self.content_size,
+          Box::new(|surface| view.draw(surface)),
+        );
+      }
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(true)
+  }
+
+  fn has_focus(&self) -> bool {
+    self.bar_focused || self.content_has_focus()
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match event {
+      Event::NextFocus => {
+        if self.bar_focused {
+          self.bar_focused = false;
+
+          self.content_on(Event::NextFocus)
+        } else if self.content_has_focus() {
+          self.content_on(Event::NextFocus)
+        } else {
+          self.bar_focused = true;
+
+          EventHandlerResult::Captured
+        }
+      }
+      Event::PrevFocus => {
+        if self.bar_focused {
+          self.bar_focused = false;
+
+          EventHandlerResult::Bubble
+        } else if let EventHandlerResult::Captured =
+          self.content_on(Event::PrevFocus)
+        {
+          EventHandlerResult::Captured
+        } else {
+          self.bar_focused = true;
+
+          EventHandlerResult::Captured
+        }
+      }
+      Event::Key(KeyEvent {
+        code, modifiers, ..
+      }) => {
+        if self.content_has_focus() {
+          if let result @ (EventHandlerResult::Captured
+          | EventHandlerResult::Mapped(_)) = self.content_on(event)
+          {
+            return result;
+          }
+        }
+
+        match code {
+          KeyCode::PageUp
+            if modifiers == KeyModifiers::CONTROL && self.has_focus() =>
+          {
+            self.switch_by(-1)
+          }
+          KeyCode::PageDown
+            if modifiers == KeyModifiers::CONTROL && self.has_focus() =>
+          {
+            self.switch_by(1)
+          }
+          KeyCode::Left if self.bar_focused => self.switch_by(-1),
+          KeyCode::Right if self.bar_focused => self.switch_by(1),
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) if row == 0 && column < self.size.width => {
+        if let MouseEventKind::Down(MouseButton::Left) = kind {
+          if let Some(i) = self
+            .title_ranges
+            .iter()
+            .position(|range| range.contains(&column))
+          {
+            self.focus();
+            self.active.set(i);
+          }
+        }
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(_) => {
+        let Some(event) =
+          event.relative_to(XY { x: 0, y: 1 }, self.content_size)
+        else {
+          return EventHandlerResult::Bubble;
+        };
+
+        let result = self.content_on(event);
+
+        if self.content_has_focus() {
+          self.bar_focused = false;
+        }
+
+        result
+      }
+      Event::Batch(events) => {
+        for event in events {
+          self.on(event);
+        }
+
+        EventHandlerResult::Captured
+      }
+    }
+  }
+
+  fn focus(&mut self) {
+    if let Some(view) = self.content_mut() {
+      if view.has_focus() {
+        view.blur();
+      }
+    }
+
+    self.bar_focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.bar_focused = false;
+
+    if let Some(view) = self.content_mut() {
+      if view.has_focus() {
+        view.blur();
+      }
+    }
+  }
+}
+
+impl IntoView for Tabs {
+  fn into_view(mut self, cx: Scope) -> View {
+    self.cx = Some(cx);
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl Tabs {
+  /// Creates a tab container, showing the tab whose index is in `active`.
+  pub fn new(
+    tabs: impl IntoIterator<Item = Tab>,
+    active: RwSignal<usize>,
+  ) -> Self {
+    Self {
+      tabs: tabs.into_iter().collect(),
+      active,
+      cx: None,
+      shown: None,
+      title_ranges: vec![],
+      size: Size::default(),
+      content_size: Size::default(),
+      bar_focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Returns the active tab's index, making sure it is not out of bounds.
+  fn active_index(&self) -> usize {
+    self
+      .active
+      .get_untracked()
+      .min(self.tabs.len().saturating_sub(1))
+  }
+
+  /// Returns the view of the tab which was last laid out.
+  fn content_mut(&mut self) -> Option<&mut View> {
+    let shown = self.shown?;
+
+    self
+      .tabs
+      .get_mut(shown)?
+      .view
+      .as_mut()
+      .map(|(view, _)| view)
+  }
+
+  fn content_has_focus(&self) -> bool {
+    self
+      .shown
+      .and_then(|shown| self.tabs.get(shown)?.view.as_ref())
+      .is_some_and(|(view, _)| view.has_focus())
+  }
+
+  fn content_on(&mut self, event: Event) -> EventHandlerResult {
+    self
+      .content_mut()
+      .map_or(EventHandlerResult::Bubble, |view| view.on(event))
+  }
+
+  /// Switches to the tab `delta` tabs away from the active one, wrapping
+  /// around at either end. Focus moves to the tab bar if it was within the
+  /// previous tab's view.
+  fn switch_by(&mut self, delta: isize) {
+    if self.content_has_focus() {
+      self.focus();
+    }
+
+    let len = self.tabs.len();
+
+    if len == 0 {
+      return;
+    }
+
+    let current = self.active_index() as isize;
+
+    self
+      .active
+      .set((current + delta).rem_euclid(len as isize) as usize);
+  }
+}
+
+impl Drop for Tabs {
+  fn drop(&mut self) {
+    for tab in &mut self.tabs {
+      tab.dispose();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Checkbox;
+  use crossterm::event::KeyModifiers;
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+    SignalGet,
+  };
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+  }
+
+  fn tabs(cx: Scope, active: RwSignal<usize>) -> Tabs {
+    let mut tabs = Tabs::new(
+      [
+        Tab::new("one", || "first"),
+        Tab::new("two", || "second"),
+        Tab::new("three", || "third"),
+      ],
+      active,
+    );
+
+    tabs.cx = Some(cx);
+
+    tabs
+  }
+
+  #[test]
+  fn renders_bar_and_active_tab() {
+    run_scope(create_runtime(), |cx| {
+      let active = create_rw_signal(cx, 1);
+
+      let tabs = tabs(cx, active);
+
+      let (size, output) =
+        crate::components::get_widget_output(tabs, (20, 5), (0, 0));
+
+      assert_eq!(size, (17, 2).into());
+      assert!(output.contains("second"));
+      assert!(!output.contains("first"));
+    });
+  }
+
+  #[test]
+  fn switches_with_keyboard_and_mouse() {
+    run_scope(create_runtime(), |cx| {
+      let active = create_rw_signal(cx, 0);
+      let mut tabs = tabs(cx, active);
+
+      tabs.layout(Limits::strict(20, 5));
+
+      tabs.on(key(KeyCode::Left, KeyModifiers::NONE));
+
+      assert_eq!(active.get_untracked(), 0);
+
+      tabs.on(Event::NextFocus);
+      tabs.on(key(KeyCode::Left, KeyModifiers::NONE));
+
+      assert_eq!(active.get_untracked(), 2);
+
+      tabs.on(key(KeyCode::Right, KeyModifiers::NONE));
+
+      assert_eq!(active.get_untracked(), 0);
+
+      tabs.on(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 6,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+      }));
+
+      assert_eq!(active.get_untracked(), 1);
+    });
+  }
+
+  #[test]
+  fn disposes_inactive_tabs_unless_kept_alive() {
+    run_scope(create_runtime(), |cx| {
+      let active = create_rw_signal(cx, 0);
+      let tick = create_rw_signal(cx, 0);
+      let runs = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+
+      let tab = |i: usize, keep_alive| {
+        let runs = runs.clone();
+
+        Tab::new(i.to_string(), move || {
+          let runs = runs.clone();
+
+          move || {
+            runs[i].fetch_add(1, Ordering::SeqCst);
+
+            tick.get().to_string()
+          }
+        })
+        .keep_alive(keep_alive)
+      };
+
+      let mut tabs = Tabs::new([tab(0, false), tab(1, true)], active);
+      tabs.cx = Some(cx);
+
+      tabs.layout(Limits::strict(10, 5));
+      active.set(1);
+      tabs.layout(Limits::strict(10, 5));
+      active.set(0);
+      tabs.layout(Limits::strict(10, 5));
+
+      let counts = || {
+        runs
+          .iter()
+          .map(|runs| runs.load(Ordering::SeqCst))
+          .collect()
+      };
+
+      let before: Vec<_> = counts();
+
+      tick.set(1);
+
+      let after: Vec<_> = counts();
+
+      assert_eq!(after[0], before[0] + 1);
+      assert_eq!(after[1], before[1] + 1);
+
+      active.set(1);
+      tabs.layout(Limits::strict(10, 5));
+      tick.set(2);
+
+      // Only the tab which was kept alive still updates
+      assert_eq!(counts(), vec![after[0], after[1] + 1]);
+    });
+  }
+
+  #[test]
+  fn focus_moves_from_bar_to_content() {
+    run_scope(create_runtime(), |cx| {
+      let active = create_rw_signal(cx, 0);
+      let checked = create_rw_signal(cx, false);
+
+      let mut tabs = Tabs::new(
+        [Tab::new("a", move || Checkbox::new("check", checked))],
+        active,
+      );
+      tabs.cx = Some(cx);
+
+      tabs.layout(Limits::strict(20, 5));
+
+      tabs.on(Event::NextFocus);
+
+      assert!(tabs.bar_focused);
+
+      tabs.on(Event::NextFocus);
+
+      assert!(!tabs.bar_focused);
+      assert!(tabs.content_has_focus());
+
+      tabs.on(key(KeyCode::Char(' '), KeyModifiers::NONE));
+
+      assert!(checked.get_untracked());
+
+      assert!(matches!(
+        tabs.on(Event::NextFocus),
+        EventHandlerResult::Bubble
+      ));
+      assert!(!tabs.has_focus());
+    });
+  }
+
+  #[test]
+  fn ctrl_page_keys_switch_only_the_focused_tabs() {
+    run_scope(create_runtime(), |cx| {
+      let first_active = create_rw_signal(cx, 0);
+      let second_active = create_rw_signal(cx, 0);
+      let ctrl_page_up = key(KeyCode::PageUp, KeyModifiers::CONTROL);
+      let ctrl_page_down = key(KeyCode::PageDown, KeyModifiers::CONTROL);
+
+      let mut first = tabs(cx, first_active);
+      let mut second = tabs(cx, second_active);
+
+      first.layout(Limits::strict(20, 5));
+      second.layout(Limits::strict(20, 5));
+
+      first.focus();
+
+      assert!(matches!(
+        first.on(ctrl_page_up.clone()),
+        EventHandlerResult::Captured
+      ));
+      assert!(matches!(
+        second.on(ctrl_page_up),
+        EventHandlerResult::Bubble
+      ));
+
+      assert_eq!(first_active.get_untracked(), 2);
+      assert_eq!(second_active.get_untracked(), 0);
+
+      first.on(ctrl_page_down);
+
+      assert_eq!(first_active.get_untracked(), 0);
+    });
+  }
+
+  #[test]
+  fn ctrl_page_keys_switch_from_within_the_content() {
+    run_scope(create_runtime(), |cx| {
+      let active = create_rw_signal(cx, 0);
+
+      let mut tabs = Tabs::new(
+        [
+          Tab::new("a", move || {
+            Checkbox::new("check", create_rw_signal(cx, false))
+          }),
+          Tab::new("b", || "b"),
+        ],
+        active,
+      );
+      tabs.cx = Some(cx);
+
+      tabs.layout(Limits::strict(20, 5));
+
+      tabs.on(Event::NextFocus);
+      tabs.on(Event::NextFocus);
+
+      assert!(tabs.content_has_focus());
+
+      tabs.on(key(KeyCode::PageDown, KeyModifiers::CONTROL));
+
+      assert_eq!(active.get_untracked(), 1);
+      assert!(tabs.bar_focused);
+    });
+  }
+}
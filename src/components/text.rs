@@ -4,6 +4,7 @@ use crate::{
   IntoView,
   Limits,
   Size,
+  ThemeHandle,
   Widget,
   XY,
 };
@@ -15,10 +16,13 @@ pub struct Text {
   text: Cow<'static, str>,
   wrapped_text: Vec<String>,
   size: Size,
+  theme: ThemeHandle,
 }
 
 impl IntoView for Text {
-  fn into_view(self, cx: leptos_reactive::Scope) -> crate::View {
+  fn into_view(mut self, cx: leptos_reactive::Scope) -> crate::View {
+    self.theme = ThemeHandle::new(cx);
+
     CoreComponent::Text(self).into_view(cx)
   }
 }
@@ -63,6 +67,7 @@ impl Widget for Text {
 
   fn draw(&self, surface: &mut dyn DrawSurface) {
     let size = self.size;
+    let theme = self.theme.get(&self.name());
 
     for (i, line) in self
       .wrapped_text
@@ -70,7 +75,7 @@ impl Widget for Text {
       .enumerate()
       .take(size.height as usize)
     {
-      surface.write(XY { x: 0, y: i as u16 }, line);
+      surface.write_with_style(XY { x: 0, y: i as u16 }, line, theme.text);
     }
   }
 }
@@ -81,6 +86,7 @@ impl Text {
       text: text.into(),
       wrapped_text: vec![],
       size: Size::default(),
+      theme: ThemeHandle::default(),
     }
   }
 }
@@ -93,6 +99,24 @@ pub enum Alignment {
   End,
 }
 
+impl Alignment {
+  /// Clips `text` to `width` characters, and pads it with spaces so that it
+  /// is exactly `width` characters wide, aligned as specified.
+  pub fn align(self, text: &str, width: u16) -> String {
+    let text = super::clip_to_width(text, width);
+    let padding = width as usize - text.chars().count();
+
+    let (left, right) = match self {
+      Self::Start => (0, padding),
+      Self::Center => (padding / 2, padding - padding / 2),
+      Self::End => (padding, 0),
+    };
+
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+  }
+}
+
+#[cfg(test)]
 fn get_text_size(limits: Limits, text: &str) -> Size {
   let wrapped_text = textwrap::wrap(text, limits.max_width as usize);
 
@@ -221,12 +245,21 @@ mod tests {
     }
   }
 
+  mod alignment {
+    use super::*;
+
+    #[test]
+    fn pads_and_clips() {
+      assert_eq!(Alignment::Start.align("ab", 5), "ab   ");
+      assert_eq!(Alignment::Center.align("ab", 5), " ab  ");
+      assert_eq!(Alignment::End.align("ab", 5), "   ab");
+      assert_eq!(Alignment::End.align("abcdef", 3), "abc");
+    }
+  }
+
   mod widget {
     use super::*;
-    use crate::{
-      components::get_widget_output,
-      BufDrawSurface,
-    };
+    use crate::components::get_widget_output;
     use crossterm::{
       cursor::MoveTo,
       style::Print,
@@ -0,0 +1,766 @@
+use crate::{
+  cycle_leaf_focus,
+  executor::spawn_local,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  IntoView,
+  Limits,
+  Size,
+  ThemeHandle,
+  View,
+  Widget,
+  XY,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  MouseButton,
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalSet,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  future::Future,
+  pin::Pin,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Width of the guide line drawn for every level of nesting, and of the
+/// expand/collapse marker.
+const INDENT_WIDTH: u16 = 2;
+
+/// A node of a [`TreeView`], identified by its `key`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<K> {
+  pub key: K,
+  pub label: Cow<'static, str>,
+  /// Whether the node can be expanded. Its children are only requested
+  /// when it is first expanded.
+  pub has_children: bool,
+}
+
+impl<K> TreeNode<K> {
+  /// Creates a node without children.
+  pub fn leaf(key: K, label: impl Into<Cow<'static, str>>) -> Self {
+    Self {
+      key,
+      label: label.into(),
+      has_children: false,
+    }
+  }
+
+  /// Creates a node which can be expanded.
+  pub fn branch(key: K, label: impl Into<Cow<'static, str>>) -> Self {
+    Self {
+      key,
+      label: label.into(),
+      has_children: true,
+    }
+  }
+}
+
+/// A tree of nodes, one per line, drawn with guide lines connecting every
+/// node to its parent.
+///
+/// Nodes are expanded lazily: the children of a node are requested from
+/// the loader given to [`TreeView::children`] or
+/// [`TreeView::children_async`] the first time it is expanded, and are
+/// kept around once loaded.
+///
+/// While focused, Up, Down, PageUp, PageDown, Home and End move the
+/// selection, Right expands the selected node or moves to its first child,
+/// Left collapses it or moves to its parent, and Enter or Space toggle it.
+pub struct TreeView<K: 'static> {
+  nodes: Arc<Mutex<Vec<Node<K>>>>,
+  loader: Loader<K>,
+  selected: Option<RwSignal<Option<K>>>,
+  /// Index of the first visible row.
+  offset: usize,
+  /// The selected row when the tree was last scrolled to it, so that
+  /// scrolling with the mouse wheel isn't undone until the selection
+  /// changes.
+  scrolled_to: Option<usize>,
+  rows: Vec<Row<K>>,
+  size: Size,
+  focused: bool,
+  theme: ThemeHandle,
+}
+
+enum Loader<K> {
+  None,
+  Sync(ChildrenFn<K>),
+  Async(Box<dyn Fn(K) -> ChildrenFuture<K> + Send + Sync>),
+}
+
+type ChildrenFn<K> = Box<dyn Fn(&K) -> Vec<TreeNode<K>> + Send + Sync>;
+
+type ChildrenFuture<K> = Pin<Box<dyn Future<Output = Vec<TreeNode<K>>>>>;
+
+struct Node<K> {
+  node: TreeNode<K>,
+  expanded: bool,
+  children: Children<K>,
+}
+
+enum Children<K> {
+  NotLoaded,
+  Loading,
+  Loaded(Vec<Node<K>>),
+}
+
+/// A visible node, flattened out of the tree.
+struct Row<K> {
+  key: K,
+  label: Cow<'static, str>,
+  depth: usize,
+  /// Guide lines and connector drawn before the marker.
+  guides: String,
+  state: RowState,
+  parent: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RowState {
+  Leaf,
+  Collapsed,
+  Expanded,
+  Loading,
+}
+
+impl<K> From<TreeNode<K>> for Node<K> {
+  fn from(node: TreeNode<K>) -> Self {
+    Self {
+      node,
+      expanded: false,
+      children: Children::NotLoaded,
+    }
+  }
+}
+
+impl<K: fmt::Debug + 'static> fmt::Debug for TreeView<K> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("TreeView")
+      .field("selected", &self.selected)
+      .field("offset", &self.offset)
+      .field("rows", &self.rows.len())
+      .field("size", &self.size)
+      .field("focused", &self.focused)
+      .finish()
+  }
+}
+
+impl<K> Widget for TreeView<K>
+where
+  K: Clone + PartialEq + fmt::Debug + Send + Sync + 'static,
+{
+  fn name(&self) -> Cow<'static, str> {
+    "TreeView".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    self.rows = self.flatten();
+
+    let height = limits.max_height as usize;
+
+    // Keep the selection visible
+    if let Some(selected) = self.selected_row() {
+      if self.scrolled_to != Some(selected) {
+        if selected < self.offset {
+          self.offset = selected;
+        } else if height > 0 && selected >= self.offset + height {
+          self.offset = selected + 1 - height;
+        }
+
+        self.scrolled_to = Some(selected);
+      }
+    }
+
+    self.offset = self.offset.min(self.rows.len().saturating_sub(height));
+
+    let width = self
+      .rows
+      .iter()
+      .map(|row| row.width())
+      .max()
+      .unwrap_or_default()
+      .clamp(limits.min_width, limits.max_width);
+
+    let height = (self.rows.len() - self.offset)
+      .min(height)
+      .max(limits.min_height as usize);
+
+    self.size = Size {
+      width,
+      height: height as u16,
+    };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let selected = self.selected_row();
+    let theme = self.theme.get(&self.name());
+
+    let visible = self.rows.iter().enumerate().skip(self.offset);
+
+    for (y, (i, row)) in visible.take(self.size.height as usize).enumerate() {
+      let y = y as u16;
+      let is_selected = selected == Some(i);
+
+      surface.write_with_style(XY { x: 0, y }, &row.guides, theme.border);
+
+      let marker = match row.state {
+        RowState::Leaf if row.depth == 0 => "  ",
+        RowState::Leaf => "─ ",
+        RowState::Collapsed => "▸ ",
+        RowState::Expanded | RowState::Loading => "▾ ",
+      };
+
+      let x = row.guides.chars().count() as u16;
+
+      surface.write_with_style(
+        XY { x, y },
+        &format!("{marker}{}", row.label),
+        if is_selected && self.focused {
+          theme.focus
+        } else {
+          theme.text
+        },
+      );
+
+      if row.state == RowState::Loading {
+        surface.write_with_style(
+          XY {
+            x: x + INDENT_WIDTH + row.label.chars().count() as u16,
+            y,
+          },
+          " loading…",
+          theme.muted,
+        );
+      }
+    }
+  }
+
+  fn needs_focus(&self) -> Option<bool> {
+    Some(self.selected.is_some())
+  }
+
+  fn has_focus(&self) -> bool {
+    self.focused
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    let page = (self.size.height as usize).max(1);
+
+    match event {
+      Event::NextFocus | Event::PrevFocus => cycle_leaf_focus(self),
+      Event::Key(KeyEvent { code, .. }) if self.focused => {
+        self.rows = self.flatten();
+
+        if self.rows.is_empty() {
+          return EventHandlerResult::Bubble;
+        }
+
+        let last = self.rows.len() - 1;
+        let current = self.selected_row().unwrap_or_default();
+        let row = &self.rows[current];
+
+        let new = match code {
+          KeyCode::Up => current.saturating_sub(1),
+          KeyCode::Down => (current + 1).min(last),
+          KeyCode::PageUp => current.saturating_sub(page),
+          KeyCode::PageDown => (current + page).min(last),
+          KeyCode::Home => 0,
+          KeyCode::End => last,
+          KeyCode::Right => match row.state {
+            RowState::Collapsed => {
+              let key = row.key.clone();
+
+              self.set_expanded(&key, true);
+
+              current
+            }
+            RowState::Expanded
+              if self
+                .rows
+                .get(current + 1)
+                .is_some_and(|next| next.parent == Some(current)) =>
+            {
+              current + 1
+            }
+            _ => current,
+          },
+          KeyCode::Left => match row.state {
+            RowState::Expanded | RowState::Loading => {
+              let key = row.key.clone();
+
+              self.set_expanded(&key, false);
+
+              current
+            }
+            _ => row.parent.unwrap_or(current),
+          },
+          KeyCode::Enter | KeyCode::Char(' ') => {
+            let key = row.key.clone();
+
+            self.toggle(&key);
+
+            current
+          }
+          _ => return EventHandlerResult::Bubble,
+        };
+
+        self.select_row(new);
+
+        EventHandlerResult::Captured
+      }
+      Event::Mouse(MouseEvent {
+        kind, column, row, ..
+      }) if column < self.size.width && row < self.size.height => {
+        match kind {
+          MouseEventKind::Down(MouseButton::Left) => {
+            let index = self.offset + row as usize;
+
+            let Some(row) = self.rows.get(index) else {
+              return EventHandlerResult::Captured;
+            };
+
+            let marker_start = row.guides.chars().count() as u16;
+
+            // Clicking the marker toggles the node
+            if column >= marker_start && column < marker_start + INDENT_WIDTH {
+              let key = row.key.clone();
+
+              self.toggle(&key);
+            }
+
+            self.focus();
+            self.select_row(index);
+          }
+          MouseEventKind::ScrollUp => {
+            self.offset = self.offset.saturating_sub(1);
+          }
+          MouseEventKind::ScrollDown => {
+            self.offset = (self.offset + 1)
+              .min(self.rows.len().saturating_sub(self.size.height as usize));
+          }
+          _ => return EventHandlerResult::Bubble,
+        }
+
+        EventHandlerResult::Captured
+      }
+      _ => EventHandlerResult::Bubble,
+    }
+  }
+
+  fn focus(&mut self) {
+    self.focused = true;
+  }
+
+  fn blur(&mut self) {
+    self.focused = false;
+  }
+}
+
+impl<K> IntoView for TreeView<K>
+where
+  K: Clone + PartialEq + fmt::Debug + Send + Sync + 'static,
+{
+  fn into_view(mut self, cx: Scope) -> View {
+    self.theme = ThemeHandle::new(cx);
+
+    View::Widget(Arc::new(Mutex::new(self)))
+  }
+}
+
+impl<K> TreeView<K>
+where
+  K: Clone + PartialEq + fmt::Debug + Send + Sync + 'static,
+{
+  /// Creates a tree with the given top-level nodes.
+  pub fn new(roots: impl IntoIterator<Item = TreeNode<K>>) -> Self {
+    Self {
+      nodes: Arc::new(Mutex::new(roots.into_iter().map(Node::from).collect())),
+      loader: Loader::None,
+      selected: None,
+      offset: 0,
+      scrolled_to: None,
+      rows: vec![],
+      size: Size::default(),
+      focused: false,
+      theme: ThemeHandle::default(),
+    }
+  }
+
+  /// Loads the children of a node with `children(key)` when it is first
+  /// expanded.
+  pub fn children(
+    mut self,
+    children: impl Fn(&K) -> Vec<TreeNode<K>> + Send + Sync + 'static,
+  ) -> Self {
+    self.loader = Loader::Sync(Box::new(children));
+
+    self
+  }
+
+  /// Loads the children of a node in the background with the future
+  /// returned by `children(key)` when it is first expanded. The node is
+  /// shown as loading until the future completes.
+  pub fn children_async<Fut>(
+    mut self,
+    children: impl Fn(K) -> Fut + Send + Sync + 'static,
+  ) -> Self
+  where
+    Fut: Future<Output = Vec<TreeNode<K>>> + 'static,
+  {
+    self.loader = Loader::Async(Box::new(move |key| Box::pin(children(key))));
+
+    self
+  }
+
+  /// Tracks the key of the selected node in `selected`. The tree can only
+  /// be focused if a selection signal is set.
+  pub fn selected(mut self, selected: RwSignal<Option<K>>) -> Self {
+    self.selected = Some(selected);
+
+    self
+  }
+
+  /// Returns the index of the selected row, if it is visible.
+  fn selected_row(&self) -> Option<usize> {
+    let key = self.selected?.get_untracked()?;
+
+    self.rows.iter().position(|row| row.key == key)
+  }
+
+  fn select_row(&self, index: usize) {
+    if let (Some(selected), Some(row)) = (self.selected, self.rows.get(index)) {
+      selected.set(Some(row.key.clone()));
+    }
+  }
+
+  fn toggle(&mut self, key: &K) {
+    let expanded = find_node(&mut self.nodes.lock().unwrap(), key)
+      .is_some_and(|node| node.expanded);
+
+    self.set_expanded(key, !expanded);
+  }
+
+  /// Expands or collapses a node, loading its children if it is expanded
+  /// for the first time.
+  fn set_expanded(&mut self, key: &K, expanded: bool) {
+    let mut nodes = self.nodes.lock().unwrap();
+
+    let Some(node) = find_node(&mut nodes, key) else {
+      return;
+    };
+
+    if !node.node.has_children {
+      return;
+    }
+
+    node.expanded = expanded;
+
+    if expanded && matches!(node.children, Children::NotLoaded) {
+      node.children = match &self.loader {
+        Loader::None => Children::Loaded(vec![]),
+        Loader::Sync(children) => {
+          Children::Loaded(children(key).into_iter().map(Node::from).collect())
+        }
+        Loader::Async(children) => {
+          let future = children(key.clone());
+          let nodes = self.nodes.clone();
+          let key = key.clone();
+
+          spawn_local(async move {
+            let children = future.await;
+
+            if let Some(node) = find_node(&mut nodes.lock().unwrap(), &key) {
+              node.children = Children::Loaded(
+                children.into_iter().map(Node::from).collect(),
+              );
+            }
+          });
+
+          Children::Loading
+        }
+      };
+    }
+
+    drop(nodes);
+
+    self.rows = self.flatten();
+  }
+
+  /// Flattens the expanded part of the tree into rows.
+  fn flatten(&self) -> Vec<Row<K>> {
+    fn push_rows<K: Clone>(
+      nodes: &[Node<K>],
+      depth: usize,
+      prefix: &str,
+      parent: Option<usize>,
+      rows: &mut Vec<Row<K>>,
+    ) {
+      for (i, node) in nodes.iter().enumerate() {
+        let is_last = i + 1 == nodes.len();
+
+        let guides = if depth == 0 {
+          String::new()
+        } else if is_last {
+          format!("{prefix}└─")
+        } else {
+          format!("{prefix}├─")
+        };
+
+        let state = match (&node.children, node.expanded) {
+          _ if !node.node.has_children => RowState::Leaf,
+          (_, false) => RowState::Collapsed,
+          (Children::Loaded(_), true) => RowState::Expanded,
+          (_, true) => RowState::Loading,
+        };
+
+        let index = rows.len();
+
+        rows.push(Row {
+          key: node.node.key.clone(),
+          label: node.node.label.clone(),
+          depth,
+          guides,
+          state,
+          parent,
+        });
+
+        if let (Children::Loaded(children), true) =
+          (&node.children, node.expanded)
+        {
+          let prefix = match (depth, is_last) {
+            (0, _) => String::new(),
+            (_, true) => format!("{prefix}  "),
+            (_, false) => format!("{prefix}│ "),
+          };
+
+          push_rows(children, depth + 1, &prefix, Some(index), rows);
+        }
+      }
+    }
+
+    let mut rows = vec![];
+
+    push_rows(&self.nodes.lock().unwrap(), 0, "", None, &mut rows);
+
+    rows
+  }
+}
+
+impl<K> Row<K> {
+  fn width(&self) -> u16 {
+    let mut width = self.guides.chars().count()
+      + INDENT_WIDTH as usize
+      + self.label.chars().count();
+
+    if self.state == RowState::Loading {
+      width += " loading…".chars().count();
+    }
+
+    width as u16
+  }
+}
+
+fn find_node<'a, K: PartialEq>(
+  nodes: &'a mut [Node<K>],
+  key: &K,
+) -> Option<&'a mut Node<K>> {
+  for node in nodes {
+    if node.node.key == *key {
+      return Some(node);
+    }
+
+    if let Children::Loaded(children) = &mut node.children {
+      if let Some(node) = find_node(children, key) {
+        return Some(node);
+      }
+    }
+  }
+
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::get_widget_output,
+    executor::poll_tasks,
+  };
+  use crossterm::{
+    cursor::MoveTo,
+    event::KeyModifiers,
+    style::{
+      Color,
+      Print,
+      ResetColor,
+      SetForegroundColor,
+    },
+  };
+  use leptos_reactive::{
+    create_runtime,
+    create_rw_signal,
+    run_scope,
+  };
+
+  fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn files(path: &&'static str) -> Vec<TreeNode<&'static str>> {
+    match *path {
+      "src" => vec![
+        TreeNode::branch("src/components", "components"),
+        TreeNode::leaf("src/lib.rs", "lib.rs"),
+      ],
+      "src/components" => vec![TreeNode::leaf("src/components/a.rs", "a.rs")],
+      _ => vec![],
+    }
+  }
+
+  fn tree() -> TreeView<&'static str> {
+    TreeView::new([
+      TreeNode::branch("src", "src"),
+      TreeNode::leaf("README", "README"),
+    ])
+    .children(files)
+  }
+
+  fn rows(tree: &TreeView<&'static str>) -> Vec<String> {
+    tree
+      .flatten()
+      .iter()
+      .map(|row| format!("{}{:?} {}", row.guides, row.state, row.label))
+      .collect::<Vec<_>>()
+  }
+
+  #[test]
+  fn draws_guide_lines() {
+    let mut tree = tree();
+
+    tree.set_expanded(&"src", true);
+    tree.set_expanded(&"src/components", true);
+
+    assert_eq!(
+      rows(&tree),
+      [
+        "Expanded src",
+        "├─Expanded components",
+        "│ └─Leaf a.rs",
+        "└─Leaf lib.rs",
+        "Leaf README",
+      ]
+    );
+
+    tree.set_expanded(&"src/components", false);
+
+    let (size, output) = get_widget_output(tree, (20, 5), (0, 0));
+
+    assert_eq!(size, (14, 4).into());
+
+    assert_eq!(
+      output,
+      commands![
+        MoveTo(0, 0),
+        Print("▾ src"),
+        MoveTo(0, 1),
+        Print(format!("{}├─", SetForegroundColor(Color::DarkGrey))),
+        Print(ResetColor),
+        MoveTo(2, 1),
+        Print("▸ components"),
+        MoveTo(0, 2),
+        Print(format!("{}└─", SetForegroundColor(Color::DarkGrey))),
+        Print(ResetColor),
+        MoveTo(2, 2),
+        Print("─ lib.rs"),
+        MoveTo(0, 3),
+        Print("  README"),
+      ]
+    );
+  }
+
+  #[test]
+  fn left_and_right_collapse_and_expand() {
+    run_scope(create_runtime(), |cx| {
+      let selected = create_rw_signal(cx, Some("src"));
+      let mut tree = tree().selected(selected);
+
+      tree.layout(Limits::strict(20, 5));
+      tree.focus();
+
+      tree.on(key(KeyCode::Right));
+
+      assert_eq!(rows(&tree).len(), 4);
+      assert_eq!(selected.get_untracked(), Some("src"));
+
+      tree.on(key(KeyCode::Right));
+
+      assert_eq!(selected.get_untracked(), Some("src/components"));
+
+      tree.on(key(KeyCode::Left));
+
+      assert_eq!(selected.get_untracked(), Some("src"));
+
+      tree.on(key(KeyCode::Left));
+
+      assert_eq!(rows(&tree), ["Collapsed src", "Leaf README"]);
+
+      tree.on(key(KeyCode::Down));
+
+      assert_eq!(selected.get_untracked(), Some("README"));
+    });
+  }
+
+  #[test]
+  fn loads_children_asynchronously() {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let rx = Mutex::new(Some(rx));
+
+    let mut tree =
+      TreeView::new([TreeNode::branch(0, "root")]).children_async(move |_| {
+        let rx = rx.lock().unwrap().take().unwrap();
+
+        async move { rx.await.unwrap() }
+      });
+
+    tree.set_expanded(&0, true);
+    poll_tasks();
+
+    assert_eq!(
+      tree
+        .flatten()
+        .iter()
+        .map(|row| row.state)
+        .collect::<Vec<_>>(),
+      [RowState::Loading]
+    );
+
+    tx.send(vec![TreeNode::leaf(1, "child")]).unwrap();
+    poll_tasks();
+
+    assert_eq!(
+      tree
+        .flatten()
+        .iter()
+        .map(|row| row.state)
+        .collect::<Vec<_>>(),
+      [RowState::Expanded, RowState::Leaf]
+    );
+  }
+}
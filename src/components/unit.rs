@@ -1,7 +1,6 @@
 use super::CoreComponent;
 use crate::{
   IntoView,
-  View,
   Widget,
 };
 
@@ -17,7 +16,7 @@ impl Widget for Unit {
     limits.min_size()
   }
 
-  fn draw(&self, surface: &mut dyn crate::DrawSurface) {}
+  fn draw(&self, _surface: &mut dyn crate::DrawSurface) {}
 }
 
 impl IntoView for Unit {
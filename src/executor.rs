@@ -0,0 +1,222 @@
+//! A minimal single-threaded executor, driven by the event loop in
+//! [`run`](crate::run), so widgets can wait on futures without blocking
+//! the UI.
+
+use std::{
+  cell::RefCell,
+  future::Future,
+  pin::Pin,
+  sync::{
+    atomic::{
+      AtomicBool,
+      Ordering,
+    },
+    Arc,
+    Mutex,
+  },
+  task::{
+    Context,
+    Wake,
+    Waker,
+  },
+  thread,
+  time::Duration,
+};
+
+thread_local! {
+  static TASKS: RefCell<Vec<Task>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Called whenever a task is spawned or woken, from whichever thread woke
+/// it, or a render is requested.
+static ON_WAKE: Mutex<Option<Box<dyn Fn() + Send>>> = Mutex::new(None);
+
+/// Set by [`request_render`] until the event loop next renders.
+static RENDER_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+struct Task {
+  future: Pin<Box<dyn Future<Output = ()>>>,
+  waker: Arc<TaskWaker>,
+}
+
+struct TaskWaker {
+  woken: AtomicBool,
+}
+
+impl Wake for TaskWaker {
+  fn wake(self: Arc<Self>) {
+    self.wake_by_ref();
+  }
+
+  fn wake_by_ref(self: &Arc<Self>) {
+    if !self.woken.swap(true, Ordering::SeqCst) {
+      notify();
+    }
+  }
+}
+
+fn notify() {
+  if let Some(on_wake) = &*ON_WAKE.lock().unwrap() {
+    on_wake();
+  }
+}
+
+/// Sets the callback run whenever a task is spawned or woken, or a render
+/// is requested, so the event loop can block until there's work to do.
+pub(crate) fn on_wake(f: impl Fn() + Send + 'static) {
+  *ON_WAKE.lock().unwrap() = Some(Box::new(f));
+}
+
+/// Makes the event loop redraw the screen, waking it if it is waiting for
+/// events.
+pub(crate) fn request_render() {
+  RENDER_REQUESTED.store(true, Ordering::SeqCst);
+
+  notify();
+}
+
+/// Returns `true` if a render was requested since this was last called.
+pub(crate) fn take_render_request() -> bool {
+  RENDER_REQUESTED.swap(false, Ordering::SeqCst)
+}
+
+/// Removes the callback set with [`on_wake`].
+pub(crate) fn clear_on_wake() {
+  *ON_WAKE.lock().unwrap() = None;
+}
+
+/// Spawns a future on the current thread. It is polled by the event loop
+/// whenever it is woken, after which the screen is redrawn.
+pub fn spawn_local(future: impl Future<Output = ()> + 'static) {
+  TASKS.with(|tasks| {
+    tasks.borrow_mut().push(Task {
+      future: Box::pin(future),
+      waker: Arc::new(TaskWaker {
+        woken: AtomicBool::new(true),
+      }),
+    })
+  });
+
+  notify();
+}
+
+/// Waits for `duration` without blocking the event loop. The waiting is
+/// done by a thread, which wakes the task once `duration` has elapsed.
+pub(crate) async fn sleep(duration: Duration) {
+  let (tx, rx) = tokio::sync::oneshot::channel();
+
+  thread::spawn(move || {
+    thread::sleep(duration);
+
+    let _ = tx.send(());
+  });
+
+  let _ = rx.await;
+}
+
+/// Polls every task which has been woken since it was last polled.
+/// Returns `true` if any task was polled.
+pub(crate) fn poll_tasks() -> bool {
+  // Tasks are taken out so that polling them can spawn new ones
+  let tasks = TASKS.with(|tasks| std::mem::take(&mut *tasks.borrow_mut()));
+
+  let mut pending = Vec::with_capacity(tasks.len());
+  let mut polled = false;
+
+  for mut task in tasks {
+    if !task.waker.woken.swap(false, Ordering::SeqCst) {
+      pending.push(task);
+
+      continue;
+    }
+
+    polled = true;
+
+    let waker = Waker::from(task.waker.clone());
+
+    if task
+      .future
+      .as_mut()
+      .poll(&mut Context::from_waker(&waker))
+      .is_pending()
+    {
+      pending.push(task);
+    }
+  }
+
+  TASKS.with(|tasks| {
+    let mut tasks = tasks.borrow_mut();
+
+    pending.append(&mut tasks);
+
+    *tasks = pending;
+  });
+
+  polled
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::mpsc,
+    thread,
+    time::Duration,
+  };
+
+  fn has_pending_tasks() -> bool {
+    TASKS.with(|tasks| !tasks.borrow().is_empty())
+  }
+
+  #[test]
+  fn polls_tasks_until_they_complete() {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let result = Rc::new(Cell::new(None));
+
+    spawn_local({
+      let result = result.clone();
+
+      async move {
+        result.set(rx.await.ok());
+      }
+    });
+
+    assert!(poll_tasks());
+    assert!(has_pending_tasks());
+    assert!(!poll_tasks());
+
+    tx.send(42).unwrap();
+
+    assert!(poll_tasks());
+    assert!(!has_pending_tasks());
+    assert_eq!(result.get(), Some(42));
+  }
+
+  #[test]
+  fn notifies_when_woken_from_another_thread() {
+    let (wakeups_tx, wakeups) = mpsc::channel();
+
+    on_wake(move || {
+      let _ = wakeups_tx.send(());
+    });
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    spawn_local(async move {
+      let _ = rx.await;
+    });
+
+    assert!(poll_tasks());
+
+    // Tests on other threads may have spawned tasks in the meantime
+    while wakeups.try_recv().is_ok() {}
+
+    thread::spawn(move || tx.send(()).unwrap()).join().unwrap();
+
+    assert!(wakeups.recv_timeout(Duration::from_secs(1)).is_ok());
+    assert!(poll_tasks());
+    assert!(!has_pending_tasks());
+  }
+}
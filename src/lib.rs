@@ -1,32 +1,34 @@
 #![feature(panic_update_hook, closure_track_caller)]
-#![allow(warnings)]
 
+mod animation;
+mod command;
 mod components;
+mod executor;
+mod overlay;
+mod resource;
+mod router;
+mod shortcut;
 mod surface;
+mod theme;
+mod toast;
 mod widget;
 
+pub use animation::AnimationGuard;
+pub use command::*;
 pub use components::*;
 use crossterm::{
   cursor::{
     Hide,
-    MoveTo,
-    MoveToNextLine,
-    Show,
+    Show as ShowCursor,
   },
   event::{
     DisableMouseCapture,
     EnableMouseCapture,
-    Event,
     KeyCode,
     KeyEvent,
+    KeyEventKind,
     KeyModifiers,
   },
-  style::{
-    Attribute,
-    Color,
-    Print,
-    Stylize,
-  },
   terminal::{
     BeginSynchronizedUpdate,
     Clear,
@@ -37,21 +39,36 @@ use crossterm::{
   },
   QueueableCommand,
 };
-use leptos_reactive::Scope;
+pub use executor::spawn_local;
+use leptos_reactive::{
+  create_effect,
+  provide_context,
+  Scope,
+  SignalWith,
+};
+pub use overlay::*;
+pub use resource::*;
+pub use router::*;
+pub use shortcut::*;
 use std::{
   borrow::Cow,
   fmt,
-  io::{
-    self,
-    BufWriter,
-    Write,
-  },
+  io::Write,
   sync::{
+    atomic::{
+      AtomicBool,
+      Ordering,
+    },
+    mpsc,
     Arc,
     Mutex,
   },
+  thread,
+  time::Duration,
 };
 pub use surface::*;
+pub use theme::*;
+pub use toast::*;
 pub use widget::*;
 
 #[macro_use]
@@ -133,7 +150,7 @@ impl fmt::Display for View {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::CoreComponent(component) => component.fmt(f),
-      Self::Widget(arg0) => todo!(),
+      Self::Widget(_) => todo!(),
     }
   }
 }
@@ -165,6 +182,41 @@ impl Widget for View {
       View::CoreComponent(component) => component.draw(surface),
     }
   }
+
+  fn needs_focus(&self) -> Option<bool> {
+    match self {
+      View::Widget(widget) => widget.lock().unwrap().needs_focus(),
+      View::CoreComponent(component) => component.needs_focus(),
+    }
+  }
+
+  fn has_focus(&self) -> bool {
+    match self {
+      View::Widget(widget) => widget.lock().unwrap().has_focus(),
+      View::CoreComponent(component) => component.has_focus(),
+    }
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    match self {
+      View::Widget(widget) => widget.lock().unwrap().on(event),
+      View::CoreComponent(component) => component.on(event),
+    }
+  }
+
+  fn focus(&mut self) {
+    match self {
+      View::Widget(widget) => widget.lock().unwrap().focus(),
+      View::CoreComponent(component) => component.focus(),
+    }
+  }
+
+  fn blur(&mut self) {
+    match self {
+      View::Widget(widget) => widget.lock().unwrap().blur(),
+      View::CoreComponent(component) => component.blur(),
+    }
+  }
 }
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
@@ -257,13 +309,13 @@ impl Limits {
 
 impl std::cmp::PartialOrd for Size {
   fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-    Some(self.linear_length().cmp(&other.linear_length()))
+    Some(self.cmp(other))
   }
 }
 
 impl std::cmp::Ord for Size {
   fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-    self.cmp(other)
+    self.linear_length().cmp(&other.linear_length())
   }
 }
 
@@ -302,6 +354,34 @@ impl From<(u16, u16)> for XY {
   }
 }
 
+/// A rectangular area of the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+  pub top_left: XY,
+  pub size: Size,
+}
+
+impl Rect {
+  /// Returns `true` if the absolute position `at` is inside this [`Rect`].
+  pub fn contains(self, at: XY) -> bool {
+    at.x >= self.top_left.x
+      && at.y >= self.top_left.y
+      && at.x - self.top_left.x < self.size.width
+      && at.y - self.top_left.y < self.size.height
+  }
+}
+
+/// How often the thread reading terminal events checks whether the event
+/// loop has stopped, while no events come in.
+const READ_STOP_INTERVAL: Duration = Duration::from_millis(100);
+
+/// What the event loop was woken up by.
+enum Wakeup {
+  Terminal(crossterm::event::Event),
+  /// A task was woken, or a render was requested.
+  Executor,
+}
+
 #[track_caller]
 pub fn run<V: IntoView>(f: impl FnOnce(Scope) -> V + 'static) {
   // Update the panic hook to make sure we leave the terminal in a
@@ -309,13 +389,18 @@ pub fn run<V: IntoView>(f: impl FnOnce(Scope) -> V + 'static) {
   std::panic::update_hook(|prev, info| {
     use std::io::Write;
 
+    // Shown by the error boundary instead
+    if components::is_catching_panics() {
+      return;
+    }
+
     cleanup_screen();
 
     prev(info);
 
     let mut file = std::fs::File::create("panic.txt").unwrap();
 
-    write!(file, "{}", info);
+    let _ = write!(file, "{}", info);
   });
 
   let runtime = leptos_reactive::create_runtime();
@@ -326,58 +411,168 @@ pub fn run<V: IntoView>(f: impl FnOnce(Scope) -> V + 'static) {
     runtime,
     #[track_caller]
     move |cx| {
+      let theme = provide_theme(cx, Theme::default());
+      let component_styles =
+        provide_component_styles(cx, ComponentStyles::new());
+
+      // Widgets read the theme when drawn, so switching it only needs the
+      // screen to be redrawn
+      create_effect(cx, move |_| {
+        theme.with(|_| {});
+        component_styles.with(|_| {});
+
+        executor::request_render();
+      });
+
+      let overlays = Overlays::default();
+
+      provide_context(cx, overlays.clone());
+      provide_context(cx, Toasts::new(overlays.clone(), ThemeHandle::new(cx)));
+
+      let shortcuts = Shortcuts::default();
+
+      provide_context(cx, shortcuts.clone());
+      provide_context(cx, Commands::new(shortcuts.clone()));
+
       let mut view = f(cx).into_view(cx);
 
       let mut surface = BufDrawSurface::default();
 
-      render_view(&mut surface, &mut view);
+      if let Ok((width, height)) = crossterm::terminal::size() {
+        surface.resize(Size { width, height });
+      }
 
-      loop {
-        match crossterm::event::read().unwrap() {
-          Event::Key(KeyEvent {
-            code,
-            modifiers,
-            state,
-            ..
-          }) => match code {
-            KeyCode::Char('c') if modifiers == KeyModifiers::CONTROL => {
+      render_view(&mut surface, &mut view, &overlays);
+
+      let (wakeups_tx, wakeups) = mpsc::channel();
+
+      executor::on_wake({
+        let wakeups = wakeups_tx.clone();
+
+        move || {
+          let _ = wakeups.send(Wakeup::Executor);
+        }
+      });
+
+      let stopped = Arc::new(AtomicBool::new(false));
+
+      // Terminal events are read on their own thread, so the loop can also
+      // be woken by tasks, which may be woken from any thread. Events are
+      // only read once available, so none are taken from after the loop
+      // has stopped
+      let reader = thread::spawn({
+        let stopped = stopped.clone();
+
+        move || {
+          while !stopped.load(Ordering::SeqCst) {
+            if !crossterm::event::poll(READ_STOP_INTERVAL).unwrap_or(false) {
+              continue;
+            }
+
+            let Ok(event) = crossterm::event::read() else {
               break;
+            };
+
+            if wakeups_tx.send(Wakeup::Terminal(event)).is_err() {
+              break;
+            }
+          }
+        }
+      });
+
+      loop {
+        let mut needs_render = false;
+
+        let is_animating = animation::is_animating();
+
+        // Without animations there's nothing to do until the next terminal
+        // event or woken task, so we can block on it
+        let wakeup = if is_animating {
+          wakeups.recv_timeout(animation::FRAME_INTERVAL).ok()
+        } else {
+          wakeups.recv().ok()
+        };
+
+        if let Some(Wakeup::Terminal(event)) = wakeup {
+          let event = match event {
+            crossterm::event::Event::Key(
+              key @ KeyEvent {
+                code,
+                modifiers,
+                kind,
+                ..
+              },
+            ) => match code {
+              KeyCode::Char('c') if modifiers == KeyModifiers::CONTROL => {
+                break;
+              }
+              _ if kind == KeyEventKind::Release => None,
+              KeyCode::Tab => Some(Event::NextFocus),
+              KeyCode::BackTab => Some(Event::PrevFocus),
+              _ => Some(Event::Key(key)),
+            },
+            crossterm::event::Event::Mouse(mouse) => Some(Event::Mouse(mouse)),
+            crossterm::event::Event::Resize(width, height) => {
+              surface.resize(Size { width, height });
+
+              needs_render = true;
+
+              None
             }
-            _ => {}
-          },
-          Event::Resize(width, height) => {
-            surface.resize(Size { width, height });
+            _ => None,
+          };
 
-            render_view(&mut surface, &mut view);
+          if let Some(event) = event {
+            if overlays.on(&event).is_none() && shortcuts.on(&event).is_none() {
+              dispatch_event(&mut view, event);
+            }
+
+            needs_render = true;
           }
-          _ => {}
+        }
+
+        needs_render |= executor::poll_tasks() || is_animating;
+        needs_render |= executor::take_render_request();
+
+        if needs_render {
+          render_view(&mut surface, &mut view, &overlays);
         }
       }
+
+      stopped.store(true, Ordering::SeqCst);
+
+      let _ = reader.join();
+
+      executor::clear_on_wake();
     },
   );
 
+  disposer.dispose();
+
   cleanup_screen();
 }
 
 fn setup_screen() {
   let mut stdout = std::io::stdout();
 
-  crossterm::terminal::enable_raw_mode();
+  crossterm::terminal::enable_raw_mode().unwrap();
 
   stdout
     .queue(EnterAlternateScreen)
     .unwrap()
     .queue(EnableMouseCapture)
     .unwrap()
-    .flush();
+    .flush()
+    .unwrap();
 }
 
 fn cleanup_screen() {
   let mut stdout = std::io::stdout();
 
-  crossterm::terminal::disable_raw_mode();
+  // Errors are ignored, as this also runs from the panic hook
+  let _ = crossterm::terminal::disable_raw_mode();
 
-  stdout
+  let _ = stdout
     .queue(EndSynchronizedUpdate)
     .unwrap()
     .queue(Clear(ClearType::All))
@@ -386,13 +581,41 @@ fn cleanup_screen() {
     .unwrap()
     .queue(DisableMouseCapture)
     .unwrap()
-    .queue(Show)
+    .queue(ShowCursor)
     .unwrap()
     .flush();
 }
 
+/// Sends an event to the root view, resolving batched and mapped events
+/// which bubble all the way up.
+fn dispatch_event(view: &mut View, event: Event) {
+  let is_focus_event = matches!(event, Event::NextFocus | Event::PrevFocus);
+
+  if let Event::Batch(events) = event {
+    for event in events {
+      dispatch_event(view, event);
+    }
+
+    return;
+  }
+
+  match view.on(event.clone()) {
+    EventHandlerResult::Captured => {}
+    // Focus left the last focusable widget, so wrap around to the first
+    EventHandlerResult::Bubble if is_focus_event && !view.has_focus() => {
+      view.on(event);
+    }
+    EventHandlerResult::Bubble => {}
+    EventHandlerResult::Mapped(event) => dispatch_event(view, event),
+  }
+}
+
 #[track_caller]
-fn render_view(surface: &mut StdoutDrawSurface, view: &mut View) {
+fn render_view(
+  surface: &mut StdoutDrawSurface,
+  view: &mut View,
+  overlays: &Overlays,
+) {
   let limits = surface.size().into_strict_limits();
 
   surface.size = limits.max_size();
@@ -415,7 +638,15 @@ fn render_view(surface: &mut StdoutDrawSurface, view: &mut View) {
     .queue(Hide)
     .unwrap();
 
-  view.draw(surface);
+  if overlays.has_modal() {
+    view.draw(&mut DimmedDrawSurface::new(surface));
+  } else {
+    view.draw(surface);
+  }
+
+  overlays.draw(surface);
+
+  surface.buf.queue(EndSynchronizedUpdate).unwrap();
 
   surface.buf.flush().unwrap();
 }
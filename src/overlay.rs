@@ -0,0 +1,478 @@
+use crate::{
+  ArcWidget,
+  DimmedDrawSurface,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  Limits,
+  Rect,
+  Size,
+  XY,
+};
+use crossterm::event::{
+  MouseEvent,
+  MouseEventKind,
+};
+use leptos_reactive::{
+  use_context,
+  Scope,
+};
+use std::sync::{
+  Arc,
+  Mutex,
+};
+
+/// Identifies a widget pushed onto the [`Overlays`] layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OverlayId(usize);
+
+/// Where an overlay is positioned on the screen.
+#[derive(Clone, Debug)]
+pub enum Placement {
+  /// The top-left corner of the overlay is placed at the given absolute
+  /// position.
+  At(XY),
+  /// The overlay is centered on the screen.
+  Centered,
+  /// The overlay is placed in a corner of the screen.
+  Corner(Corner),
+  /// The top-left corner of the overlay is placed at the given absolute
+  /// position, or it is moved left and up as much as needed for the overlay
+  /// to fit on the screen.
+  Near(XY),
+  /// The overlay is placed right below the anchor, or above it if there
+  /// isn't enough room below. The anchor is usually the [`Rect`] a widget
+  /// last drew itself into, see [`DrawSurface::top_left`].
+  Below(Arc<Mutex<Rect>>),
+  /// Like [`Placement::Near`], at the top-left corner of the anchor, which
+  /// is usually the [`Rect`] a widget last drew itself into.
+  Over(Arc<Mutex<Rect>>),
+}
+
+/// A corner of the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+  TopLeft,
+  #[default]
+  TopRight,
+  BottomLeft,
+  BottomRight,
+}
+
+#[derive(Debug)]
+struct Overlay {
+  id: OverlayId,
+  widget: ArcWidget,
+  placement: Placement,
+  /// Whether the overlay blocks interaction with everything below it.
+  modal: bool,
+  rect: Rect,
+}
+
+/// A layer of widgets which are drawn above the main view tree, after it,
+/// and which are not clipped by the area of the widget that opened them.
+///
+/// The layer is provided through context by [`run`](crate::run), and can be
+/// retrieved with [`use_overlays`].
+#[derive(Clone, Debug, Default)]
+pub struct Overlays {
+  inner: Arc<Mutex<OverlaysInner>>,
+}
+
+#[derive(Debug, Default)]
+struct OverlaysInner {
+  next_id: usize,
+  /// Overlays in drawing order, the last being the topmost.
+  overlays: Vec<Overlay>,
+}
+
+impl Overlays {
+  /// Pushes a widget on top of all other overlays.
+  pub fn push(&self, widget: ArcWidget, placement: Placement) -> OverlayId {
+    self.push_overlay(widget, placement, false)
+  }
+
+  /// Pushes a widget centered on top of all other overlays, which is the
+  /// only one receiving events until it is removed, and with everything
+  /// below it dimmed.
+  ///
+  /// Focus events wrap around within the modal overlay, and all key events
+  /// are sent to it, whether it has focus or not.
+  pub fn push_modal(&self, widget: ArcWidget) -> OverlayId {
+    self.push_overlay(widget, Placement::Centered, true)
+  }
+
+  fn push_overlay(
+    &self,
+    widget: ArcWidget,
+    placement: Placement,
+    modal: bool,
+  ) -> OverlayId {
+    let mut inner = self.inner.lock().unwrap();
+
+    let id = OverlayId(inner.next_id);
+
+    inner.next_id += 1;
+
+    inner.overlays.push(Overlay {
+      id,
+      widget,
+      placement,
+      modal,
+      rect: Rect::default(),
+    });
+
+    id
+  }
+
+  /// Removes an overlay. Does nothing if it was already removed.
+  pub fn remove(&self, id: OverlayId) {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .overlays
+      .retain(|overlay| overlay.id != id);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.inner.lock().unwrap().overlays.is_empty()
+  }
+
+  /// Returns `true` if any modal overlay is open.
+  pub fn has_modal(&self) -> bool {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .overlays
+      .iter()
+      .any(|overlay| overlay.modal)
+  }
+
+  /// Lays out and draws every overlay, bottom-most first, on a surface
+  /// covering the whole screen. Overlays below the topmost modal overlay
+  /// are dimmed.
+  pub(crate) fn draw(&self, surface: &mut dyn DrawSurface) {
+    let screen = surface.size();
+
+    let overlays = self
+      .inner
+      .lock()
+      .unwrap()
+      .overlays
+      .iter()
+      .map(|overlay| {
+        (
+          overlay.id,
+          overlay.widget.clone(),
+          overlay.placement.clone(),
+          overlay.modal,
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let topmost_modal = overlays.iter().rposition(|(.., modal)| *modal);
+
+    for (i, (id, widget, placement, _)) in overlays.into_iter().enumerate() {
+      let is_dimmed = topmost_modal.is_some_and(|modal| i < modal);
+
+      let mut widget = widget.lock().unwrap();
+
+      let rect = place(&mut *widget, &placement, screen);
+
+      if rect.size.width == 0 || rect.size.height == 0 {
+        continue;
+      }
+
+      surface.shrink(
+        rect.top_left,
+        rect.size,
+        Box::new(|surface| {
+          surface.clear();
+
+          if is_dimmed {
+            widget.draw(&mut DimmedDrawSurface::new(surface));
+          } else {
+            widget.draw(surface);
+          }
+        }),
+      );
+
+      if let Some(overlay) = self
+        .inner
+        .lock()
+        .unwrap()
+        .overlays
+        .iter_mut()
+        .find(|overlay| overlay.id == id)
+      {
+        overlay.rect = rect;
+      }
+    }
+  }
+
+  /// Offers an event to the overlays, topmost first. Returns [`None`] if
+  /// no overlay was interested in it, in which case it should be sent to
+  /// the main view tree.
+  ///
+  /// Mouse events landing on an overlay never reach the widgets beneath it,
+  /// and key events are only sent to overlays which have focus. Pressing a
+  /// mouse button outside of an overlay which has focus blurs it. No event
+  /// reaches anything below a modal overlay.
+  pub(crate) fn on(&self, event: &Event) -> Option<EventHandlerResult> {
+    let overlays = self
+      .inner
+      .lock()
+      .unwrap()
+      .overlays
+      .iter()
+      .rev()
+      .map(|overlay| (overlay.widget.clone(), overlay.rect, overlay.modal))
+      .collect::<Vec<_>>();
+
+    for (widget, rect, modal) in overlays {
+      match event {
+        Event::Mouse(_) => {
+          if let Some(event) = event.relative_to(rect.top_left, rect.size) {
+            return Some(widget.lock().unwrap().on(event));
+          }
+
+          if let Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(_),
+            ..
+          }) = event
+          {
+            let mut widget = widget.lock().unwrap();
+
+            if widget.has_focus() && !modal {
+              widget.blur();
+            }
+          }
+        }
+        Event::Key(_) => {
+          let mut widget = widget.lock().unwrap();
+
+          if widget.has_focus() || modal {
+            match widget.on(event.clone()) {
+              EventHandlerResult::Bubble => {}
+              result => return Some(result),
+            }
+          }
+        }
+        Event::NextFocus | Event::PrevFocus if modal => {
+          let mut widget = widget.lock().unwrap();
+
+          // Focus left the last focusable widget, so wrap around to the
+          // first
+          if let EventHandlerResult::Bubble = widget.on(event.clone()) {
+            widget.on(event.clone());
+          }
+        }
+        _ => {}
+      }
+
+      if modal {
+        return Some(EventHandlerResult::Captured);
+      }
+    }
+
+    None
+  }
+}
+
+/// Gets the [`Overlays`] layer provided by [`run`](crate::run).
+///
+/// # Panics
+/// Panics if called outside of [`run`](crate::run) without an [`Overlays`]
+/// layer having been provided through context.
+#[track_caller]
+pub fn use_overlays(cx: Scope) -> Overlays {
+  use_context(cx).expect("`Overlays` to be provided through context")
+}
+
+/// Lays out the overlay and returns the area it should be drawn in.
+fn place(
+  widget: &mut dyn crate::Widget,
+  placement: &Placement,
+  screen: Size,
+) -> Rect {
+  match placement {
+    Placement::At(top_left) => {
+      let top_left = XY {
+        x: top_left.x.min(screen.width),
+        y: top_left.y.min(screen.height),
+      };
+
+      let size = widget.layout(Limits {
+        max_width: screen.width - top_left.x,
+        max_height: screen.height - top_left.y,
+        ..Default::default()
+      });
+
+      Rect { top_left, size }
+    }
+    Placement::Centered => {
+      let size = widget.layout(Limits {
+        max_width: screen.width,
+        max_height: screen.height,
+        ..Default::default()
+      });
+
+      Rect {
+        top_left: XY {
+          x: (screen.width - size.width) / 2,
+          y: (screen.height - size.height) / 2,
+        },
+        size,
+      }
+    }
+    Placement::Near(at) => near(widget, *at, screen),
+    Placement::Over(anchor) => {
+      let at = anchor.lock().unwrap().top_left;
+
+      near(widget, at, screen)
+    }
+    Placement::Corner(corner) => {
+      let size = widget.layout(Limits {
+        max_width: screen.width,
+        max_height: screen.height,
+        ..Default::default()
+      });
+
+      let x = match corner {
+        Corner::TopLeft | Corner::BottomLeft => 0,
+        Corner::TopRight | Corner::BottomRight => screen.width - size.width,
+      };
+
+      let y = match corner {
+        Corner::TopLeft | Corner::TopRight => 0,
+        Corner::BottomLeft | Corner::BottomRight => screen.height - size.height,
+      };
+
+      Rect {
+        top_left: XY { x, y },
+        size,
+      }
+    }
+    Placement::Below(anchor) => {
+      let anchor = *anchor.lock().unwrap();
+
+      let x = anchor.top_left.x.min(screen.width);
+      let below = (anchor.top_left.y + anchor.size.height).min(screen.height);
+      let space_below = screen.height - below;
+      let space_above = anchor.top_left.y.min(screen.height);
+
+      let limits = |max_height| Limits {
+        min_width: anchor.size.width.min(screen.width - x),
+        max_width: screen.width - x,
+        min_height: 0,
+        max_height,
+      };
+
+      let size = widget.layout(limits(space_below.max(space_above)));
+
+      if size.height <= space_below || space_below >= space_above {
+        let size = widget.layout(limits(space_below));
+
+        Rect {
+          top_left: XY { x, y: below },
+          size,
+        }
+      } else {
+        Rect {
+          top_left: XY {
+            x,
+            y: space_above - size.height,
+          },
+          size,
+        }
+      }
+    }
+  }
+}
+
+/// Lays out the overlay with its top-left corner at `at`, moved left and up
+/// as much as needed for it to fit on the screen.
+fn near(widget: &mut dyn crate::Widget, at: XY, screen: Size) -> Rect {
+  let size = widget.layout(Limits {
+    max_width: screen.width,
+    max_height: screen.height,
+    ..Default::default()
+  });
+
+  Rect {
+    top_left: XY {
+      x: at.x.min(screen.width - size.width),
+      y: at.y.min(screen.height - size.height),
+    },
+    size,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    BufDrawSurface,
+    Text,
+  };
+  use crossterm::cursor::MoveTo;
+
+  fn text(text: &'static str) -> ArcWidget {
+    Arc::new(Mutex::new(Text::new(text)))
+  }
+
+  #[test]
+  fn below_flips_above_when_there_is_no_room() {
+    let anchor = Rect {
+      top_left: (2, 3).into(),
+      size: (4, 1).into(),
+    };
+
+    let rect = place(
+      &mut Text::new("a\nb"),
+      &Placement::Below(Arc::new(Mutex::new(anchor))),
+      (10, 5).into(),
+    );
+
+    assert_eq!(
+      rect,
+      Rect {
+        top_left: (2, 1).into(),
+        size: (1, 2).into(),
+      }
+    );
+  }
+
+  #[test]
+  fn draws_over_cleared_area() {
+    let overlays = Overlays::default();
+
+    overlays.push(text("hi"), Placement::At((1, 1).into()));
+
+    let mut buf = vec![];
+
+    let mut surface = BufDrawSurface::new(&mut buf, (4, 2));
+
+    overlays.draw(&mut surface);
+
+    drop(surface);
+
+    assert_eq!(
+      std::str::from_utf8(&buf).unwrap(),
+      format!("{}  {}hi", MoveTo(1, 1), MoveTo(1, 1)),
+    );
+  }
+
+  #[test]
+  fn removed_overlays_are_not_drawn() {
+    let overlays = Overlays::default();
+
+    let id = overlays.push(text("hi"), Placement::Centered);
+
+    overlays.remove(id);
+
+    assert!(overlays.is_empty());
+  }
+}
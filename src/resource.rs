@@ -0,0 +1,297 @@
+use crate::spawn_local;
+use leptos_reactive::{
+  create_effect,
+  create_rw_signal,
+  on_cleanup,
+  use_context,
+  RwSignal,
+  Scope,
+  SignalGet,
+  SignalGetUntracked,
+  SignalSet,
+  SignalWith,
+  SuspenseContext,
+};
+use std::{
+  collections::HashSet,
+  fmt,
+  future::Future,
+  pin::Pin,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// Provided by a [`Suspense`](crate::Suspense) along with its
+/// [`SuspenseContext`], so that the resources read under it forget it once
+/// it is disposed, instead of reporting to its disposed signals.
+#[derive(Clone, Default)]
+pub(crate) struct SuspenseCleanups(Arc<Mutex<Vec<Cleanup>>>);
+
+type Cleanup = Box<dyn FnOnce() + Send + Sync>;
+
+impl SuspenseCleanups {
+  /// Creates the cleanups, which are run when `cx` is disposed.
+  pub(crate) fn new(cx: Scope) -> Self {
+    let cleanups = Self::default();
+
+    on_cleanup(cx, {
+      let cleanups = cleanups.clone();
+
+      move || {
+        for cleanup in std::mem::take(&mut *cleanups.0.lock().unwrap()) {
+          cleanup();
+        }
+      }
+    });
+
+    cleanups
+  }
+
+  fn push(&self, cleanup: impl FnOnce() + Send + Sync + 'static) {
+    self.0.lock().unwrap().push(Box::new(cleanup));
+  }
+}
+
+type Fetcher<S, T> =
+  Arc<dyn Fn(S) -> Pin<Box<dyn Future<Output = T>>> + Send + Sync>;
+
+/// A value loaded asynchronously, created with [`create_async_resource`].
+///
+/// Reading it under a [`Suspense`](crate::Suspense) or
+/// [`Transition`](crate::Transition) makes them show their fallback while
+/// it loads.
+pub struct AsyncResource<S: 'static, T: 'static> {
+  value: RwSignal<Option<T>>,
+  loading: RwSignal<bool>,
+  inner: Arc<Mutex<ResourceInner<S, T>>>,
+}
+
+struct ResourceInner<S, T> {
+  fetcher: Fetcher<S, T>,
+  source: Option<S>,
+  /// Incremented on every load, so that only the latest one is kept.
+  generation: usize,
+  /// Every suspense this was read under, which are told when it starts
+  /// and stops loading.
+  suspense_contexts: HashSet<SuspenseContext>,
+}
+
+impl<S, T> Clone for AsyncResource<S, T> {
+  fn clone(&self) -> Self {
+    Self {
+      value: self.value,
+      loading: self.loading,
+      inner: self.inner.clone(),
+    }
+  }
+}
+
+impl<S, T: fmt::Debug> fmt::Debug for AsyncResource<S, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("AsyncResource")
+      .field("value", &self.value)
+      .field("loading", &self.loading)
+      .finish()
+  }
+}
+
+/// Creates a resource which loads its value with `fetcher` from whatever
+/// `source` returns, and loads it again whenever that changes.
+///
+/// This is an alternative to `leptos_reactive`'s own resources, whose
+/// futures are run to completion right away, blocking the event loop,
+/// outside of the browser. These futures are spawned with
+/// [`spawn_local`] instead, and the screen is redrawn once they are done.
+pub fn create_async_resource<S, T, F>(
+  cx: Scope,
+  source: impl Fn() -> S + 'static,
+  fetcher: impl Fn(S) -> F + Send + Sync + 'static,
+) -> AsyncResource<S, T>
+where
+  S: Clone + PartialEq + Send + Sync + 'static,
+  T: Clone + Send + Sync + 'static,
+  F: Future<Output = T> + 'static,
+{
+  let resource = AsyncResource {
+    value: create_rw_signal(cx, None),
+    loading: create_rw_signal(cx, false),
+    inner: Arc::new(Mutex::new(ResourceInner {
+      fetcher: Arc::new(move |source| Box::pin(fetcher(source))),
+      source: None,
+      generation: 0,
+      suspense_contexts: HashSet::new(),
+    })),
+  };
+
+  create_effect(cx, {
+    let resource = resource.clone();
+
+    move |_| {
+      let source = source();
+
+      let mut inner = resource.inner.lock().unwrap();
+
+      if inner.source.as_ref() != Some(&source) {
+        inner.source = Some(source);
+
+        drop(inner);
+
+        resource.load();
+      }
+    }
+  });
+
+  resource
+}
+
+impl<S, T> AsyncResource<S, T>
+where
+  S: Clone + Send + Sync + 'static,
+  T: Clone + Send + Sync + 'static,
+{
+  /// Returns the loaded value, if it has loaded yet. While it loads again,
+  /// the previous value is returned.
+  ///
+  /// Reading it under a [`Suspense`](crate::Suspense) makes it wait for
+  /// this resource.
+  pub fn read(&self, cx: Scope) -> Option<T> {
+    self.with(cx, T::clone)
+  }
+
+  /// Like [`AsyncResource::read`], without cloning the value.
+  pub fn with<U>(&self, cx: Scope, f: impl FnOnce(&T) -> U) -> Option<U> {
+    if let Some(suspense_cx) = use_context::<SuspenseContext>(cx) {
+      let is_new = self
+        .inner
+        .lock()
+        .unwrap()
+        .suspense_contexts
+        .insert(suspense_cx);
+
+      if is_new {
+        if let Some(cleanups) = use_context::<SuspenseCleanups>(cx) {
+          let inner = Arc::downgrade(&self.inner);
+
+          cleanups.push(move || {
+            if let Some(inner) = inner.upgrade() {
+              inner.lock().unwrap().suspense_contexts.remove(&suspense_cx);
+            }
+          });
+        }
+
+        if self.loading.get_untracked() {
+          suspense_cx.increment(false);
+        }
+      }
+    }
+
+    self.value.with(|value| value.as_ref().map(f))
+  }
+
+  /// Returns `true` while the value is loading.
+  pub fn loading(&self) -> bool {
+    self.loading.get()
+  }
+
+  /// Loads the value again from the current source.
+  pub fn refetch(&self) {
+    self.load();
+  }
+
+  fn load(&self) {
+    let (generation, future, suspense_contexts) = {
+      let mut inner = self.inner.lock().unwrap();
+
+      let Some(source) = inner.source.clone() else {
+        return;
+      };
+
+      inner.generation += 1;
+
+      (
+        inner.generation,
+        (inner.fetcher)(source),
+        inner.suspense_contexts.clone(),
+      )
+    };
+
+    if !self.loading.get_untracked() {
+      self.loading.set(true);
+
+      for suspense_cx in suspense_contexts {
+        suspense_cx.increment(false);
+      }
+    }
+
+    let resource = self.clone();
+
+    spawn_local(async move {
+      let value = future.await;
+
+      let suspense_contexts = {
+        let inner = resource.inner.lock().unwrap();
+
+        // A newer load is still running
+        if inner.generation != generation {
+          return;
+        }
+
+        inner.suspense_contexts.clone()
+      };
+
+      resource.value.set(Some(value));
+      resource.loading.set(false);
+
+      for suspense_cx in suspense_contexts {
+        suspense_cx.decrement(false);
+      }
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    executor::poll_tasks,
+    IntoView,
+    Suspense,
+  };
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+  };
+
+  #[test]
+  fn disposed_suspenses_are_forgotten() {
+    run_scope(create_runtime(), |cx| {
+      let resource = create_async_resource(cx, || (), |_| async { 1 });
+
+      let (_, suspense_disposer) = cx.run_child_scope({
+        let resource = resource.clone();
+
+        move |cx| {
+          Suspense::new(move |cx| {
+            move || resource.read(cx).unwrap_or_default().to_string()
+          })
+          .into_view(cx);
+        }
+      });
+
+      poll_tasks();
+
+      assert_eq!(resource.inner.lock().unwrap().suspense_contexts.len(), 1);
+
+      suspense_disposer.dispose();
+
+      assert!(resource.inner.lock().unwrap().suspense_contexts.is_empty());
+
+      resource.refetch();
+      poll_tasks();
+
+      assert_eq!(resource.read(cx), Some(1));
+    });
+  }
+}
@@ -0,0 +1,225 @@
+use crate::{
+  IntoView,
+  Shortcut,
+  View,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyModifiers,
+};
+use leptos_reactive::{
+  create_rw_signal,
+  provide_context,
+  use_context,
+  RwSignal,
+  Scope,
+  SignalUpdate,
+  SignalWith,
+  SignalWithUntracked,
+};
+use std::{
+  borrow::Cow,
+  fmt,
+  sync::{
+    atomic::{
+      AtomicUsize,
+      Ordering,
+    },
+    Arc,
+    Mutex,
+  },
+};
+
+/// A screen which can be navigated to with a [`Router`], given parameters
+/// of type `P`.
+pub struct Route<P> {
+  name: Cow<'static, str>,
+  view: Arc<dyn Fn(Scope, P) -> View + Send + Sync>,
+}
+
+impl<P> Clone for Route<P> {
+  fn clone(&self) -> Self {
+    Self {
+      name: self.name.clone(),
+      view: self.view.clone(),
+    }
+  }
+}
+
+impl<P> fmt::Debug for Route<P> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Route")
+      .field("name", &self.name)
+      .field("view", &"Fn(Scope, P) -> View")
+      .finish()
+  }
+}
+
+impl<P: Clone + Send + Sync + 'static> Route<P> {
+  /// Creates a route named `name`, whose screen is created by `view` from
+  /// the parameters it was navigated to with.
+  pub fn new<V: IntoView>(
+    name: impl Into<Cow<'static, str>>,
+    view: impl Fn(Scope, P) -> V + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      name: name.into(),
+      view: Arc::new(move |cx, params| view(cx, params).into_view(cx)),
+    }
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  fn entry(&self, id: usize, params: P) -> RouteEntry {
+    let view = self.view.clone();
+
+    RouteEntry {
+      id,
+      name: self.name.clone(),
+      view: Arc::new(move |cx| view(cx, params.clone())),
+    }
+  }
+}
+
+/// A screen on the navigation stack of a [`Router`].
+#[derive(Clone)]
+pub(crate) struct RouteEntry {
+  /// Unique for every navigation, so that navigating to the same route
+  /// again creates a new screen.
+  pub(crate) id: usize,
+  pub(crate) name: Cow<'static, str>,
+  pub(crate) view: Arc<dyn Fn(Scope) -> View + Send + Sync>,
+}
+
+impl fmt::Debug for RouteEntry {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("RouteEntry")
+      .field("id", &self.id)
+      .field("name", &self.name)
+      .finish()
+  }
+}
+
+/// A stack of screens, the topmost of which is shown by an
+/// [`Outlet`](crate::Outlet).
+///
+/// Creating a router provides it through context, so that screens can
+/// navigate with [`use_router`]. While an outlet is shown, the
+/// [back shortcut](Router::back_shortcut) pops the current screen.
+#[derive(Clone)]
+pub struct Router {
+  pub(crate) stack: RwSignal<Vec<RouteEntry>>,
+  next_id: Arc<AtomicUsize>,
+  pub(crate) back_shortcut: Arc<Mutex<Option<Shortcut>>>,
+}
+
+impl fmt::Debug for Router {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Router")
+      .field("stack", &self.stack)
+      .field("back_shortcut", &self.back_shortcut)
+      .finish()
+  }
+}
+
+impl Router {
+  /// Creates a router with an empty stack, providing it through context.
+  pub fn new(cx: Scope) -> Self {
+    let router = Self {
+      stack: create_rw_signal(cx, vec![]),
+      next_id: Default::default(),
+      back_shortcut: Arc::new(Mutex::new(Some(Shortcut::new(
+        KeyCode::Left,
+        KeyModifiers::ALT,
+      )))),
+    };
+
+    provide_context(cx, router.clone());
+
+    router
+  }
+
+  /// Sets the shortcut which goes back to the previous screen, or disables
+  /// it with [`None`]. Defaults to Alt+Left.
+  ///
+  /// The shortcut is registered by the [`Outlet`](crate::Outlet), so it
+  /// must be set before the outlet is created.
+  pub fn back_shortcut(self, shortcut: Option<Shortcut>) -> Self {
+    // Shared with the copy provided through context
+    *self.back_shortcut.lock().unwrap() = shortcut;
+
+    self
+  }
+
+  /// Shows the screen of `route` on top of the current one.
+  pub fn push<P: Clone + Send + Sync + 'static>(
+    &self,
+    route: &Route<P>,
+    params: P,
+  ) {
+    let entry = route.entry(self.next_id(), params);
+
+    self.stack.update(|stack| stack.push(entry));
+  }
+
+  /// Shows the screen of `route` instead of the current one, which can't be
+  /// gone back to.
+  pub fn replace<P: Clone + Send + Sync + 'static>(
+    &self,
+    route: &Route<P>,
+    params: P,
+  ) {
+    let entry = route.entry(self.next_id(), params);
+
+    self.stack.update(|stack| {
+      stack.pop();
+      stack.push(entry);
+    });
+  }
+
+  /// Goes back to the previous screen. Returns `false`, leaving the stack
+  /// as it is, if the current screen is the only one.
+  pub fn pop(&self) -> bool {
+    if self.stack.with_untracked(|stack| stack.len() <= 1) {
+      return false;
+    }
+
+    self.stack.update(|stack| {
+      stack.pop();
+    });
+
+    true
+  }
+
+  /// Returns `true` if there is a screen to go back to.
+  pub fn can_go_back(&self) -> bool {
+    self.stack.with(|stack| stack.len() > 1)
+  }
+
+  /// Returns the name of the current route, if any.
+  pub fn current(&self) -> Option<Cow<'static, str>> {
+    self
+      .stack
+      .with(|stack| stack.last().map(|entry| entry.name.clone()))
+  }
+
+  /// Returns the number of screens on the stack.
+  pub fn depth(&self) -> usize {
+    self.stack.with(|stack| stack.len())
+  }
+
+  fn next_id(&self) -> usize {
+    self.next_id.fetch_add(1, Ordering::Relaxed)
+  }
+}
+
+/// Gets the [`Router`] created in this scope or one of its parents.
+///
+/// # Panics
+///
+/// Panics if no router was created above `cx`.
+pub fn use_router(cx: Scope) -> Router {
+  use_context(cx).expect("a `Router` to have been created")
+}
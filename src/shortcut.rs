@@ -0,0 +1,233 @@
+use crate::{
+  Event,
+  EventHandlerResult,
+};
+use crossterm::event::{
+  KeyCode,
+  KeyEvent,
+  KeyModifiers,
+};
+use leptos_reactive::{
+  use_context,
+  Scope,
+};
+use std::{
+  fmt,
+  sync::{
+    Arc,
+    Mutex,
+  },
+};
+
+/// A key combination, such as Ctrl+S.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+  pub code: KeyCode,
+  pub modifiers: KeyModifiers,
+}
+
+impl Shortcut {
+  pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+    Self { code, modifiers }
+  }
+
+  /// Returns `true` if `key` is this key combination.
+  ///
+  /// Characters are compared regardless of case, and without Shift, since
+  /// terminals don't report it consistently for them.
+  pub fn matches(&self, key: &KeyEvent) -> bool {
+    match (self.code, key.code) {
+      (KeyCode::Char(a), KeyCode::Char(b)) => {
+        a.to_lowercase().eq(b.to_lowercase())
+          && self.modifiers - KeyModifiers::SHIFT
+            == key.modifiers - KeyModifiers::SHIFT
+      }
+      (a, b) => a == b && self.modifiers == key.modifiers,
+    }
+  }
+}
+
+impl fmt::Display for Shortcut {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (modifier, name) in [
+      (KeyModifiers::CONTROL, "Ctrl"),
+      (KeyModifiers::ALT, "Alt"),
+      (KeyModifiers::SHIFT, "Shift"),
+    ] {
+      if self.modifiers.contains(modifier) {
+        write!(f, "{name}+")?;
+      }
+    }
+
+    match self.code {
+      KeyCode::Char(' ') => write!(f, "Space"),
+      KeyCode::Char(c) => write!(f, "{}", c.to_uppercase()),
+      KeyCode::F(n) => write!(f, "F{n}"),
+      code => write!(f, "{code:?}"),
+    }
+  }
+}
+
+/// Identifies a handler registered with [`Shortcuts::register`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShortcutId(usize);
+
+/// App-wide keyboard shortcuts, which are handled before the focused
+/// widget gets to see the key, but after any focused or modal overlay.
+///
+/// The registry is provided through context by [`run`](crate::run), and
+/// can be retrieved with [`use_shortcuts`].
+#[derive(Clone, Default)]
+pub struct Shortcuts {
+  inner: Arc<Mutex<ShortcutsInner>>,
+}
+
+#[derive(Default)]
+struct ShortcutsInner {
+  next_id: usize,
+  handlers: Vec<(ShortcutId, Shortcut, Handler)>,
+}
+
+type Handler = Arc<dyn Fn() + Send + Sync>;
+
+impl fmt::Debug for Shortcuts {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let shortcuts = self
+      .inner
+      .lock()
+      .unwrap()
+      .handlers
+      .iter()
+      .map(|(_, shortcut, _)| *shortcut)
+      .collect::<Vec<_>>();
+
+    f.debug_struct("Shortcuts")
+      .field("shortcuts", &shortcuts)
+      .finish()
+  }
+}
+
+impl Shortcuts {
+  /// Calls `handler` whenever `shortcut` is pressed, until it is
+  /// unregistered. Handlers registered later take precedence for the same
+  /// shortcut.
+  pub fn register(
+    &self,
+    shortcut: Shortcut,
+    handler: impl Fn() + Send + Sync + 'static,
+  ) -> ShortcutId {
+    let mut inner = self.inner.lock().unwrap();
+
+    let id = ShortcutId(inner.next_id);
+
+    inner.next_id += 1;
+
+    inner.handlers.push((id, shortcut, Arc::new(handler)));
+
+    id
+  }
+
+  /// Removes a handler. Does nothing if it was already removed.
+  pub fn unregister(&self, id: ShortcutId) {
+    self
+      .inner
+      .lock()
+      .unwrap()
+      .handlers
+      .retain(|(handler_id, ..)| *handler_id != id);
+  }
+
+  /// Calls the handler of the shortcut matching a key event, if any.
+  /// Returns [`None`] if there was none, in which case the event should be
+  /// sent to the main view tree.
+  pub(crate) fn on(&self, event: &Event) -> Option<EventHandlerResult> {
+    let Event::Key(key) = event else {
+      return None;
+    };
+
+    // The lock is released before calling the handler, which may register
+    // or unregister shortcuts itself
+    let handler = self
+      .inner
+      .lock()
+      .unwrap()
+      .handlers
+      .iter()
+      .rev()
+      .find(|(_, shortcut, _)| shortcut.matches(key))
+      .map(|(.., handler)| handler.clone())?;
+
+    handler();
+
+    Some(EventHandlerResult::Captured)
+  }
+}
+
+/// Gets the [`Shortcuts`] registry provided by [`run`](crate::run).
+///
+/// # Panics
+///
+/// Panics if called outside of [`run`](crate::run).
+pub fn use_shortcuts(cx: Scope) -> Shortcuts {
+  use_context(cx).expect("`Shortcuts` to be provided through context")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+  };
+
+  #[test]
+  fn matches_characters_regardless_of_case() {
+    let shortcut = Shortcut::new(KeyCode::Char('f'), KeyModifiers::ALT);
+
+    assert!(shortcut.matches(&KeyEvent::new(
+      KeyCode::Char('F'),
+      KeyModifiers::ALT | KeyModifiers::SHIFT
+    )));
+    assert!(
+      !shortcut.matches(&KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE))
+    );
+
+    assert_eq!(shortcut.to_string(), "Alt+F");
+  }
+
+  #[test]
+  fn latest_handler_wins_until_unregistered() {
+    let shortcuts = Shortcuts::default();
+    let calls = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+
+    let shortcut = Shortcut::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+
+    let handler = |i: usize| {
+      let calls = calls.clone();
+
+      move || {
+        calls[i].fetch_add(1, Ordering::SeqCst);
+      }
+    };
+
+    shortcuts.register(shortcut, handler(0));
+    let second = shortcuts.register(shortcut, handler(1));
+
+    let event =
+      Event::Key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+
+    shortcuts.on(&event);
+    shortcuts.unregister(second);
+    shortcuts.on(&event);
+
+    assert_eq!(calls[0].load(Ordering::SeqCst), 1);
+    assert_eq!(calls[1].load(Ordering::SeqCst), 1);
+
+    assert!(shortcuts
+      .on(&Event::Key(KeyEvent::new(
+        KeyCode::Char('q'),
+        KeyModifiers::CONTROL
+      )))
+      .is_none());
+  }
+}
@@ -1,12 +1,10 @@
 use crate::{
   Size,
+  Style,
   XY,
 };
 use crossterm::{
-  cursor::{
-    MoveTo,
-    MoveToNextLine,
-  },
+  cursor::MoveTo,
   style::{
     Attribute,
     Color,
@@ -32,6 +30,10 @@ pub(crate) type StdoutDrawSurface = BufDrawSurface<Stdout>;
 pub trait DrawSurface {
   fn size(&self) -> Size;
 
+  /// Gets the absolute position of this surface's top-left corner on the
+  /// screen.
+  fn top_left(&self) -> XY;
+
   /// Writes the given data starting at the given coordinates.
   /// Returns [`Err`] with the data that was written out of bounds,
   /// if any.
@@ -52,6 +54,31 @@ pub trait DrawSurface {
     attribute: Option<Attribute>,
   );
 
+  /// Like [`DrawSurface::write_styled`], with the colors and attribute of
+  /// `style`.
+  fn write_with_style(&mut self, at: XY, data: &str, style: Style) {
+    self.write_styled(
+      at,
+      data,
+      style.foreground,
+      style.background,
+      style.attribute,
+    )
+  }
+
+  /// Fills the whole surface with blank spaces, erasing anything that was
+  /// drawn below it.
+  fn clear(&mut self) {
+    let Size { width, height } = self.size();
+
+    let blank = " ".repeat(width as usize);
+
+    for y in 0..height {
+      self.write(XY { x: 0, y }, &blank);
+    }
+  }
+
+  #[allow(clippy::type_complexity)]
   fn shrink(
     &mut self,
     top_left: XY,
@@ -59,6 +86,7 @@ pub trait DrawSurface {
     f: Box<dyn FnOnce(&mut dyn DrawSurface) + '_>,
   );
 
+  #[allow(clippy::type_complexity)]
   fn shrink_centered(
     &mut self,
     size: Size,
@@ -107,10 +135,14 @@ impl<W: Write> DrawSurface for BufDrawSurface<W> {
     self.size
   }
 
+  fn top_left(&self) -> XY {
+    self.top_left
+  }
+
   fn write_styled(
     &mut self,
     at: XY,
-    mut data: &str,
+    data: &str,
     foreground_color: Option<Color>,
     background_color: Option<Color>,
     attribute: Option<Attribute>,
@@ -119,18 +151,22 @@ impl<W: Write> DrawSurface for BufDrawSurface<W> {
       return;
     }
 
-    if at.y > self.size.height {
+    if at.y >= self.size.height {
       return;
     }
 
-    if at.x > self.size.width {
+    if at.x >= self.size.width {
       return;
     }
 
-    let mut data = Cow::Borrowed(
-      &data.lines().next().unwrap()
-        [0..((self.size.width - at.x) as usize).min(data.len())],
-    );
+    let line = data.lines().next().unwrap_or_default();
+
+    let end = line
+      .char_indices()
+      .nth((self.size.width - at.x) as usize)
+      .map_or(line.len(), |(i, _)| i);
+
+    let mut data = Cow::Borrowed(&line[0..end]);
 
     if let Some(color) = foreground_color {
       data = Cow::Owned(format!("{}{data}", SetForegroundColor(color)));
@@ -150,7 +186,7 @@ impl<W: Write> DrawSurface for BufDrawSurface<W> {
 
     self
       .buf
-      .queue(MoveTo(at.x, at.y))
+      .queue(MoveTo(self.top_left.x + at.x, self.top_left.y + at.y))
       .unwrap()
       .queue(Print(data))
       .unwrap();
@@ -193,6 +229,7 @@ impl<W: Write> DrawSurface for BufDrawSurface<W> {
 }
 
 impl<W: Write> BufDrawSurface<W> {
+  #[cfg(test)]
   pub fn new(writer: W, size: impl Into<Size>) -> Self {
     Self {
       buf: BufWriter::new(writer),
@@ -206,6 +243,166 @@ impl<W: Write> BufDrawSurface<W> {
   }
 }
 
+/// A surface which is larger than the area it is shown in, such as the
+/// content of a scroll view.
+///
+/// Widgets draw on it as if the whole of it was visible, and only the part
+/// within the window starting at `offset` is written to the underlying
+/// surface, which is the size of that window.
+pub(crate) struct ScrolledDrawSurface<'a> {
+  inner: &'a mut dyn DrawSurface,
+  offset: XY,
+  /// Top-left corner of the current shrunk area, relative to the whole
+  /// scrolled surface.
+  origin: XY,
+  size: Size,
+}
+
+impl<'a> ScrolledDrawSurface<'a> {
+  pub fn new(inner: &'a mut dyn DrawSurface, size: Size, offset: XY) -> Self {
+    Self {
+      inner,
+      offset,
+      origin: XY::default(),
+      size,
+    }
+  }
+}
+
+impl DrawSurface for ScrolledDrawSurface<'_> {
+  fn size(&self) -> Size {
+    self.size
+  }
+
+  fn top_left(&self) -> XY {
+    let top_left = self.inner.top_left();
+
+    XY {
+      x: (top_left.x + self.origin.x).saturating_sub(self.offset.x),
+      y: (top_left.y + self.origin.y).saturating_sub(self.offset.y),
+    }
+  }
+
+  fn write_styled(
+    &mut self,
+    at: XY,
+    data: &str,
+    foreground_color: Option<Color>,
+    background_color: Option<Color>,
+    attribute: Option<Attribute>,
+  ) {
+    if at.x >= self.size.width || at.y >= self.size.height {
+      return;
+    }
+
+    let x = self.origin.x + at.x;
+    let y = self.origin.y + at.y;
+
+    let window = self.inner.size();
+
+    if y < self.offset.y || y >= self.offset.y + window.height {
+      return;
+    }
+
+    let line = data.lines().next().unwrap_or_default();
+
+    // Clip to this area first, then skip whatever is scrolled out of view
+    // to the left
+    let skip = self.offset.x.saturating_sub(x);
+
+    let line = line
+      .chars()
+      .take((self.size.width - at.x) as usize)
+      .skip(skip as usize)
+      .collect::<String>();
+
+    if line.is_empty() {
+      return;
+    }
+
+    self.inner.write_styled(
+      XY {
+        x: x + skip - self.offset.x,
+        y: y - self.offset.y,
+      },
+      &line,
+      foreground_color,
+      background_color,
+      attribute,
+    );
+  }
+
+  fn shrink(
+    &mut self,
+    top_left: XY,
+    size: Size,
+    f: Box<dyn FnOnce(&mut dyn DrawSurface) + '_>,
+  ) {
+    let original_origin = self.origin;
+    let original_size = self.size;
+
+    self.origin = self.origin + top_left;
+    self.size = size;
+
+    f(self);
+
+    self.origin = original_origin;
+    self.size = original_size;
+  }
+}
+
+/// Forwards everything drawn on it to another surface, dimmed, such as
+/// the content behind a modal overlay.
+pub(crate) struct DimmedDrawSurface<'a> {
+  inner: &'a mut dyn DrawSurface,
+}
+
+impl<'a> DimmedDrawSurface<'a> {
+  pub fn new(inner: &'a mut dyn DrawSurface) -> Self {
+    Self { inner }
+  }
+}
+
+impl DrawSurface for DimmedDrawSurface<'_> {
+  fn size(&self) -> Size {
+    self.inner.size()
+  }
+
+  fn top_left(&self) -> XY {
+    self.inner.top_left()
+  }
+
+  fn write_styled(
+    &mut self,
+    at: XY,
+    data: &str,
+    foreground_color: Option<Color>,
+    background_color: Option<Color>,
+    _: Option<Attribute>,
+  ) {
+    self.inner.write_styled(
+      at,
+      data,
+      foreground_color,
+      background_color,
+      Some(Attribute::Dim),
+    );
+  }
+
+  fn shrink(
+    &mut self,
+    top_left: XY,
+    size: Size,
+    f: Box<dyn FnOnce(&mut dyn DrawSurface) + '_>,
+  ) {
+    self.inner.shrink(
+      top_left,
+      size,
+      Box::new(|surface| f(&mut DimmedDrawSurface::new(surface))),
+    );
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -218,7 +415,7 @@ mod tests {
 
     let mut surface = BufDrawSurface::new(&mut buf, (5, 1));
 
-    surface.write((0, 0).into(), &data);
+    surface.write((0, 0).into(), data);
 
     drop(surface);
 
@@ -235,7 +432,7 @@ mod tests {
 
     let mut surface = BufDrawSurface::new(&mut buf, (2, 1));
 
-    surface.write((0, 0).into(), &data);
+    surface.write((0, 0).into(), data);
 
     drop(surface);
 
@@ -252,7 +449,64 @@ mod tests {
 
     let mut surface = BufDrawSurface::new(&mut buf, (5, 2));
 
-    surface.write((0, 0).into(), &data);
+    surface.write((0, 0).into(), data);
+
+    drop(surface);
+
+    let buf = std::str::from_utf8(&buf).unwrap();
+
+    assert_eq!(buf, expected_data);
+  }
+
+  #[test]
+  fn clips_multi_byte_characters() {
+    let mut buf = vec![];
+    let data = "█▌ab";
+    let expected_data = format!("{}█▌a", MoveTo(0, 0));
+
+    let mut surface = BufDrawSurface::new(&mut buf, (3, 1));
+
+    surface.write((0, 0).into(), data);
+
+    drop(surface);
+
+    let buf = std::str::from_utf8(&buf).unwrap();
+
+    assert_eq!(buf, expected_data);
+  }
+
+  #[test]
+  fn shrunk_writes_are_offset() {
+    let mut buf = vec![];
+    let expected_data = format!("{}hi", MoveTo(2, 1));
+
+    let mut surface = BufDrawSurface::new(&mut buf, (5, 3));
+
+    surface.shrink(
+      (2, 1).into(),
+      (2, 1).into(),
+      Box::new(|surface| surface.write((0, 0).into(), "hi")),
+    );
+
+    drop(surface);
+
+    let buf = std::str::from_utf8(&buf).unwrap();
+
+    assert_eq!(buf, expected_data);
+  }
+
+  #[test]
+  fn scrolled_writes_are_clipped_to_the_window() {
+    let mut buf = vec![];
+    let expected_data = format!("{}llo", MoveTo(0, 0));
+
+    let mut surface = BufDrawSurface::new(&mut buf, (3, 1));
+    let mut scrolled =
+      ScrolledDrawSurface::new(&mut surface, (10, 10).into(), (2, 3).into());
+
+    scrolled.write((0, 2).into(), "above");
+    scrolled.write((0, 3).into(), "hello");
+    scrolled.write((0, 4).into(), "below");
 
     drop(surface);
 
@@ -0,0 +1,369 @@
+mod file;
+
+use crossterm::style::{
+  Attribute,
+  Color,
+};
+pub use file::*;
+use leptos_reactive::{
+  create_rw_signal,
+  provide_context,
+  use_context,
+  RwSignal,
+  Scope,
+  SignalGetUntracked,
+  SignalWithUntracked,
+};
+use std::{
+  borrow::Cow,
+  collections::BTreeMap,
+};
+
+/// How text is drawn. Unset fields keep whatever the terminal uses by
+/// default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+  pub foreground: Option<Color>,
+  pub background: Option<Color>,
+  pub attribute: Option<Attribute>,
+}
+
+impl Style {
+  pub const fn new() -> Self {
+    Self {
+      foreground: None,
+      background: None,
+      attribute: None,
+    }
+  }
+
+  pub const fn foreground(mut self, color: Color) -> Self {
+    self.foreground = Some(color);
+
+    self
+  }
+
+  pub const fn background(mut self, color: Color) -> Self {
+    self.background = Some(color);
+
+    self
+  }
+
+  pub const fn attribute(mut self, attribute: Attribute) -> Self {
+    self.attribute = Some(attribute);
+
+    self
+  }
+
+  /// Returns this style with every field set in `other` taking precedence.
+  pub fn patch(self, other: Style) -> Self {
+    Self {
+      foreground: other.foreground.or(self.foreground),
+      background: other.background.or(self.background),
+      attribute: other.attribute.or(self.attribute),
+    }
+  }
+}
+
+/// The styles built-in widgets are drawn with, named after what they are
+/// used for rather than what they look like.
+///
+/// The theme is provided through context by [`run`](crate::run), and can be
+/// replaced at any time through the signal returned by [`use_theme`], after
+/// which the screen is drawn with it on the next render. Specific widgets
+/// can be styled differently with [`ComponentStyles`].
+///
+/// Themes can also be loaded from a file with [`ThemeFile`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+  /// Regular text.
+  pub text: Style,
+  /// Secondary text, such as hints, placeholders and disabled widgets.
+  pub muted: Style,
+  /// Accents, such as informational messages.
+  pub primary: Style,
+  /// The background of windows drawn above everything else, such as
+  /// dialogs and popups.
+  pub surface: Style,
+  /// The focused widget, or the focused part of it.
+  pub focus: Style,
+  /// Focused widgets which are drawn filled in, such as buttons.
+  pub focus_fill: Style,
+  /// The selected item of a list, menu or table.
+  pub selected: Style,
+  /// Every other row of striped tables.
+  pub stripe: Style,
+  /// The parts of an item matching what was searched for.
+  pub highlight: Style,
+  /// Errors, such as failed validation.
+  pub error: Style,
+  /// Warnings.
+  pub warning: Style,
+  /// Borders, dividers and separators.
+  pub border: Style,
+}
+
+impl Default for Theme {
+  fn default() -> Self {
+    Self::dark()
+  }
+}
+
+impl Theme {
+  /// The default theme, for terminals with a dark background.
+  pub const fn dark() -> Self {
+    Self {
+      text: Style::new(),
+      muted: Style::new().foreground(Color::DarkGrey),
+      primary: Style::new().foreground(Color::Blue),
+      surface: Style::new(),
+      focus: Style::new()
+        .foreground(Color::Red)
+        .attribute(Attribute::Bold),
+      focus_fill: Style::new()
+        .background(Color::Red)
+        .attribute(Attribute::Bold),
+      selected: Style::new().attribute(Attribute::Reverse),
+      stripe: Style::new().background(Color::DarkGrey),
+      highlight: Style::new()
+        .foreground(Color::Yellow)
+        .attribute(Attribute::Bold),
+      error: Style::new().foreground(Color::Red),
+      warning: Style::new().foreground(Color::Yellow),
+      border: Style::new().foreground(Color::DarkGrey),
+    }
+  }
+
+  /// A theme where nothing is styled, which leaves every style unchanged
+  /// when [patched](Theme::patch) onto another theme.
+  pub const fn unstyled() -> Self {
+    Self {
+      text: Style::new(),
+      muted: Style::new(),
+      primary: Style::new(),
+      surface: Style::new(),
+      focus: Style::new(),
+      focus_fill: Style::new(),
+      selected: Style::new(),
+      stripe: Style::new(),
+      highlight: Style::new(),
+      error: Style::new(),
+      warning: Style::new(),
+      border: Style::new(),
+    }
+  }
+
+  /// A theme for terminals with a light background.
+  pub const fn light() -> Self {
+    Self {
+      text: Style::new().foreground(Color::Black),
+      muted: Style::new().foreground(Color::Grey),
+      primary: Style::new().foreground(Color::DarkBlue),
+      surface: Style::new().background(Color::White),
+      focus: Style::new()
+        .foreground(Color::DarkBlue)
+        .attribute(Attribute::Bold),
+      focus_fill: Style::new()
+        .foreground(Color::White)
+        .background(Color::DarkBlue)
+        .attribute(Attribute::Bold),
+      selected: Style::new().attribute(Attribute::Reverse),
+      stripe: Style::new().background(Color::Grey),
+      highlight: Style::new()
+        .foreground(Color::DarkMagenta)
+        .attribute(Attribute::Bold),
+      error: Style::new().foreground(Color::DarkRed),
+      warning: Style::new().foreground(Color::DarkYellow),
+      border: Style::new().foreground(Color::Grey),
+    }
+  }
+
+  /// Returns this theme with each style [patched](Style::patch) with the
+  /// same style of `other`.
+  pub fn patch(self, other: Theme) -> Self {
+    Self {
+      text: self.text.patch(other.text),
+      muted: self.muted.patch(other.muted),
+      primary: self.primary.patch(other.primary),
+      surface: self.surface.patch(other.surface),
+      focus: self.focus.patch(other.focus),
+      focus_fill: self.focus_fill.patch(other.focus_fill),
+      selected: self.selected.patch(other.selected),
+      stripe: self.stripe.patch(other.stripe),
+      highlight: self.highlight.patch(other.highlight),
+      error: self.error.patch(other.error),
+      warning: self.warning.patch(other.warning),
+      border: self.border.patch(other.border),
+    }
+  }
+}
+
+/// Styles overriding the [`Theme`] for specific widgets, by the
+/// [`name`](crate::Widget::name) of the widget, such as `"Button"`.
+///
+/// Like the theme, these are provided through context by
+/// [`run`](crate::run), and can be replaced through the signal returned by
+/// [`use_component_styles`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComponentStyles(BTreeMap<Cow<'static, str>, Theme>);
+
+impl ComponentStyles {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Patches the theme widgets named `component` are drawn with with
+  /// `overrides`, which is usually based on [`Theme::unstyled`].
+  pub fn set(
+    &mut self,
+    component: impl Into<Cow<'static, str>>,
+    overrides: Theme,
+  ) {
+    self.0.insert(component.into(), overrides);
+  }
+
+  /// Gets the overrides for widgets named `component`, if any.
+  pub fn get(&self, component: &str) -> Option<&Theme> {
+    self.0.get(component)
+  }
+
+  /// Returns the theme widgets named `component` are drawn with.
+  pub fn apply(&self, component: &str, theme: Theme) -> Theme {
+    self
+      .get(component)
+      .map_or(theme, |overrides| theme.patch(*overrides))
+  }
+}
+
+/// Provides `theme` through context to `cx` and the scopes below it,
+/// returning the signal it can be switched with.
+pub fn provide_theme(cx: Scope, theme: Theme) -> RwSignal<Theme> {
+  let theme = create_rw_signal(cx, theme);
+
+  provide_context(cx, theme);
+
+  theme
+}
+
+/// Gets the signal holding the [`Theme`] provided in this scope or one of
+/// its parents, which can be set to switch themes.
+///
+/// If none was provided, the default theme is provided to `cx`.
+pub fn use_theme(cx: Scope) -> RwSignal<Theme> {
+  use_context(cx).unwrap_or_else(|| provide_theme(cx, Theme::default()))
+}
+
+/// Provides `styles` through context to `cx` and the scopes below it,
+/// returning the signal they can be replaced with.
+pub fn provide_component_styles(
+  cx: Scope,
+  styles: ComponentStyles,
+) -> RwSignal<ComponentStyles> {
+  let styles = create_rw_signal(cx, styles);
+
+  provide_context(cx, styles);
+
+  styles
+}
+
+/// Gets the signal holding the [`ComponentStyles`] provided in this scope
+/// or one of its parents.
+///
+/// If none were provided, empty ones are provided to `cx`.
+pub fn use_component_styles(cx: Scope) -> RwSignal<ComponentStyles> {
+  use_context(cx)
+    .unwrap_or_else(|| provide_component_styles(cx, ComponentStyles::new()))
+}
+
+/// The theme a widget is drawn with, which is the default theme until the
+/// widget is turned into a view.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ThemeHandle(
+  Option<(RwSignal<Theme>, RwSignal<ComponentStyles>)>,
+);
+
+impl ThemeHandle {
+  pub(crate) fn new(cx: Scope) -> Self {
+    Self(Some((use_theme(cx), use_component_styles(cx))))
+  }
+
+  /// Gets the theme widgets named `component` are drawn with.
+  pub(crate) fn get(&self, component: &str) -> Theme {
+    self.0.map_or_else(Theme::default, |(theme, styles)| {
+      styles
+        .with_untracked(|styles| styles.apply(component, theme.get_untracked()))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    components::Checkbox,
+    BufDrawSurface,
+    Event,
+    IntoView,
+    Limits,
+    View,
+    Widget,
+  };
+  use crossterm::style::SetForegroundColor;
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+    SignalSet,
+  };
+
+  #[test]
+  fn patched_fields_take_precedence() {
+    let style = Style::new()
+      .foreground(Color::Red)
+      .attribute(Attribute::Bold)
+      .patch(Style::new().foreground(Color::Blue));
+
+    assert_eq!(
+      style,
+      Style::new()
+        .foreground(Color::Blue)
+        .attribute(Attribute::Bold)
+    );
+  }
+
+  #[test]
+  fn widgets_are_drawn_with_the_current_theme() {
+    run_scope(create_runtime(), |cx| {
+      let theme = provide_theme(cx, Theme::light());
+
+      let mut checkbox =
+        Checkbox::new("hi", create_rw_signal(cx, false)).into_view(cx);
+      let mut text = "hi".into_view(cx);
+
+      checkbox.on(Event::NextFocus);
+
+      let draw = |view: &mut View| {
+        let mut buf = vec![];
+        let mut surface = BufDrawSurface::new(&mut buf, (10u16, 1u16));
+
+        view.layout(Limits::from((10, 1)));
+        view.draw(&mut surface);
+
+        drop(surface);
+
+        String::from_utf8(buf).unwrap()
+      };
+
+      let black = SetForegroundColor(Color::Black).to_string();
+
+      assert!(draw(&mut checkbox)
+        .contains(&SetForegroundColor(Color::DarkBlue).to_string()));
+      assert!(draw(&mut text).contains(&black));
+
+      theme.set(Theme::dark());
+
+      assert!(draw(&mut checkbox)
+        .contains(&SetForegroundColor(Color::Red).to_string()));
+      assert!(!draw(&mut text).contains(&black));
+    });
+  }
+}
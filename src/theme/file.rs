@@ -0,0 +1,666 @@
+use super::{
+  use_component_styles,
+  use_theme,
+  ComponentStyles,
+  Style,
+  Theme,
+};
+use crate::{
+  spawn_local,
+  Toast,
+  Toasts,
+};
+use crossterm::style::{
+  Attribute,
+  Color,
+};
+use leptos_reactive::{
+  on_cleanup,
+  use_context,
+  RwSignal,
+  Scope,
+  SignalSet,
+};
+use serde::{
+  de::{
+    self,
+    Unexpected,
+    Visitor,
+  },
+  Deserialize,
+  Deserializer,
+};
+use std::{
+  cell::RefCell,
+  collections::BTreeMap,
+  error::Error,
+  fmt,
+  fs,
+  io,
+  path::{
+    Path,
+    PathBuf,
+  },
+  sync::{
+    atomic::{
+      AtomicBool,
+      Ordering,
+    },
+    Arc,
+  },
+  thread,
+  time::{
+    Duration,
+    SystemTime,
+  },
+};
+
+/// How often a file watched with [`watch_theme_file`] is checked for
+/// changes.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+thread_local! {
+  /// The named colors of the file being parsed, which colors are looked up
+  /// in while parsing its styles.
+  static PALETTE: RefCell<BTreeMap<String, Color>> =
+    const { RefCell::new(BTreeMap::new()) };
+}
+
+/// A theme, and the styles of specific widgets, loaded from a TOML or JSON
+/// file.
+///
+/// Styles are applied on top of the `base` theme, which is `"dark"` unless
+/// set to `"light"` or `"unstyled"`, and only change the colors and
+/// attribute they set. The styles under `components` are applied on top of
+/// the theme for the widgets with that [`name`](crate::Widget::name).
+///
+/// Colors are the names of [`Color`]s such as `"dark_grey"`, `"#rrggbb"`
+/// or `"#rgb"` hex codes, `"rgb(r, g, b)"`, ANSI color indices from 0 to
+/// 255, or the names of colors defined under `colors`.
+///
+/// ```toml
+/// base = "light"
+///
+/// [colors]
+/// accent = "#ff8700"
+/// panel = "rgb(40, 40, 40)"
+/// subtle = 244
+///
+/// [styles]
+/// focus = { foreground = "accent", attribute = "bold" }
+/// surface = { background = "panel" }
+/// muted = { fg = "subtle" }
+///
+/// [components.Button]
+/// focus_fill = { foreground = "black", background = "accent" }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeFile {
+  pub theme: Theme,
+  pub component_styles: ComponentStyles,
+}
+
+impl ThemeFile {
+  /// Loads the theme file at `path`, parsed as TOML or JSON depending on
+  /// its extension.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeFileError> {
+    let path = path.as_ref();
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+      Some("toml") => Self::from_toml(&fs::read_to_string(path)?),
+      Some("json") => Self::from_json(&fs::read_to_string(path)?),
+      _ => Err(ThemeFileError::UnknownFormat(path.to_owned())),
+    }
+  }
+
+  pub fn from_toml(source: &str) -> Result<Self, ThemeFileError> {
+    let error = |error: toml::de::Error| {
+      let (line, column) = error
+        .span()
+        .map_or((1, 1), |span| line_and_column(source, span.start));
+
+      ThemeFileError::Invalid {
+        line,
+        column,
+        message: error.message().to_owned(),
+      }
+    };
+
+    // Named colors are parsed first, so styles can use them wherever they
+    // are defined
+    let palette = toml::from_str::<PaletteDef>(source).map_err(error)?;
+
+    with_palette(palette, || toml::from_str::<ThemeDef>(source))
+      .map(ThemeDef::build)
+      .map_err(error)
+  }
+
+  pub fn from_json(source: &str) -> Result<Self, ThemeFileError> {
+    let error = |error: serde_json::Error| {
+      let (line, column) = (error.line(), error.column());
+      let message = error.to_string();
+      let position = format!(" at line {line} column {column}");
+
+      ThemeFileError::Invalid {
+        line,
+        column,
+        message: message
+          .strip_suffix(&position)
+          .unwrap_or(&message)
+          .to_owned(),
+      }
+    };
+
+    let palette = serde_json::from_str::<PaletteDef>(source).map_err(error)?;
+
+    with_palette(palette, || serde_json::from_str::<ThemeDef>(source))
+      .map(ThemeDef::build)
+      .map_err(error)
+  }
+
+  /// Replaces the theme and component styles provided to `cx`.
+  pub fn apply(self, cx: Scope) {
+    self.set(use_theme(cx), use_component_styles(cx));
+  }
+
+  fn set(
+    self,
+    theme: RwSignal<Theme>,
+    component_styles: RwSignal<ComponentStyles>,
+  ) {
+    theme.set(self.theme);
+    component_styles.set(self.component_styles);
+  }
+}
+
+/// Loads the theme file at `path` and [applies](ThemeFile::apply) it to
+/// `cx`, then applies it again every time the file changes, for as long as
+/// `cx` is alive.
+///
+/// Errors loading the file later on are shown as error [`Toast`]s, and
+/// keep the theme as it was.
+pub fn watch_theme_file(
+  cx: Scope,
+  path: impl Into<PathBuf>,
+) -> Result<(), ThemeFileError> {
+  let path = path.into();
+  let theme = use_theme(cx);
+  let component_styles = use_component_styles(cx);
+  let toasts = use_context::<Toasts>(cx);
+
+  // Read before loading, so changes made in between aren't missed
+  let mut modified = modified_at(&path);
+
+  ThemeFile::load(&path)?.set(theme, component_styles);
+
+  let stopped = Arc::new(AtomicBool::new(false));
+  let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+  on_cleanup(cx, {
+    let stopped = stopped.clone();
+
+    move || stopped.store(true, Ordering::SeqCst)
+  });
+
+  // Files are polled rather than watched, and loaded off the UI thread
+  thread::spawn({
+    let path = path.clone();
+    let stopped = stopped.clone();
+
+    move || {
+      while !stopped.load(Ordering::SeqCst) {
+        thread::sleep(WATCH_INTERVAL);
+
+        let now = modified_at(&path);
+
+        if now != modified {
+          modified = now;
+
+          if tx.send(ThemeFile::load(&path)).is_err() {
+            break;
+          }
+        }
+      }
+    }
+  });
+
+  spawn_local(async move {
+    while let Some(file) = rx.recv().await {
+      if stopped.load(Ordering::SeqCst) {
+        break;
+      }
+
+      match file {
+        Ok(file) => file.set(theme, component_styles),
+        Err(error) => {
+          if let Some(toasts) = &toasts {
+            toasts.push(Toast::error(format!("{}: {error}", path.display())));
+          }
+        }
+      }
+    }
+  });
+
+  Ok(())
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
+
+/// Why a [`ThemeFile`] couldn't be loaded.
+#[derive(Debug)]
+pub enum ThemeFileError {
+  /// The file couldn't be read.
+  Io(io::Error),
+  /// The file's extension is neither `toml` nor `json`.
+  UnknownFormat(PathBuf),
+  /// The file isn't a valid theme. Lines and columns start at 1.
+  Invalid {
+    line: usize,
+    column: usize,
+    message: String,
+  },
+}
+
+impl fmt::Display for ThemeFileError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Io(error) => write!(f, "failed to read theme file: {error}"),
+      Self::UnknownFormat(path) => write!(
+        f,
+        "unknown theme file format of `{}`, expected `.toml` or `.json`",
+        path.display()
+      ),
+      Self::Invalid {
+        line,
+        column,
+        message,
+      } => write!(f, "{line}:{column}: {message}"),
+    }
+  }
+}
+
+impl Error for ThemeFileError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      Self::Io(error) => Some(error),
+      Self::UnknownFormat(_) | Self::Invalid { .. } => None,
+    }
+  }
+}
+
+impl From<io::Error> for ThemeFileError {
+  fn from(error: io::Error) -> Self {
+    Self::Io(error)
+  }
+}
+
+/// Returns the line and column of the byte at `offset`, starting at 1.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+  let before = &source[..offset.min(source.len())];
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+  (
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count() + 1,
+  )
+}
+
+/// Runs `f` with the named colors of `palette` available to [`ColorDef`].
+fn with_palette<T>(palette: PaletteDef, f: impl FnOnce() -> T) -> T {
+  let colors = palette
+    .colors
+    .into_iter()
+    .map(|(name, ColorDef(color))| (name, color))
+    .collect();
+
+  let previous = PALETTE.with(|palette| palette.replace(colors));
+
+  let result = f();
+
+  PALETTE.with(|palette| palette.replace(previous));
+
+  result
+}
+
+/// Only the named colors of a theme file, ignoring everything else.
+#[derive(Deserialize)]
+struct PaletteDef {
+  #[serde(default)]
+  colors: BTreeMap<String, ColorDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeDef {
+  #[serde(default)]
+  base: BaseDef,
+  /// Already parsed into the palette.
+  #[serde(default)]
+  #[allow(dead_code)]
+  colors: BTreeMap<String, ColorDef>,
+  #[serde(default)]
+  styles: StylesDef,
+  #[serde(default)]
+  components: BTreeMap<String, StylesDef>,
+}
+
+impl ThemeDef {
+  fn build(self) -> ThemeFile {
+    let base = match self.base {
+      BaseDef::Dark => Theme::dark(),
+      BaseDef::Light => Theme::light(),
+      BaseDef::Unstyled => Theme::unstyled(),
+    };
+
+    let mut component_styles = ComponentStyles::new();
+
+    for (component, styles) in self.components {
+      component_styles.set(component, styles.into());
+    }
+
+    ThemeFile {
+      theme: base.patch(self.styles.into()),
+      component_styles,
+    }
+  }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum BaseDef {
+  #[default]
+  Dark,
+  Light,
+  Unstyled,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct StylesDef {
+  text: StyleDef,
+  muted: StyleDef,
+  primary: StyleDef,
+  surface: StyleDef,
+  focus: StyleDef,
+  focus_fill: StyleDef,
+  selected: StyleDef,
+  stripe: StyleDef,
+  highlight: StyleDef,
+  error: StyleDef,
+  warning: StyleDef,
+  border: StyleDef,
+}
+
+impl From<StylesDef> for Theme {
+  fn from(styles: StylesDef) -> Self {
+    Self {
+      text: styles.text.into(),
+      muted: styles.muted.into(),
+      primary: styles.primary.into(),
+      surface: styles.surface.into(),
+      focus: styles.focus.into(),
+      focus_fill: styles.focus_fill.into(),
+      selected: styles.selected.into(),
+      stripe: styles.stripe.into(),
+      highlight: styles.highlight.into(),
+      error: styles.error.into(),
+      warning: styles.warning.into(),
+      border: styles.border.into(),
+    }
+  }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct StyleDef {
+  #[serde(alias = "fg")]
+  foreground: Option<ColorDef>,
+  #[serde(alias = "bg")]
+  background: Option<ColorDef>,
+  attribute: Option<AttributeDef>,
+}
+
+impl From<StyleDef> for Style {
+  fn from(style: StyleDef) -> Self {
+    Self {
+      foreground: style.foreground.map(|ColorDef(color)| color),
+      background: style.background.map(|ColorDef(color)| color),
+      attribute: style.attribute.map(Into::into),
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AttributeDef {
+  Bold,
+  Dim,
+  Italic,
+  Underlined,
+  SlowBlink,
+  RapidBlink,
+  Reverse,
+  Hidden,
+  CrossedOut,
+}
+
+impl From<AttributeDef> for Attribute {
+  fn from(attribute: AttributeDef) -> Self {
+    match attribute {
+      AttributeDef::Bold => Self::Bold,
+      AttributeDef::Dim => Self::Dim,
+      AttributeDef::Italic => Self::Italic,
+      AttributeDef::Underlined => Self::Underlined,
+      AttributeDef::SlowBlink => Self::SlowBlink,
+      AttributeDef::RapidBlink => Self::RapidBlink,
+      AttributeDef::Reverse => Self::Reverse,
+      AttributeDef::Hidden => Self::Hidden,
+      AttributeDef::CrossedOut => Self::CrossedOut,
+    }
+  }
+}
+
+struct ColorDef(Color);
+
+impl<'de> Deserialize<'de> for ColorDef {
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(ColorVisitor).map(Self)
+  }
+}
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+  type Value = Color;
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(
+      "a color name, `#rrggbb`, `rgb(r, g, b)` or an ANSI color index",
+    )
+  }
+
+  fn visit_u64<E: de::Error>(self, value: u64) -> Result<Color, E> {
+    u8::try_from(value)
+      .map(Color::AnsiValue)
+      .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+  }
+
+  fn visit_i64<E: de::Error>(self, value: i64) -> Result<Color, E> {
+    u8::try_from(value)
+      .map(Color::AnsiValue)
+      .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+    parse_color(value)
+      .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+  }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+  if let Some(hex) = value.strip_prefix('#') {
+    let channel = |i: usize, len: usize| {
+      let digits = hex.get(i * len..(i + 1) * len)?;
+      let channel = u8::from_str_radix(digits, 16).ok()?;
+
+      // `#rgb` is short for `#rrggbb`
+      Some(if len == 1 { channel * 0x11 } else { channel })
+    };
+
+    let len = match hex.len() {
+      3 => 1,
+      6 => 2,
+      _ => return None,
+    };
+
+    return Some(Color::Rgb {
+      r: channel(0, len)?,
+      g: channel(1, len)?,
+      b: channel(2, len)?,
+    });
+  }
+
+  if let Some(rgb) = value
+    .strip_prefix("rgb(")
+    .and_then(|rgb| rgb.strip_suffix(')'))
+  {
+    let mut channels = rgb.split(',').map(|channel| channel.trim().parse());
+
+    let color = Color::Rgb {
+      r: channels.next()?.ok()?,
+      g: channels.next()?.ok()?,
+      b: channels.next()?.ok()?,
+    };
+
+    return channels.next().is_none().then_some(color);
+  }
+
+  PALETTE
+    .with(|palette| palette.borrow().get(value).copied())
+    .or_else(|| Color::try_from(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::executor::poll_tasks;
+  use leptos_reactive::{
+    create_runtime,
+    run_scope,
+    SignalGetUntracked,
+  };
+  use std::time::Instant;
+
+  #[test]
+  fn parses_colors_styles_and_component_styles() {
+    let file = ThemeFile::from_toml(
+      r##"
+        base = "unstyled"
+
+        [styles]
+        focus = { foreground = "accent", attribute = "bold" }
+        muted = { fg = 244 }
+        surface = { bg = "rgb(1, 2, 3)" }
+
+        [colors]
+        accent = "#f80"
+
+        [components.Button]
+        focus = { background = "dark_blue" }
+      "##,
+    )
+    .unwrap();
+
+    assert_eq!(
+      file.theme,
+      Theme {
+        focus: Style::new()
+          .foreground(Color::Rgb {
+            r: 0xff,
+            g: 0x88,
+            b: 0
+          })
+          .attribute(Attribute::Bold),
+        muted: Style::new().foreground(Color::AnsiValue(244)),
+        surface: Style::new().background(Color::Rgb { r: 1, g: 2, b: 3 }),
+        ..Theme::unstyled()
+      }
+    );
+    assert_eq!(
+      file.component_styles.apply("Button", file.theme).focus,
+      file.theme.focus.background(Color::DarkBlue)
+    );
+    assert_eq!(ThemeFile::from_json("{}").unwrap().theme, Theme::dark());
+  }
+
+  #[test]
+  fn errors_point_at_their_line_and_column() {
+    let error =
+      ThemeFile::from_toml("[styles]\nfocus = { fg = \"acent\" }").unwrap_err();
+
+    assert!(
+      matches!(
+        &error,
+        ThemeFileError::Invalid {
+          line: 2,
+          column: 16,
+          message,
+        } if message.contains("\"acent\"")
+      ),
+      "{error}"
+    );
+
+    let error =
+      ThemeFile::from_json("{\n  \"styles\": {\n    \"focs\": {}\n  }\n}")
+        .unwrap_err();
+
+    assert!(
+      matches!(
+        &error,
+        ThemeFileError::Invalid { line: 3, message, .. }
+          if message.starts_with("unknown field `focs`")
+      ),
+      "{error}"
+    );
+  }
+
+  #[test]
+  fn watched_files_are_reapplied_when_changed() {
+    let path = std::env::temp_dir()
+      .join(format!("leptos-tui-theme-{}.toml", std::process::id()));
+
+    fs::write(&path, "").unwrap();
+
+    run_scope(create_runtime(), {
+      let path = path.clone();
+
+      move |cx| {
+        let theme = use_theme(cx);
+
+        watch_theme_file(cx, &path).unwrap();
+
+        assert_eq!(theme.get_untracked(), Theme::dark());
+
+        fs::write(&path, "base = \"light\"").unwrap();
+
+        let started_at = Instant::now();
+
+        while theme.get_untracked() != Theme::light() {
+          assert!(started_at.elapsed() < Duration::from_secs(5));
+
+          thread::sleep(Duration::from_millis(50));
+
+          poll_tasks();
+        }
+      }
+    });
+
+    fs::remove_file(path).unwrap();
+  }
+}
@@ -0,0 +1,584 @@
+use crate::{
+  executor::sleep,
+  spawn_local,
+  Corner,
+  DrawSurface,
+  Event,
+  EventHandlerResult,
+  Limits,
+  OverlayId,
+  Overlays,
+  Placement,
+  Size,
+  Style,
+  Theme,
+  ThemeHandle,
+  Widget,
+  XY,
+};
+use crossterm::{
+  event::{
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+  },
+  style::Attribute,
+};
+use leptos_reactive::{
+  use_context,
+  Scope,
+};
+use std::{
+  borrow::Cow,
+  ops::Range,
+  sync::{
+    Arc,
+    Mutex,
+    Weak,
+  },
+  time::{
+    Duration,
+    Instant,
+  },
+};
+
+/// How long toasts are shown for, unless they are given another timeout.
+pub const DEFAULT_TOAST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Toasts never get wider than this, their messages are wrapped instead.
+const MAX_TOAST_WIDTH: u16 = 40;
+
+/// How important a [`Toast`] is, which determines its style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastLevel {
+  #[default]
+  Info,
+  Warn,
+  Error,
+}
+
+impl ToastLevel {
+  fn icon(self) -> &'static str {
+    match self {
+      Self::Info => "ℹ",
+      Self::Warn => "▲",
+      Self::Error => "✖",
+    }
+  }
+
+  fn style(self, theme: &Theme) -> Style {
+    match self {
+      Self::Info => theme.primary,
+      Self::Warn => theme.warning,
+      Self::Error => theme.error,
+    }
+  }
+}
+
+/// A transient message shown with [`Toasts::push`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+  pub message: Cow<'static, str>,
+  pub level: ToastLevel,
+  /// How long the toast is shown for, or [`None`] if it stays until it is
+  /// dismissed.
+  pub timeout: Option<Duration>,
+}
+
+impl Toast {
+  /// Creates an info toast, shown for [`DEFAULT_TOAST_TIMEOUT`].
+  pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+    Self {
+      message: message.into(),
+      level: ToastLevel::Info,
+      timeout: Some(DEFAULT_TOAST_TIMEOUT),
+    }
+  }
+
+  pub fn info(message: impl Into<Cow<'static, str>>) -> Self {
+    Self::new(message)
+  }
+
+  pub fn warn(message: impl Into<Cow<'static, str>>) -> Self {
+    Self::new(message).level(ToastLevel::Warn)
+  }
+
+  pub fn error(message: impl Into<Cow<'static, str>>) -> Self {
+    Self::new(message).level(ToastLevel::Error)
+  }
+
+  pub fn level(mut self, level: ToastLevel) -> Self {
+    self.level = level;
+
+    self
+  }
+
+  /// Sets how long the toast is shown for. With [`None`], the toast stays
+  /// until it is dismissed.
+  pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+    self.timeout = timeout;
+
+    self
+  }
+}
+
+/// Identifies a toast pushed with [`Toasts::push`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// The app-wide queue of toasts, stacked in a corner of the screen on the
+/// [`Overlays`] layer, newest closest to the corner.
+///
+/// Toasts disappear once their timeout elapses, when they are clicked, or
+/// when they are dismissed with [`Toasts::dismiss`]. Toasts with a timeout
+/// are removed by a task which is only woken once it elapses.
+///
+/// The queue is provided through context by [`run`](crate::run), and can
+/// be retrieved with [`use_toasts`].
+#[derive(Clone, Debug)]
+pub struct Toasts {
+  shared: Arc<ToastsShared>,
+}
+
+#[derive(Debug)]
+struct ToastsShared {
+  inner: Mutex<ToastsInner>,
+  overlays: Overlays,
+  theme: ThemeHandle,
+}
+
+#[derive(Debug, Default)]
+struct ToastsInner {
+  next_id: usize,
+  /// Shown toasts, oldest first.
+  toasts: Vec<ShownToast>,
+  corner: Corner,
+  /// The overlay the toasts are drawn in, while there are any.
+  overlay: Option<OverlayId>,
+}
+
+#[derive(Debug)]
+struct ShownToast {
+  id: ToastId,
+  toast: Toast,
+  shown_at: Instant,
+}
+
+impl Toasts {
+  pub(crate) fn new(overlays: Overlays, theme: ThemeHandle) -> Self {
+    Self {
+      shared: Arc::new(ToastsShared {
+        inner: Default::default(),
+        overlays,
+        theme,
+      }),
+    }
+  }
+
+  /// Shows a toast above all other toasts.
+  pub fn push(&self, toast: Toast) -> ToastId {
+    let mut inner = self.shared.inner.lock().unwrap();
+
+    let id = ToastId(inner.next_id);
+
+    inner.next_id += 1;
+
+    if let Some(timeout) = toast.timeout {
+      let shared = Arc::downgrade(&self.shared);
+
+      spawn_local(async move {
+        sleep(timeout).await;
+
+        if let Some(shared) = shared.upgrade() {
+          Toasts { shared }.remove_expired(Instant::now());
+        }
+      });
+    }
+
+    inner.toasts.push(ShownToast {
+      id,
+      toast,
+      shown_at: Instant::now(),
+    });
+
+    self.sync(&mut inner);
+
+    id
+  }
+
+  /// Hides a toast before its timeout elapses. Does nothing if it was
+  /// already hidden.
+  pub fn dismiss(&self, id: ToastId) {
+    let mut inner = self.shared.inner.lock().unwrap();
+
+    inner.toasts.retain(|shown| shown.id != id);
+
+    self.sync(&mut inner);
+  }
+
+  /// Hides every toast.
+  pub fn clear(&self) {
+    let mut inner = self.shared.inner.lock().unwrap();
+
+    inner.toasts.clear();
+
+    self.sync(&mut inner);
+  }
+
+  /// Sets the corner of the screen toasts are stacked in, which is the
+  /// top-right corner by default.
+  pub fn set_corner(&self, corner: Corner) {
+    let mut inner = self.shared.inner.lock().unwrap();
+
+    inner.corner = corner;
+
+    // The overlay is pushed again at its new placement
+    if let Some(id) = inner.overlay.take() {
+      self.shared.overlays.remove(id);
+    }
+
+    self.sync(&mut inner);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.shared.inner.lock().unwrap().toasts.is_empty()
+  }
+
+  /// Removes the toasts whose timeout elapsed.
+  fn remove_expired(&self, now: Instant) {
+    let mut inner = self.shared.inner.lock().unwrap();
+
+    inner.toasts.retain(|shown| {
+      shown.toast.timeout.is_none_or(|timeout| {
+        now.saturating_duration_since(shown.shown_at) < timeout
+      })
+    });
+
+    self.sync(&mut inner);
+  }
+
+  /// Makes sure the overlay is shown only while there are toasts.
+  fn sync(&self, inner: &mut ToastsInner) {
+    if inner.toasts.is_empty() {
+      if let Some(id) = inner.overlay.take() {
+        self.shared.overlays.remove(id);
+      }
+    } else if inner.overlay.is_none() {
+      let stack = ToastStack {
+        toasts: Arc::downgrade(&self.shared),
+        laid_out: vec![],
+        size: Size::default(),
+      };
+
+      inner.overlay = Some(
+        self
+          .shared
+          .overlays
+          .push(Arc::new(Mutex::new(stack)), Placement::Corner(inner.corner)),
+      );
+    }
+  }
+}
+
+/// Gets the [`Toasts`] queue provided by [`run`](crate::run).
+///
+/// # Panics
+///
+/// Panics if called outside of [`run`](crate::run).
+pub fn use_toasts(cx: Scope) -> Toasts {
+  use_context(cx).expect("`Toasts` to be provided through context")
+}
+
+/// A toast as it was last laid out.
+#[derive(Debug)]
+struct LaidOutToast {
+  id: ToastId,
+  level: ToastLevel,
+  lines: Vec<String>,
+  rows: Range<u16>,
+  width: u16,
+}
+
+/// The overlay widget drawing the stack of toasts.
+#[derive(Debug)]
+struct ToastStack {
+  /// Weak, since the toasts own the overlay layer this is pushed to.
+  toasts: Weak<ToastsShared>,
+  laid_out: Vec<LaidOutToast>,
+  size: Size,
+}
+
+impl Widget for ToastStack {
+  fn name(&self) -> Cow<'static, str> {
+    "Toasts".into()
+  }
+
+  fn layout(&mut self, limits: Limits) -> Size {
+    let Some(toasts) = self.toasts() else {
+      return limits.min_size();
+    };
+
+    toasts.remove_expired(Instant::now());
+
+    let inner = toasts.shared.inner.lock().unwrap();
+
+    // Each toast is padded by a space on both sides, and its message
+    // follows its icon and a space
+    let max_width = limits.max_width.min(MAX_TOAST_WIDTH);
+    let message_width = max_width.saturating_sub(4).max(1) as usize;
+
+    self.laid_out.clear();
+
+    let mut height = 0;
+
+    // The newest toasts are shown first if they don't all fit
+    for shown in inner.toasts.iter().rev() {
+      let lines = textwrap::wrap(&shown.toast.message, message_width)
+        .into_iter()
+        .map(|line| line.into_owned())
+        .collect::<Vec<_>>();
+
+      let gap = if self.laid_out.is_empty() { 0 } else { 1 };
+      let toast_height = lines.len().max(1) as u16;
+
+      if height + gap + toast_height > limits.max_height {
+        break;
+      }
+
+      height += gap + toast_height;
+
+      let width = lines
+        .iter()
+        .map(|line| line.chars().count() as u16)
+        .max()
+        .unwrap_or_default()
+        + 4;
+
+      self.laid_out.push(LaidOutToast {
+        id: shown.id,
+        level: shown.toast.level,
+        lines,
+        rows: 0..toast_height,
+        width: width.min(max_width),
+      });
+    }
+
+    // Newest toasts are closest to the corner
+    let is_bottom =
+      matches!(inner.corner, Corner::BottomLeft | Corner::BottomRight);
+
+    if is_bottom {
+      self.laid_out.reverse();
+    }
+
+    let mut y = 0;
+
+    for toast in &mut self.laid_out {
+      toast.rows = y..y + toast.rows.len() as u16;
+
+      y = toast.rows.end + 1;
+    }
+
+    let width = self
+      .laid_out
+      .iter()
+      .map(|toast| toast.width)
+      .max()
+      .unwrap_or_default();
+
+    self.size = Size { width, height };
+
+    self.size
+  }
+
+  fn draw(&self, surface: &mut dyn DrawSurface) {
+    let Some(toasts) = self.toasts() else {
+      return;
+    };
+
+    let is_right = matches!(
+      toasts.shared.inner.lock().unwrap().corner,
+      Corner::TopRight | Corner::BottomRight
+    );
+
+    let theme = toasts.shared.theme.get(&self.name());
+
+    for toast in &self.laid_out {
+      let x = if is_right {
+        self.size.width - toast.width
+      } else {
+        0
+      };
+
+      let message_width = toast.width as usize - 4;
+
+      for (i, y) in toast.rows.clone().enumerate() {
+        let icon = if i == 0 { toast.level.icon() } else { " " };
+        let line = toast.lines.get(i).map(String::as_str).unwrap_or_default();
+
+        surface.write_with_style(
+          XY { x, y },
+          &format!(" {icon} {line:<message_width$} "),
+          toast.level.style(&theme).attribute(Attribute::Reverse),
+        );
+      }
+    }
+  }
+
+  fn on(&mut self, event: Event) -> EventHandlerResult {
+    if let Event::Mouse(MouseEvent {
+      kind: MouseEventKind::Down(MouseButton::Left),
+      row,
+      ..
+    }) = event
+    {
+      if let (Some(toast), Some(toasts)) = (
+        self.laid_out.iter().find(|toast| toast.rows.contains(&row)),
+        self.toasts(),
+      ) {
+        toasts.dismiss(toast.id);
+      }
+    }
+
+    EventHandlerResult::Captured
+  }
+}
+
+impl ToastStack {
+  fn toasts(&self) -> Option<Toasts> {
+    self.toasts.upgrade().map(|shared| Toasts { shared })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::executor::poll_tasks;
+  use crossterm::event::KeyModifiers;
+  use std::thread;
+
+  const LIMITS: Limits = Limits {
+    min_width: 0,
+    max_width: 80,
+    min_height: 0,
+    max_height: 24,
+  };
+
+  fn toasts() -> (Toasts, Overlays) {
+    let overlays = Overlays::default();
+
+    (
+      Toasts::new(overlays.clone(), ThemeHandle::default()),
+      overlays,
+    )
+  }
+
+  fn stack(toasts: &Toasts) -> ToastStack {
+    ToastStack {
+      toasts: Arc::downgrade(&toasts.shared),
+      laid_out: vec![],
+      size: Size::default(),
+    }
+  }
+
+  #[test]
+  fn overlay_is_shown_only_while_there_are_toasts() {
+    let (toasts, overlays) = toasts();
+
+    let first = toasts.push(Toast::info("first"));
+    let second = toasts.push(Toast::error("second").timeout(None));
+
+    assert!(!overlays.is_empty());
+
+    toasts.dismiss(first);
+
+    assert!(!overlays.is_empty());
+
+    toasts.dismiss(second);
+
+    assert!(overlays.is_empty());
+    assert!(toasts.is_empty());
+  }
+
+  #[test]
+  fn expired_toasts_are_removed() {
+    let (toasts, overlays) = toasts();
+
+    toasts.push(Toast::warn("gone").timeout(Some(Duration::ZERO)));
+    toasts.push(Toast::info("stays").timeout(None));
+
+    let mut stack = stack(&toasts);
+
+    let size = stack.layout(LIMITS);
+
+    assert_eq!(size, (9, 1).into());
+    assert_eq!(stack.laid_out[0].lines, vec!["stays"]);
+
+    toasts.clear();
+
+    assert!(overlays.is_empty());
+  }
+
+  #[test]
+  fn timed_toasts_are_removed_once_their_timer_wakes() {
+    let (toasts, overlays) = toasts();
+
+    toasts.push(Toast::info("soon").timeout(Some(Duration::from_millis(10))));
+
+    poll_tasks();
+
+    assert!(!overlays.is_empty());
+
+    thread::sleep(Duration::from_millis(100));
+
+    assert!(poll_tasks());
+    assert!(overlays.is_empty());
+  }
+
+  #[test]
+  fn toasts_are_freed_along_with_their_overlays() {
+    let (toasts, overlays) = toasts();
+
+    toasts.push(Toast::info("stays").timeout(None));
+
+    let shared = Arc::downgrade(&toasts.shared);
+
+    drop((toasts, overlays));
+
+    assert!(shared.upgrade().is_none());
+  }
+
+  #[test]
+  fn newest_toasts_are_closest_to_the_corner() {
+    let (toasts, _) = toasts();
+
+    toasts.push(Toast::info("old"));
+    toasts.push(Toast::info("new"));
+
+    let mut stack = stack(&toasts);
+
+    stack.layout(LIMITS);
+
+    assert_eq!(stack.laid_out[0].lines, vec!["new"]);
+    assert_eq!(stack.laid_out[1].rows, 2..3);
+
+    toasts.set_corner(Corner::BottomLeft);
+
+    stack.layout(LIMITS);
+
+    assert_eq!(stack.laid_out[0].lines, vec!["old"]);
+
+    // Clicking a toast dismisses it
+    stack.on(Event::Mouse(MouseEvent {
+      kind: MouseEventKind::Down(MouseButton::Left),
+      column: 1,
+      row: 2,
+      modifiers: KeyModifiers::NONE,
+    }));
+
+    stack.layout(LIMITS);
+
+    assert_eq!(stack.laid_out.len(), 1);
+    assert_eq!(stack.laid_out[0].lines, vec!["old"]);
+  }
+}
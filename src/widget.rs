@@ -1,9 +1,14 @@
 use crate::{
   DrawSurface,
   Limits,
+  Rect,
   Size,
+  XY,
+};
+use crossterm::event::{
+  KeyEvent,
+  MouseEvent,
 };
-use crossterm::event::KeyEvent;
 use std::{
   borrow::Cow,
   fmt,
@@ -18,11 +23,20 @@ pub trait Widget: fmt::Debug + Send + Sync {
 
   fn draw(&self, surface: &mut dyn DrawSurface);
 
+  /// Returns [`Some`] if this widget can receive focus, with `true`
+  /// meaning it currently wants to be focused. Widgets which never
+  /// take focus return [`None`].
   fn needs_focus(&self) -> Option<bool> {
     None
   }
 
-  fn on(&mut self, event: Event) -> EventHandlerResult {
+  /// Returns `true` if this widget, or any of its children, is
+  /// currently focused.
+  fn has_focus(&self) -> bool {
+    false
+  }
+
+  fn on(&mut self, _event: Event) -> EventHandlerResult {
     EventHandlerResult::Bubble
   }
 
@@ -31,13 +45,43 @@ pub trait Widget: fmt::Debug + Send + Sync {
   fn blur(&mut self) {}
 }
 
+#[derive(Clone, Debug)]
 pub enum Event {
   Key(KeyEvent),
+  /// A mouse event, with `column` and `row` relative to the top-left
+  /// corner of the widget receiving it.
+  Mouse(MouseEvent),
   NextFocus,
   PrevFocus,
   Batch(Vec<Event>),
 }
 
+impl Event {
+  /// Translates this event for a child drawn at `offset` with the given
+  /// `size`. Returns [`None`] if this is a mouse event which falls outside
+  /// of the child's area.
+  pub fn relative_to(&self, offset: XY, size: Size) -> Option<Event> {
+    match self {
+      Self::Mouse(event) => {
+        let column = event.column.checked_sub(offset.x)?;
+        let row = event.row.checked_sub(offset.y)?;
+
+        if column < size.width && row < size.height {
+          Some(Self::Mouse(MouseEvent {
+            column,
+            row,
+            ..*event
+          }))
+        } else {
+          None
+        }
+      }
+      event => Some(event.clone()),
+    }
+  }
+}
+
+#[derive(Debug)]
 pub enum EventHandlerResult {
   /// The event was captured by the widget, and should
   /// not be handled by the parent.
@@ -49,3 +93,119 @@ pub enum EventHandlerResult {
   /// it wants the parent to respond to this new event instead.
   Mapped(Event),
 }
+
+/// Handles [`Event::NextFocus`] and [`Event::PrevFocus`] for widgets
+/// which don't have any focusable children.
+///
+/// If the widget is focused, it is blurred and focus bubbles to the
+/// next widget, otherwise the widget takes focus if it wants it.
+pub fn cycle_leaf_focus<W: Widget + ?Sized>(
+  widget: &mut W,
+) -> EventHandlerResult {
+  if widget.has_focus() {
+    widget.blur();
+
+    EventHandlerResult::Bubble
+  } else if widget.needs_focus() == Some(true) {
+    widget.focus();
+
+    EventHandlerResult::Captured
+  } else {
+    EventHandlerResult::Bubble
+  }
+}
+
+/// Handles an event for a container, given its children and the area
+/// each of them was laid out in, relative to the container.
+///
+/// - Focus events move focus through the children in order, starting from the
+///   focused child, and bubble once focus leaves the last one.
+/// - Key events are sent to the focused child.
+/// - Mouse events are sent to the child under the cursor. If this results in
+///   that child taking focus, any other focused child is blurred.
+/// - Batched events are dispatched one by one. Any of them the children did not
+///   capture are handed back to the parent as a batch of their own.
+pub fn dispatch_to_children(
+  children: &mut [(Rect, &mut dyn Widget)],
+  event: Event,
+) -> EventHandlerResult {
+  let focused = children.iter().position(|(_, child)| child.has_focus());
+
+  match event {
+    Event::NextFocus | Event::PrevFocus => {
+      let forward = matches!(event, Event::NextFocus);
+
+      let order = if forward {
+        (focused.unwrap_or(0)..children.len()).collect::<Vec<_>>()
+      } else {
+        (0..=focused.unwrap_or(children.len().saturating_sub(1)))
+          .rev()
+          .take(children.len())
+          .collect()
+      };
+
+      for i in order {
+        if let EventHandlerResult::Captured = children[i].1.on(event.clone()) {
+          return EventHandlerResult::Captured;
+        }
+      }
+
+      EventHandlerResult::Bubble
+    }
+    Event::Key(_) => match focused {
+      Some(i) => children[i].1.on(event),
+      None => EventHandlerResult::Bubble,
+    },
+    Event::Mouse(_) => {
+      let Some((i, event)) =
+        children
+          .iter()
+          .enumerate()
+          .rev()
+          .find_map(|(i, (rect, _))| {
+            event
+              .relative_to(rect.top_left, rect.size)
+              .map(|event| (i, event))
+          })
+      else {
+        return EventHandlerResult::Bubble;
+      };
+
+      let result = children[i].1.on(event);
+
+      if children[i].1.has_focus() {
+        for (j, (_, child)) in children.iter_mut().enumerate() {
+          if j != i && child.has_focus() {
+            child.blur();
+          }
+        }
+      }
+
+      result
+    }
+    Event::Batch(events) => {
+      let count = events.len();
+      let mut unhandled = Vec::new();
+      let mut mapped = false;
+
+      for event in events {
+        match dispatch_to_children(children, event.clone()) {
+          EventHandlerResult::Captured => {}
+          EventHandlerResult::Bubble => unhandled.push(event),
+          EventHandlerResult::Mapped(event) => {
+            mapped = true;
+            unhandled.push(event);
+          }
+        }
+      }
+
+      if unhandled.is_empty() {
+        EventHandlerResult::Captured
+      } else if !mapped && unhandled.len() == count {
+        EventHandlerResult::Bubble
+      } else {
+        EventHandlerResult::Mapped(Event::Batch(unhandled))
+      }
+    }
+  }
+}